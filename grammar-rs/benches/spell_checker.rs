@@ -1,7 +1,8 @@
 //! Benchmarks for the spell checker module
 //! This is the most critical module to benchmark as Levenshtein is O(m*n)
 //!
-//! Compares HashSet vs FST dictionary backends.
+//! Compares HashSet vs FST dictionary backends, and Levenshtein automaton
+//! suggestions against the previous full-dictionary Levenshtein scan.
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
 use grammar_rs::prelude::*;
@@ -197,6 +198,65 @@ fn bench_fst_vs_hashset(c: &mut Criterion) {
     group.finish();
 }
 
+// === Levenshtein automaton vs linear scan ===
+
+/// Previous suggestion strategy: Levenshtein against every dictionary word
+fn linear_scan_suggest(words: &[String], word: &str, max: usize) -> Vec<String> {
+    let mut candidates: Vec<(&String, usize)> = words.iter()
+        .map(|w| (w, levenshtein(word, w)))
+        .filter(|(_, d)| *d <= 2)
+        .collect();
+    candidates.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(b.0)));
+    candidates.into_iter().take(max).map(|(w, _)| w.clone()).collect()
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for j in 1..=b.len() {
+            let current = row[j];
+            row[j] = (row[j] + 1).min(row[j - 1] + 1).min(prev + usize::from(ca != b[j - 1]));
+            prev = current;
+        }
+    }
+    row[b.len()]
+}
+
+fn bench_suggest_automaton_vs_scan(c: &mut Criterion) {
+    let dict = load_fst_dictionary();
+    let words: Vec<String> = dict.words_with_prefix("");
+    let fst_checker = SpellChecker::with_shared_fst(dict.clone());
+    let hash_checker = SpellChecker::new().with_words(&words);
+    // Build the HashSet backend's suggestion index outside the measurement
+    hash_checker.suggest("warmup", 1);
+
+    let mut group = c.benchmark_group("spell_checker/suggest_speedup");
+    group.sample_size(10);
+
+    for misspelling in ["libary", "recieve", "understandig"] {
+        group.bench_with_input(
+            BenchmarkId::new("linear_scan", misspelling),
+            &misspelling,
+            |b, word| b.iter(|| linear_scan_suggest(&words, black_box(word), 3)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("fst_automaton", misspelling),
+            &misspelling,
+            |b, word| b.iter(|| fst_checker.suggest(black_box(word), 3)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("hashset_automaton", misspelling),
+            &misspelling,
+            |b, word| b.iter(|| hash_checker.suggest(black_box(word), 3)),
+        );
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_spell_check_clean,
@@ -206,6 +266,7 @@ criterion_group!(
     bench_levenshtein_scaling,
     bench_fst_lookup,
     bench_fst_vs_hashset,
+    bench_suggest_automaton_vs_scan,
 );
 
 criterion_main!(benches);
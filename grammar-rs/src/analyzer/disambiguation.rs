@@ -0,0 +1,352 @@
+//! Rule-based disambiguation (LanguageTool `disambiguation.xml`)
+//!
+//! Runs between the `Analyzer` and the checkers: multi-token patterns pick
+//! the right POS reading from the context ("I saw" → VBD, "the saw" → NN)
+//! or immunize tokens against every rule. Patterns use the same token
+//! matcher as `DynamicPatternChecker` (regex, postag, SENT_START, skip...).
+//!
+//! Actions on the readings of the marked tokens:
+//! - `replace`: keep a single reading with the POS tag (and lemma)
+//! - `add`: add a reading
+//! - `filter` / `filterall`: keep the matching readings; when none matches,
+//!   replace them with the filter tag if it is a plain tag
+//! - `remove`: drop the matching readings
+//! - `immunize`: no match is reported on the token
+
+use crate::checker::{CompiledToken, ComplexPatternToken, match_pattern};
+use crate::core::traits::Disambiguator;
+use crate::core::{AnalyzedToken, PosTag, Reading, TokenKind};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// JSON Structures (matching sync-lt output)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DisambiguationAction {
+    Replace,
+    Add,
+    Remove,
+    Filter,
+    FilterAll,
+    Immunize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisambiguationRule {
+    pub id: String,
+    pub pattern: Vec<ComplexPatternToken>,
+    /// Indices of the pattern tokens inside `<marker>` (empty: whole pattern)
+    #[serde(default)]
+    pub marker: Vec<usize>,
+    pub action: DisambiguationAction,
+    /// Tag set by replace/add, tag regex kept by filter or dropped by remove
+    #[serde(default)]
+    pub postag: Option<String>,
+    #[serde(default)]
+    pub lemma: Option<String>,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Compiled rules
+// ═══════════════════════════════════════════════════════════════════════════════
+
+struct CompiledDisambiguation {
+    pattern: Vec<CompiledToken>,
+    marker: Vec<usize>,
+    action: DisambiguationAction,
+    /// Tag written on the marked tokens
+    tag: Option<PosTag>,
+    /// Tags kept by filter / dropped by remove
+    postag_regex: Option<Regex>,
+    lemma: Option<String>,
+}
+
+impl CompiledDisambiguation {
+    fn from_json(rule: &DisambiguationRule) -> Option<Self> {
+        // An invalid regex would make the token match anything
+        let invalid_regex = rule
+            .pattern
+            .iter()
+            .any(|t| t.regexp.as_ref().is_some_and(|r| Regex::new(r).is_err()));
+        if rule.pattern.is_empty() || invalid_regex {
+            return None;
+        }
+        let pattern: Vec<_> = rule.pattern.iter().map(CompiledToken::from_json).collect::<Option<_>>()?;
+
+        let postag = rule.postag.as_deref().filter(|p| !p.is_empty());
+        let postag_regex = match rule.action {
+            DisambiguationAction::Filter | DisambiguationAction::FilterAll | DisambiguationAction::Remove => {
+                Some(Regex::new(&format!("^(?:{})$", postag?)).ok()?)
+            }
+            _ => None,
+        };
+        let tag = match rule.action {
+            DisambiguationAction::Replace | DisambiguationAction::Add => Some(parse_tag(postag?)?),
+            // A filter regex only gives a tag when it names a single one
+            DisambiguationAction::Filter | DisambiguationAction::FilterAll => postag
+                .filter(|p| !p.contains(['.', '*', '+', '?', '|', '(', '[']))
+                .and_then(parse_tag),
+            _ => None,
+        };
+
+        Some(Self {
+            pattern,
+            marker: rule.marker.clone(),
+            action: rule.action,
+            tag,
+            postag_regex,
+            lemma: rule.lemma.clone().filter(|l| !l.is_empty()),
+        })
+    }
+
+    fn is_marked(&self, pattern_idx: usize) -> bool {
+        self.marker.is_empty() || self.marker.contains(&pattern_idx)
+    }
+
+    fn tag_matches(&self, reading: &Reading) -> bool {
+        self.postag_regex.as_ref().is_some_and(|regex| regex.is_match(reading.pos.as_str()))
+    }
+
+    /// Reading written by replace/add (and filter without a matching reading)
+    fn reading(&self, token: &AnalyzedToken) -> Option<Reading> {
+        Some(Reading {
+            lemma: self.lemma.clone().or_else(|| token.lemma.clone()),
+            pos: self.tag?,
+        })
+    }
+
+    fn apply(&self, token: &mut AnalyzedToken) {
+        match self.action {
+            DisambiguationAction::Replace => {
+                if let Some(reading) = self.reading(token) {
+                    token.set_readings(vec![reading]);
+                }
+            }
+            DisambiguationAction::Add => {
+                if let Some(reading) = self.reading(token) {
+                    let mut readings = token.readings.clone();
+                    if !readings.contains(&reading) {
+                        readings.push(reading);
+                    }
+                    token.set_readings(readings);
+                }
+            }
+            DisambiguationAction::Filter | DisambiguationAction::FilterAll => {
+                let kept: Vec<Reading> = token.readings.iter().filter(|r| self.tag_matches(r)).cloned().collect();
+                if !kept.is_empty() {
+                    token.set_readings(kept);
+                } else if let Some(reading) = self.reading(token) {
+                    token.set_readings(vec![reading]);
+                }
+            }
+            DisambiguationAction::Remove => {
+                if token.readings.iter().any(|r| self.tag_matches(r)) {
+                    let kept = token.readings.iter().filter(|r| !self.tag_matches(r)).cloned().collect();
+                    token.set_readings(kept);
+                }
+            }
+            DisambiguationAction::Immunize => token.immunized = true,
+        }
+    }
+}
+
+/// "NN:UN" → NN: LanguageTool sub-tags fall back to their Penn tag
+fn parse_tag(tag: &str) -> Option<PosTag> {
+    PosTag::from_str(tag).or_else(|| tag.split(':').next().and_then(PosTag::from_str))
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Disambiguator
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Disambiguator applying LanguageTool rules in file order
+pub struct RuleDisambiguator {
+    rules: Vec<CompiledDisambiguation>,
+}
+
+impl RuleDisambiguator {
+    /// Create an empty disambiguator
+    pub fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Load rules from JSON string (unsupported or invalid rules are skipped)
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let raw_rules: Vec<DisambiguationRule> = serde_json::from_str(json)?;
+        Ok(Self::from_rules(&raw_rules))
+    }
+
+    /// Load rules from a slice of DisambiguationRule
+    pub fn from_rules(raw_rules: &[DisambiguationRule]) -> Self {
+        Self {
+            rules: raw_rules.iter().filter_map(CompiledDisambiguation::from_json).collect(),
+        }
+    }
+
+    /// Number of loaded rules
+    pub fn rule_count(&self) -> usize {
+        self.rules.len()
+    }
+}
+
+impl Default for RuleDisambiguator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Disambiguator for RuleDisambiguator {
+    fn disambiguate(&self, tokens: &mut [AnalyzedToken]) {
+        let positions: Vec<usize> = tokens
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| t.token.kind != TokenKind::Whitespace)
+            .map(|(i, _)| i)
+            .collect();
+        let mut targets = Vec::new();
+
+        // Each rule sees the readings left by the previous ones
+        for rule in &self.rules {
+            let word_tokens: Vec<(usize, &AnalyzedToken)> = positions.iter().map(|&i| (i, &tokens[i])).collect();
+            for start in 0..word_tokens.len() {
+                let matched = targets.len();
+                let end = match_pattern(&word_tokens, start, &rule.pattern, |pattern_idx, token_idx| {
+                    if rule.is_marked(pattern_idx) {
+                        targets.push(word_tokens[token_idx].0);
+                    }
+                });
+                if end.is_none() {
+                    targets.truncate(matched);
+                }
+            }
+
+            for &i in &targets {
+                rule.apply(&mut tokens[i]);
+            }
+            targets.clear();
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Static loading for embedded JSON
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Embedded English disambiguation rules
+static EN_DISAMBIGUATION_JSON: &str = include_str!("../checker/data/en_disambiguation.json");
+
+/// Embedded French disambiguation rules
+static FR_DISAMBIGUATION_JSON: &str = include_str!("../checker/data/fr_disambiguation.json");
+
+/// Create the English disambiguator
+pub fn create_en_disambiguator() -> RuleDisambiguator {
+    RuleDisambiguator::from_json(EN_DISAMBIGUATION_JSON).expect("Failed to parse en_disambiguation.json")
+}
+
+/// Create the French disambiguator
+pub fn create_fr_disambiguator() -> RuleDisambiguator {
+    RuleDisambiguator::from_json(FR_DISAMBIGUATION_JSON).expect("Failed to parse fr_disambiguation.json")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::traits::{Analyzer, SentenceSplitter, Tokenizer};
+    use crate::analyzer::PosTagger;
+    use crate::tokenizer::{SimpleSentenceSplitter, SimpleTokenizer};
+
+    fn analyze<'a>(text: &'a str, disambiguator: &RuleDisambiguator) -> Vec<AnalyzedToken<'a>> {
+        let mut tagger = PosTagger::new();
+        tagger.add_word("saw", "saw", PosTag::NN);
+        let mut tokens = tagger.analyze(SimpleTokenizer::new().tokenize(text));
+        SimpleSentenceSplitter::new().segment(text, &mut tokens);
+        disambiguator.disambiguate(&mut tokens);
+        tokens
+    }
+
+    fn find<'t, 'a>(tokens: &'t [AnalyzedToken<'a>], text: &str, nth: usize) -> &'t AnalyzedToken<'a> {
+        tokens.iter().filter(|t| t.token.text == text).nth(nth).unwrap()
+    }
+
+    #[test]
+    fn test_embedded_rules_load() {
+        assert!(create_en_disambiguator().rule_count() > 0);
+        create_fr_disambiguator();
+    }
+
+    #[test]
+    fn test_saw_verb_and_noun() {
+        let tokens = analyze("I saw the saw.", &create_en_disambiguator());
+        let verb = find(&tokens, "saw", 0);
+        assert_eq!(verb.pos, Some(PosTag::VBD));
+        assert_eq!(verb.lemma.as_deref(), Some("see"));
+        assert_eq!(find(&tokens, "saw", 1).pos, Some(PosTag::NN));
+    }
+
+    fn token(text: &str, postag: Option<&str>) -> ComplexPatternToken {
+        ComplexPatternToken {
+            text: Some(text.to_string()),
+            regexp: None,
+            postag: postag.map(str::to_string),
+            postag_regexp: postag.is_some(),
+            inflected: false,
+            case_sensitive: false,
+            negation: false,
+            min: 1,
+            max: 1,
+            skip: None,
+        }
+    }
+
+    fn rule(id: &str, pattern: Vec<ComplexPatternToken>, marker: Vec<usize>, action: DisambiguationAction, postag: &str) -> DisambiguationRule {
+        DisambiguationRule {
+            id: id.to_string(),
+            pattern,
+            marker,
+            action,
+            postag: Some(postag.to_string()),
+            lemma: None,
+        }
+    }
+
+    #[test]
+    fn test_filter_remove_and_immunize() {
+        let disambiguator = RuleDisambiguator::from_rules(&[
+            rule("TO_VB", vec![token("to", None), token("saw", None)], vec![1], DisambiguationAction::Filter, "VB"),
+            rule("SAW_NO_NN", vec![token("saw", Some("NN"))], vec![], DisambiguationAction::Remove, "NN.*"),
+            rule("ET_AL", vec![token("et", None), token("al", None)], vec![], DisambiguationAction::Immunize, ""),
+        ]);
+        assert_eq!(disambiguator.rule_count(), 3);
+
+        let tokens = analyze("We want to saw it. A saw, et al.", &disambiguator);
+        assert_eq!(find(&tokens, "saw", 0).pos, Some(PosTag::VB));
+        assert_eq!(find(&tokens, "saw", 1).pos, None);
+        assert!(find(&tokens, "et", 0).immunized && find(&tokens, "al", 0).immunized);
+    }
+
+    #[test]
+    fn test_filter_keeps_matching_readings() {
+        let disambiguator = RuleDisambiguator::from_rules(&[
+            rule("PRP_VBD", vec![token("i", None), token("saw", None)], vec![1], DisambiguationAction::Filter, "VB.*"),
+            rule("DT_NN", vec![token("the", None), token("saw", None)], vec![1], DisambiguationAction::Remove, "VB.*"),
+        ]);
+        let mut tagger = PosTagger::new();
+        tagger.add_word("saw", "saw", PosTag::NN);
+        tagger.add_word("saw", "see", PosTag::VBD);
+        tagger.add_word("saw", "saw", PosTag::VB);
+        let mut tokens = tagger.analyze(SimpleTokenizer::new().tokenize("I saw the saw"));
+        disambiguator.disambiguate(&mut tokens);
+
+        let verb = find(&tokens, "saw", 0);
+        assert_eq!(verb.pos, Some(PosTag::VBD));
+        assert_eq!(verb.lemma.as_deref(), Some("see"));
+        assert_eq!(verb.readings.len(), 2);
+
+        let noun = find(&tokens, "saw", 1);
+        assert_eq!(noun.pos, Some(PosTag::NN));
+        assert_eq!(noun.readings.len(), 1);
+    }
+}
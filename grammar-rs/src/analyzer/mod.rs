@@ -1,5 +1,12 @@
 mod simple;
 mod pos_tagger;
+mod disambiguation;
+mod perceptron;
 
 pub use simple::{PassthroughAnalyzer, DictAnalyzer};
 pub use pos_tagger::PosTagger;
+pub use perceptron::{PerceptronTagger, TaggedSentence, parse_tagged_corpus};
+pub use disambiguation::{
+    RuleDisambiguator, DisambiguationRule, DisambiguationAction,
+    create_en_disambiguator, create_fr_disambiguator,
+};
@@ -0,0 +1,468 @@
+//! Averaged-perceptron POS tagger
+//!
+//! Statistical tagger trained on a tagged corpus (Penn Treebank tags):
+//! greedy left-to-right tagging from context features (word, affixes,
+//! neighbouring words, previous tags). Frequent unambiguous words are
+//! tagged straight from a tag dictionary.
+//!
+//! Train a model with `cargo run --bin train-pos-tagger`, then load it with
+//! `PerceptronTagger::from_file` (or plug it into `PosTagger::with_model`).
+
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use crate::core::{AnalyzedToken, PosTag, Token, TokenKind};
+use crate::core::traits::Analyzer;
+
+/// A sentence of (word, tag) pairs
+pub type TaggedSentence = Vec<(String, String)>;
+
+/// Words seen at least this often can enter the tag dictionary
+const TAG_DICT_MIN_FREQ: usize = 20;
+/// ... if this share of their occurrences has the same tag
+const TAG_DICT_MIN_RATIO: f64 = 0.97;
+
+const START: [&str; 2] = ["-START-", "-START2-"];
+const END: [&str; 2] = ["-END-", "-END2-"];
+
+/// Averaged-perceptron tagger (serializable model)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PerceptronTagger {
+    /// Tag names, indexed by class id
+    classes: Vec<String>,
+    /// Unambiguous frequent words -> class id
+    tag_dict: HashMap<String, u16>,
+    /// Feature -> averaged weights per class
+    weights: HashMap<String, Vec<(u16, f32)>>,
+}
+
+impl PerceptronTagger {
+    /// Train a model on tagged sentences
+    pub fn train(sentences: &[TaggedSentence], iterations: usize) -> Self {
+        let mut trainer = Trainer::new(sentences);
+        let mut order: Vec<usize> = (0..sentences.len()).collect();
+        let mut seed = 0x9E37_79B9_7F4A_7C15_u64;
+
+        for _ in 0..iterations {
+            for &i in &order {
+                trainer.train_sentence(&sentences[i]);
+            }
+            shuffle(&mut order, &mut seed);
+        }
+
+        trainer.into_model()
+    }
+
+    /// Load a model from a binary file (bincode format)
+    pub fn from_file(path: &Path) -> io::Result<Self> {
+        let data = std::fs::read(path)?;
+        bincode::deserialize(&data)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Save the model to a binary file (bincode format)
+    pub fn to_file(&self, path: &Path) -> io::Result<()> {
+        let data = bincode::serialize(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        std::fs::write(path, data)
+    }
+
+    /// Tags known by the model
+    pub fn classes(&self) -> &[String] {
+        &self.classes
+    }
+
+    /// Number of features with non-zero weights
+    pub fn feature_count(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// Tag a sentence, one tag per word
+    pub fn tag<S: AsRef<str>>(&self, words: &[S]) -> Vec<&str> {
+        let context = context(words);
+        let mut prev = START[0];
+        let mut prev2 = START[1];
+        let mut tags = Vec::with_capacity(words.len());
+
+        for (i, word) in words.iter().enumerate() {
+            let word = word.as_ref();
+            let class = match self.tag_dict.get(word) {
+                Some(&class) => class,
+                None => {
+                    let features = features(i, word, &context, prev, prev2);
+                    best_class(&self.score(&features))
+                }
+            };
+            let tag = self.classes.get(class as usize).map_or("", String::as_str);
+            prev2 = prev;
+            prev = tag;
+            tags.push(tag);
+        }
+
+        tags
+    }
+
+    /// Share of correctly tagged words
+    pub fn accuracy(&self, sentences: &[TaggedSentence]) -> f64 {
+        let (mut correct, mut total) = (0, 0);
+        for sentence in sentences {
+            let words: Vec<&str> = sentence.iter().map(|(w, _)| w.as_str()).collect();
+            for (predicted, (_, gold)) in self.tag(&words).into_iter().zip(sentence) {
+                correct += usize::from(predicted == gold);
+                total += 1;
+            }
+        }
+        if total == 0 { 0.0 } else { correct as f64 / total as f64 }
+    }
+
+    /// Tag the non-whitespace tokens, one sentence at a time
+    ///
+    /// Sentences end on `.`, `!` and `?` (the sentence splitter runs after
+    /// the analyzer).
+    pub fn tag_tokens(&self, tokens: &[Token]) -> Vec<Option<PosTag>> {
+        let mut tags = vec![None; tokens.len()];
+        let words: Vec<usize> = (0..tokens.len())
+            .filter(|&i| tokens[i].kind != TokenKind::Whitespace)
+            .collect();
+
+        for sentence in words.split_inclusive(|&i| matches!(tokens[i].text, "." | "!" | "?")) {
+            let texts: Vec<&str> = sentence.iter().map(|&i| tokens[i].text).collect();
+            for (&i, tag) in sentence.iter().zip(self.tag(&texts)) {
+                tags[i] = PosTag::from_str(tag);
+            }
+        }
+
+        tags
+    }
+
+    fn score(&self, features: &[String]) -> Vec<f32> {
+        let mut scores = vec![0.0; self.classes.len()];
+        for feature in features {
+            if let Some(weights) = self.weights.get(feature) {
+                for &(class, weight) in weights {
+                    scores[class as usize] += weight;
+                }
+            }
+        }
+        scores
+    }
+}
+
+impl Analyzer for PerceptronTagger {
+    fn analyze<'a>(&self, tokens: Vec<Token<'a>>) -> Vec<AnalyzedToken<'a>> {
+        let tags = self.tag_tokens(&tokens);
+        tokens
+            .into_iter()
+            .zip(tags)
+            .map(|(token, pos)| AnalyzedToken::new(token, None, pos))
+            .collect()
+    }
+}
+
+/// Parse a tagged corpus
+///
+/// Two formats are accepted:
+/// - CoNLL: one token per line, blank line between sentences. Two columns
+///   are `word tag`; CoNLL-U lines (10 columns) use FORM and XPOS.
+/// - Penn: one sentence per line, `word/TAG` separated by spaces.
+pub fn parse_tagged_corpus(text: &str) -> Vec<TaggedSentence> {
+    let mut sentences = Vec::new();
+    let mut current = Vec::new();
+
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            if !current.is_empty() {
+                sentences.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if line.starts_with('#') {
+            continue;
+        }
+
+        let columns: Vec<&str> = line.split('\t').collect();
+        match columns.len() {
+            // CoNLL-U: ID FORM LEMMA UPOS XPOS ... (skip multiword ranges "1-2")
+            n if n >= 5 => {
+                if columns[0].parse::<usize>().is_ok() && columns[4] != "_" {
+                    current.push((columns[1].to_string(), columns[4].to_string()));
+                }
+            }
+            n if n >= 2 => current.push((columns[0].to_string(), columns[n - 1].to_string())),
+            _ => {
+                let sentence: TaggedSentence = line
+                    .split_whitespace()
+                    .filter_map(|pair| pair.rsplit_once('/'))
+                    .map(|(word, tag)| (word.to_string(), tag.to_string()))
+                    .collect();
+                if !sentence.is_empty() {
+                    sentences.push(sentence);
+                }
+            }
+        }
+    }
+    if !current.is_empty() {
+        sentences.push(current);
+    }
+
+    sentences
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Features
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Normalized word: lowercase, with classes for numbers and hyphenated words
+fn normalize(word: &str) -> String {
+    if word.contains('-') && !word.starts_with('-') {
+        "!HYPHEN".to_string()
+    } else if word.len() == 4 && word.chars().all(|c| c.is_ascii_digit()) {
+        "!YEAR".to_string()
+    } else if word.starts_with(|c: char| c.is_ascii_digit()) {
+        "!DIGITS".to_string()
+    } else {
+        word.to_lowercase()
+    }
+}
+
+/// Normalized sentence padded with start/end markers
+fn context<S: AsRef<str>>(words: &[S]) -> Vec<String> {
+    START
+        .iter()
+        .map(|s| s.to_string())
+        .chain(words.iter().map(|w| normalize(w.as_ref())))
+        .chain(END.iter().map(|s| s.to_string()))
+        .collect()
+}
+
+fn suffix(word: &str) -> &str {
+    let start = word.char_indices().rev().nth(2).map_or(0, |(i, _)| i);
+    &word[start..]
+}
+
+fn prefix(word: &str) -> &str {
+    let end = word.chars().next().map_or(0, char::len_utf8);
+    &word[..end]
+}
+
+/// Features of word `i` (`context` is offset by the two start markers)
+fn features(i: usize, word: &str, context: &[String], prev: &str, prev2: &str) -> Vec<String> {
+    let i = i + START.len();
+    vec![
+        "bias".to_string(),
+        format!("i suffix {}", suffix(word)),
+        format!("i pref1 {}", prefix(word)),
+        format!("i-1 tag {prev}"),
+        format!("i-2 tag {prev2}"),
+        format!("i tag+i-2 tag {prev} {prev2}"),
+        format!("i word {}", context[i]),
+        format!("i-1 tag+i word {prev} {}", context[i]),
+        format!("i-1 word {}", context[i - 1]),
+        format!("i-1 suffix {}", suffix(&context[i - 1])),
+        format!("i-2 word {}", context[i - 2]),
+        format!("i+1 word {}", context[i + 1]),
+        format!("i+1 suffix {}", suffix(&context[i + 1])),
+        format!("i+2 word {}", context[i + 2]),
+    ]
+}
+
+/// Highest scoring class (first one on ties)
+fn best_class(scores: &[f32]) -> u16 {
+    let mut best = 0;
+    for (class, &score) in scores.iter().enumerate() {
+        if score > scores[best] {
+            best = class;
+        }
+    }
+    best as u16
+}
+
+/// Deterministic Fisher-Yates shuffle (xorshift)
+fn shuffle(items: &mut [usize], seed: &mut u64) {
+    for i in (1..items.len()).rev() {
+        *seed ^= *seed << 13;
+        *seed ^= *seed >> 7;
+        *seed ^= *seed << 17;
+        items.swap(i, (*seed % (i as u64 + 1)) as usize);
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Training
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Weight of a (feature, class) pair with its running total for averaging
+#[derive(Default)]
+struct Param {
+    weight: f32,
+    total: f64,
+    updated: u64,
+}
+
+struct Trainer {
+    model: PerceptronTagger,
+    class_ids: HashMap<String, u16>,
+    params: HashMap<String, HashMap<u16, Param>>,
+    /// Number of training examples seen
+    instances: u64,
+}
+
+impl Trainer {
+    fn new(sentences: &[TaggedSentence]) -> Self {
+        let mut class_ids = HashMap::new();
+        let mut classes = Vec::new();
+        let mut counts: HashMap<&str, HashMap<&str, usize>> = HashMap::new();
+
+        for (word, tag) in sentences.iter().flatten() {
+            class_ids.entry(tag.clone()).or_insert_with(|| {
+                classes.push(tag.clone());
+                (classes.len() - 1) as u16
+            });
+            *counts.entry(word).or_default().entry(tag).or_default() += 1;
+        }
+
+        let tag_dict = counts
+            .into_iter()
+            .filter_map(|(word, tags)| {
+                let total: usize = tags.values().sum();
+                let (tag, count) = tags.into_iter().max_by_key(|&(tag, count)| (count, std::cmp::Reverse(tag)))?;
+                let unambiguous = total >= TAG_DICT_MIN_FREQ && count as f64 / total as f64 >= TAG_DICT_MIN_RATIO;
+                unambiguous.then(|| (word.to_string(), class_ids[tag]))
+            })
+            .collect();
+
+        Self {
+            model: PerceptronTagger { classes, tag_dict, weights: HashMap::new() },
+            class_ids,
+            params: HashMap::new(),
+            instances: 0,
+        }
+    }
+
+    fn train_sentence(&mut self, sentence: &TaggedSentence) {
+        let words: Vec<&str> = sentence.iter().map(|(w, _)| w.as_str()).collect();
+        let context = context(&words);
+        let mut prev = START[0].to_string();
+        let mut prev2 = START[1].to_string();
+
+        for (i, (word, gold)) in sentence.iter().enumerate() {
+            let guess = match self.model.tag_dict.get(word) {
+                Some(&class) => class,
+                None => {
+                    let features = features(i, word, &context, &prev, &prev2);
+                    let guess = best_class(&self.score(&features));
+                    self.update(self.class_ids[gold], guess, &features);
+                    guess
+                }
+            };
+            prev2 = std::mem::replace(&mut prev, self.model.classes[guess as usize].clone());
+        }
+    }
+
+    /// Scores with the current (non-averaged) weights
+    fn score(&self, features: &[String]) -> Vec<f32> {
+        let mut scores = vec![0.0; self.model.classes.len()];
+        for feature in features {
+            for (&class, param) in self.params.get(feature).into_iter().flatten() {
+                scores[class as usize] += param.weight;
+            }
+        }
+        scores
+    }
+
+    fn update(&mut self, truth: u16, guess: u16, features: &[String]) {
+        self.instances += 1;
+        if truth == guess {
+            return;
+        }
+        for feature in features {
+            let params = self.params.entry(feature.clone()).or_default();
+            for (class, delta) in [(truth, 1.0), (guess, -1.0)] {
+                let param = params.entry(class).or_default();
+                param.total += (self.instances - param.updated) as f64 * param.weight as f64;
+                param.updated = self.instances;
+                param.weight += delta;
+            }
+        }
+    }
+
+    /// Average the weights over every training example, dropping zeros
+    fn into_model(mut self) -> PerceptronTagger {
+        let instances = self.instances.max(1);
+        for (feature, params) in self.params {
+            let weights: Vec<(u16, f32)> = params
+                .into_iter()
+                .filter_map(|(class, param)| {
+                    let total = param.total + (instances - param.updated) as f64 * param.weight as f64;
+                    let average = (total / instances as f64) as f32;
+                    (average.abs() > 1e-3).then_some((class, average))
+                })
+                .collect();
+            if !weights.is_empty() {
+                self.model.weights.insert(feature, weights);
+            }
+        }
+        self.model
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CORPUS: &str = "\
+The/DT dog/NN runs/VBZ ./.
+The/DT cat/NN sleeps/VBZ ./.
+A/DT dog/NN barks/VBZ loudly/RB ./.
+The/DT dogs/NNS run/VBP fast/RB ./.
+I/PRP saw/VBD the/DT saw/NN ./.
+The/DT saw/NN cuts/VBZ wood/NN ./.
+We/PRP saw/VBD a/DT cat/NN ./.
+";
+
+    #[test]
+    fn test_parse_formats() {
+        let penn = parse_tagged_corpus(CORPUS);
+        assert_eq!(penn.len(), 7);
+        assert_eq!(penn[0][1], ("dog".to_string(), "NN".to_string()));
+
+        let conll = parse_tagged_corpus("The\tDT\ndog\tNN\n\n# comment\n1\tCats\tcat\tNOUN\tNNS\t_\t_\t_\t_\t_\n");
+        assert_eq!(conll.len(), 2);
+        assert_eq!(conll[1], vec![("Cats".to_string(), "NNS".to_string())]);
+    }
+
+    #[test]
+    fn test_train_and_tag() {
+        let sentences = parse_tagged_corpus(CORPUS);
+        let tagger = PerceptronTagger::train(&sentences, 10);
+
+        assert_eq!(tagger.accuracy(&sentences), 1.0);
+        assert_eq!(tagger.tag(&["The", "cat", "runs", "."]), vec!["DT", "NN", "VBZ", "."]);
+        // Context picks the reading of ambiguous words
+        assert_eq!(tagger.tag(&["I", "saw", "the", "saw", "."]), vec!["PRP", "VBD", "DT", "NN", "."]);
+    }
+
+    #[test]
+    fn test_model_roundtrip() {
+        let tagger = PerceptronTagger::train(&parse_tagged_corpus(CORPUS), 5);
+        let file = tempfile::NamedTempFile::new().unwrap();
+        tagger.to_file(file.path()).unwrap();
+
+        let loaded = PerceptronTagger::from_file(file.path()).unwrap();
+        assert_eq!(loaded.classes(), tagger.classes());
+        assert_eq!(loaded.tag(&["A", "dog", "sleeps"]), tagger.tag(&["A", "dog", "sleeps"]));
+    }
+
+    #[test]
+    fn test_analyze_tokens() {
+        use crate::core::traits::Tokenizer;
+        use crate::tokenizer::SimpleTokenizer;
+
+        let tagger = PerceptronTagger::train(&parse_tagged_corpus(CORPUS), 10);
+        let analyzed = tagger.analyze(SimpleTokenizer::new().tokenize("The dog runs."));
+        let tags: Vec<_> = analyzed.iter().map(|t| t.pos).collect();
+        assert_eq!(tags, vec![Some(PosTag::DT), None, Some(PosTag::NN), None, Some(PosTag::VBZ), Some(PosTag::PERIOD)]);
+    }
+}
@@ -4,24 +4,42 @@
 //! 1. FST dictionary for known words (~90% accuracy)
 //! 2. Suffix-based heuristics for unknown words
 //!
+//! With English morphology (`with_morphology`), irregular and inflected
+//! forms get their readings and lemmas from the lemmatizer (went → go/VBD,
+//! cities → city/NNS).
+//!
+//! With a statistical model (`with_model`), the model picks the selected
+//! reading from the context and tags unknown words instead of the suffix
+//! heuristics.
+//!
+//! Ambiguous words keep every reading ("saw" → VBD/see, NN/saw); the first
+//! one is selected and the disambiguator can narrow them down.
+//!
 //! Performance: O(word_length) lookup
 
 use std::collections::HashMap;
-use crate::core::{AnalyzedToken, Token, TokenKind, PosTag};
+use crate::core::{AnalyzedToken, Reading, Token, TokenKind, PosTag};
 use crate::core::traits::Analyzer;
+use crate::morphology::EnglishMorphology;
+use super::PerceptronTagger;
 
 /// Suffix rule for unknown word tagging
 struct SuffixRule {
     suffix: &'static str,
-    tag: PosTag,
+    /// Candidate tags, most likely first
+    tags: &'static [PosTag],
 }
 
 /// POS Tagger with dictionary and suffix heuristics
 pub struct PosTagger {
-    /// word -> (lemma, POS tag)
-    dictionary: HashMap<String, (String, PosTag)>,
+    /// word -> readings (lemma, POS tag), in insertion order
+    dictionary: HashMap<String, Vec<(String, PosTag)>>,
     /// Suffix rules for unknown words (English)
     en_suffix_rules: Vec<SuffixRule>,
+    /// Statistical model choosing among the readings
+    model: Option<PerceptronTagger>,
+    /// Lemmatizer for the words missing from the dictionary
+    morphology: Option<&'static EnglishMorphology>,
 }
 
 impl PosTagger {
@@ -29,17 +47,41 @@ impl PosTagger {
         Self {
             dictionary: HashMap::new(),
             en_suffix_rules: Self::default_en_suffix_rules(),
+            model: None,
+            morphology: None,
         }
     }
 
     /// Create a PosTagger with a preloaded dictionary
     pub fn with_dictionary(dictionary: HashMap<String, (String, PosTag)>) -> Self {
         Self {
-            dictionary,
+            dictionary: dictionary
+                .into_iter()
+                .map(|(word, reading)| (word, vec![reading]))
+                .collect(),
             en_suffix_rules: Self::default_en_suffix_rules(),
+            model: None,
+            morphology: None,
         }
     }
 
+    /// Use a trained model to select readings and tag unknown words
+    pub fn with_model(mut self, model: PerceptronTagger) -> Self {
+        self.model = Some(model);
+        self
+    }
+
+    /// Use the English morphology for readings and lemmas
+    pub fn with_morphology(mut self, morphology: &'static EnglishMorphology) -> Self {
+        self.morphology = Some(morphology);
+        self
+    }
+
+    /// Whether a statistical model is loaded
+    pub fn has_model(&self) -> bool {
+        self.model.is_some()
+    }
+
     /// Load dictionary entries from lines in "word\tlemma\tPOS" format
     pub fn load_from_lines<I, S>(&mut self, lines: I)
     where
@@ -54,112 +96,148 @@ impl PosTagger {
 
             let parts: Vec<&str> = line.split('\t').collect();
             if parts.len() >= 3 {
-                let word = parts[0].to_lowercase();
-                let lemma = parts[1].to_string();
                 if let Some(pos) = PosTag::from_str(parts[2]) {
-                    self.dictionary.insert(word, (lemma, pos));
+                    self.add_word(parts[0], parts[1], pos);
                 }
             }
         }
     }
 
-    /// Add a single word to the dictionary
+    /// Add a reading of a word to the dictionary (a word can have several)
     pub fn add_word(&mut self, word: &str, lemma: &str, pos: PosTag) {
-        self.dictionary.insert(word.to_lowercase(), (lemma.to_string(), pos));
+        let readings = self.dictionary.entry(word.to_lowercase()).or_default();
+        let reading = (lemma.to_string(), pos);
+        if !readings.contains(&reading) {
+            readings.push(reading);
+        }
     }
 
-    /// Number of entries in the dictionary
+    /// Number of words in the dictionary
     pub fn dictionary_size(&self) -> usize {
         self.dictionary.len()
     }
 
     /// Tag a single word
     pub fn tag_word(&self, word: &str) -> Option<PosTag> {
+        self.readings(word).first().map(|r| r.pos)
+    }
+
+    /// All readings of a word: dictionary entries, else morphology, else
+    /// suffix guesses
+    pub fn readings(&self, word: &str) -> Vec<Reading> {
         let lower = word.to_lowercase();
+        let mut readings = self.known_readings(&lower).unwrap_or_else(|| {
+            self.guess_pos_from_suffix(&lower)
+                .iter()
+                .map(|&pos| Reading { lemma: None, pos })
+                .collect()
+        });
+        self.fill_lemmas(&lower, &mut readings);
+        readings
+    }
 
-        // First, try dictionary lookup
-        if let Some((_, pos)) = self.dictionary.get(&lower) {
-            return Some(*pos);
+    /// Readings from the dictionary, then from the morphology
+    fn known_readings(&self, lower: &str) -> Option<Vec<Reading>> {
+        if let Some(entries) = self.dictionary.get(lower) {
+            return Some(entries
+                .iter()
+                .map(|(lemma, pos)| Reading { lemma: Some(lemma.clone()), pos: *pos })
+                .collect());
         }
 
-        // Then, try suffix heuristics
-        self.guess_pos_from_suffix(&lower)
+        let readings: Vec<Reading> = self.morphology?
+            .analyze(lower)
+            .iter()
+            .filter_map(|entry| {
+                // LanguageTool sub-tags ("NN:UN") fall back to their Penn tag
+                let pos = PosTag::from_str(&entry.pos)
+                    .or_else(|| entry.pos.split(':').next().and_then(PosTag::from_str))?;
+                Some(Reading { lemma: Some(entry.lemma.clone()), pos })
+            })
+            .collect();
+        (!readings.is_empty()).then_some(readings)
+    }
+
+    /// Lemmatize the readings without a lemma (suffix guesses, model tags)
+    fn fill_lemmas(&self, lower: &str, readings: &mut [Reading]) {
+        if let Some(morphology) = self.morphology {
+            for reading in readings.iter_mut().filter(|r| r.lemma.is_none()) {
+                reading.lemma = Some(morphology.lemmatize(lower, reading.pos.as_str()));
+            }
+        }
     }
 
     /// Get lemma for a word
     pub fn get_lemma(&self, word: &str) -> Option<&str> {
         self.dictionary
             .get(&word.to_lowercase())
+            .and_then(|r| r.first())
             .map(|(lemma, _)| lemma.as_str())
     }
 
     /// Guess POS from suffix (English)
-    fn guess_pos_from_suffix(&self, word: &str) -> Option<PosTag> {
+    fn guess_pos_from_suffix(&self, word: &str) -> &'static [PosTag] {
         // Skip very short words
         if word.len() < 3 {
-            return None;
-        }
-
-        for rule in &self.en_suffix_rules {
-            if word.ends_with(rule.suffix) {
-                return Some(rule.tag);
-            }
+            return &[];
         }
 
-        None
+        self.en_suffix_rules
+            .iter()
+            .find(|rule| word.ends_with(rule.suffix))
+            .map_or(&[], |rule| rule.tags)
     }
 
     /// Default English suffix rules
     fn default_en_suffix_rules() -> Vec<SuffixRule> {
         vec![
             // Verb forms
-            SuffixRule { suffix: "ing", tag: PosTag::VBG },      // running, eating
-            SuffixRule { suffix: "ed", tag: PosTag::VBD },       // walked, talked (or VBN)
-            SuffixRule { suffix: "ize", tag: PosTag::VB },       // organize, realize
-            SuffixRule { suffix: "ise", tag: PosTag::VB },       // organise (British)
-            SuffixRule { suffix: "ify", tag: PosTag::VB },       // simplify, clarify
-            SuffixRule { suffix: "ate", tag: PosTag::VB },       // create, operate
+            SuffixRule { suffix: "ing", tags: &[PosTag::VBG, PosTag::NN] },   // running, building
+            SuffixRule { suffix: "ed", tags: &[PosTag::VBD, PosTag::VBN] },   // walked, talked
+            SuffixRule { suffix: "ize", tags: &[PosTag::VB] },                // organize, realize
+            SuffixRule { suffix: "ise", tags: &[PosTag::VB] },                // organise (British)
+            SuffixRule { suffix: "ify", tags: &[PosTag::VB] },                // simplify, clarify
+            SuffixRule { suffix: "ate", tags: &[PosTag::VB] },                // create, operate
 
             // Adjectives
-            SuffixRule { suffix: "able", tag: PosTag::JJ },      // readable, doable
-            SuffixRule { suffix: "ible", tag: PosTag::JJ },      // possible, visible
-            SuffixRule { suffix: "ful", tag: PosTag::JJ },       // beautiful, careful
-            SuffixRule { suffix: "less", tag: PosTag::JJ },      // careless, hopeless
-            SuffixRule { suffix: "ous", tag: PosTag::JJ },       // dangerous, famous
-            SuffixRule { suffix: "ive", tag: PosTag::JJ },       // creative, active
-            SuffixRule { suffix: "al", tag: PosTag::JJ },        // natural, formal
-            SuffixRule { suffix: "ish", tag: PosTag::JJ },       // childish, reddish
-            SuffixRule { suffix: "ic", tag: PosTag::JJ },        // historic, basic
-            SuffixRule { suffix: "ical", tag: PosTag::JJ },      // historical, political
-            SuffixRule { suffix: "ent", tag: PosTag::JJ },       // different, apparent
-            SuffixRule { suffix: "ant", tag: PosTag::JJ },       // important, distant
+            SuffixRule { suffix: "able", tags: &[PosTag::JJ] },               // readable, doable
+            SuffixRule { suffix: "ible", tags: &[PosTag::JJ] },               // possible, visible
+            SuffixRule { suffix: "ful", tags: &[PosTag::JJ] },                // beautiful, careful
+            SuffixRule { suffix: "less", tags: &[PosTag::JJ] },               // careless, hopeless
+            SuffixRule { suffix: "ous", tags: &[PosTag::JJ] },                // dangerous, famous
+            SuffixRule { suffix: "ive", tags: &[PosTag::JJ] },                // creative, active
+            SuffixRule { suffix: "al", tags: &[PosTag::JJ] },                 // natural, formal
+            SuffixRule { suffix: "ish", tags: &[PosTag::JJ] },                // childish, reddish
+            SuffixRule { suffix: "ic", tags: &[PosTag::JJ] },                 // historic, basic
+            SuffixRule { suffix: "ical", tags: &[PosTag::JJ] },               // historical, political
+            SuffixRule { suffix: "ent", tags: &[PosTag::JJ] },                // different, apparent
+            SuffixRule { suffix: "ant", tags: &[PosTag::JJ] },                // important, distant
 
             // Comparative/superlative
-            SuffixRule { suffix: "er", tag: PosTag::JJR },       // bigger, faster
-            SuffixRule { suffix: "est", tag: PosTag::JJS },      // biggest, fastest
+            SuffixRule { suffix: "er", tags: &[PosTag::JJR, PosTag::NN] },    // bigger, teacher
+            SuffixRule { suffix: "est", tags: &[PosTag::JJS] },               // biggest, fastest
 
             // Adverbs
-            SuffixRule { suffix: "ly", tag: PosTag::RB },        // quickly, slowly
+            SuffixRule { suffix: "ly", tags: &[PosTag::RB] },                 // quickly, slowly
 
             // Nouns
-            SuffixRule { suffix: "tion", tag: PosTag::NN },      // nation, creation
-            SuffixRule { suffix: "sion", tag: PosTag::NN },      // vision, decision
-            SuffixRule { suffix: "ment", tag: PosTag::NN },      // movement, agreement
-            SuffixRule { suffix: "ness", tag: PosTag::NN },      // happiness, darkness
-            SuffixRule { suffix: "ity", tag: PosTag::NN },       // ability, activity
-            SuffixRule { suffix: "ance", tag: PosTag::NN },      // importance, distance
-            SuffixRule { suffix: "ence", tag: PosTag::NN },      // difference, presence
-            SuffixRule { suffix: "er", tag: PosTag::NN },        // teacher, worker (also JJR)
-            SuffixRule { suffix: "or", tag: PosTag::NN },        // actor, doctor
-            SuffixRule { suffix: "ist", tag: PosTag::NN },       // artist, scientist
-            SuffixRule { suffix: "ism", tag: PosTag::NN },       // capitalism, tourism
-            SuffixRule { suffix: "ship", tag: PosTag::NN },      // friendship, leadership
-            SuffixRule { suffix: "dom", tag: PosTag::NN },       // freedom, kingdom
-
-            // Plural nouns
-            SuffixRule { suffix: "ies", tag: PosTag::NNS },      // cities, parties
-            SuffixRule { suffix: "es", tag: PosTag::NNS },       // boxes, buses
-            SuffixRule { suffix: "s", tag: PosTag::NNS },        // cats, dogs (last resort)
+            SuffixRule { suffix: "tion", tags: &[PosTag::NN] },               // nation, creation
+            SuffixRule { suffix: "sion", tags: &[PosTag::NN] },               // vision, decision
+            SuffixRule { suffix: "ment", tags: &[PosTag::NN] },               // movement, agreement
+            SuffixRule { suffix: "ness", tags: &[PosTag::NN] },               // happiness, darkness
+            SuffixRule { suffix: "ity", tags: &[PosTag::NN] },                // ability, activity
+            SuffixRule { suffix: "ance", tags: &[PosTag::NN] },               // importance, distance
+            SuffixRule { suffix: "ence", tags: &[PosTag::NN] },               // difference, presence
+            SuffixRule { suffix: "or", tags: &[PosTag::NN] },                 // actor, doctor
+            SuffixRule { suffix: "ist", tags: &[PosTag::NN] },                // artist, scientist
+            SuffixRule { suffix: "ism", tags: &[PosTag::NN] },                // capitalism, tourism
+            SuffixRule { suffix: "ship", tags: &[PosTag::NN] },               // friendship, leadership
+            SuffixRule { suffix: "dom", tags: &[PosTag::NN] },                // freedom, kingdom
+
+            // Plural nouns or 3rd person verbs
+            SuffixRule { suffix: "ies", tags: &[PosTag::NNS, PosTag::VBZ] },  // cities, tries
+            SuffixRule { suffix: "es", tags: &[PosTag::NNS, PosTag::VBZ] },   // boxes, fixes
+            SuffixRule { suffix: "s", tags: &[PosTag::NNS, PosTag::VBZ] },    // cats, runs (last resort)
         ]
     }
 }
@@ -172,32 +250,49 @@ impl Default for PosTagger {
 
 impl Analyzer for PosTagger {
     fn analyze<'a>(&self, tokens: Vec<Token<'a>>) -> Vec<AnalyzedToken<'a>> {
+        let predicted = self.model.as_ref().map(|model| model.tag_tokens(&tokens));
+
         tokens
             .into_iter()
-            .map(|token| {
+            .enumerate()
+            .map(|(i, token)| {
                 let lower = token.text.to_lowercase();
-                let lookup = self.dictionary.get(&lower);
-
-                let lemma = lookup.map(|(l, _)| l.clone());
-                let pos = lookup.map(|(_, p)| *p).or_else(|| {
+                let known = self.known_readings(&lower);
+                let readings = if token.kind == TokenKind::Word || known.is_some() {
+                    let predicted = predicted.as_ref().and_then(|tags| tags[i]);
+                    let mut readings = match (known, predicted) {
+                        (Some(readings), _) => readings,
+                        // Unknown words: the model replaces the suffix guesses
+                        (None, Some(_)) => Vec::new(),
+                        (None, None) => self.readings(&lower),
+                    };
+                    if let Some(pos) = predicted {
+                        select_reading(&mut readings, pos);
+                    }
+                    self.fill_lemmas(&lower, &mut readings);
+                    readings
+                } else {
                     match token.kind {
-                        TokenKind::Punctuation => Some(PosTag::Punctuation),
-                        TokenKind::Number => Some(PosTag::CD),
-                        TokenKind::Word => self.guess_pos_from_suffix(&lower),
-                        _ => None,
+                        TokenKind::Punctuation => vec![Reading { lemma: None, pos: PosTag::Punctuation }],
+                        TokenKind::Number => vec![Reading { lemma: None, pos: PosTag::CD }],
+                        _ => Vec::new(),
                     }
-                });
+                };
 
-                AnalyzedToken {
-                    token,
-                    lemma,
-                    pos,
-                }
+                AnalyzedToken::with_readings(token, readings)
             })
             .collect()
     }
 }
 
+/// Move the reading with `pos` first, adding it when missing
+fn select_reading(readings: &mut Vec<Reading>, pos: PosTag) {
+    match readings.iter().position(|r| r.pos == pos) {
+        Some(index) => readings[..=index].rotate_right(1),
+        None => readings.insert(0, Reading { lemma: None, pos }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -249,6 +344,33 @@ mod tests {
         assert_eq!(tagger.tag_word("artist"), Some(PosTag::NN));
     }
 
+    #[test]
+    fn test_multiple_readings() {
+        let mut tagger = PosTagger::new();
+        tagger.add_word("saw", "see", PosTag::VBD);
+        tagger.add_word("saw", "saw", PosTag::NN);
+        tagger.add_word("saw", "saw", PosTag::NN);
+
+        assert_eq!(tagger.dictionary_size(), 1);
+        assert_eq!(tagger.tag_word("saw"), Some(PosTag::VBD));
+        assert_eq!(tagger.get_lemma("saw"), Some("see"));
+        let tags: Vec<_> = tagger.readings("saw").iter().map(|r| r.pos).collect();
+        assert_eq!(tags, vec![PosTag::VBD, PosTag::NN]);
+
+        // Ambiguous suffixes keep every candidate
+        let tags: Vec<_> = tagger.readings("walked").iter().map(|r| r.pos).collect();
+        assert_eq!(tags, vec![PosTag::VBD, PosTag::VBN]);
+        let tags: Vec<_> = tagger.readings("teacher").iter().map(|r| r.pos).collect();
+        assert_eq!(tags, vec![PosTag::JJR, PosTag::NN]);
+
+        use crate::core::traits::Tokenizer;
+        use crate::tokenizer::SimpleTokenizer;
+        let analyzed = tagger.analyze(SimpleTokenizer::new().tokenize("saw"));
+        assert_eq!(analyzed[0].pos, Some(PosTag::VBD));
+        assert_eq!(analyzed[0].readings.len(), 2);
+        assert!(analyzed[0].has_pos(|p| p == PosTag::NN));
+    }
+
     #[test]
     fn test_lemma_lookup() {
         let mut tagger = PosTagger::new();
@@ -306,4 +428,50 @@ mod tests {
         assert!(PosTag::VBG.matches_pattern("VB.*"));
         assert!(!PosTag::JJ.matches_pattern("NN.*"));
     }
+
+    #[test]
+    fn test_model_selects_reading() {
+        use crate::analyzer::parse_tagged_corpus;
+        use crate::core::traits::Tokenizer;
+        use crate::tokenizer::SimpleTokenizer;
+
+        let corpus = parse_tagged_corpus("\
+I/PRP saw/VBD the/DT saw/NN ./.
+The/DT saw/NN cuts/VBZ wood/NN ./.
+We/PRP saw/VBD a/DT blorf/NN ./.
+");
+        let mut tagger = PosTagger::new().with_model(PerceptronTagger::train(&corpus, 10));
+        tagger.add_word("saw", "saw", PosTag::NN);
+        tagger.add_word("saw", "see", PosTag::VBD);
+
+        let analyzed = tagger.analyze(SimpleTokenizer::new().tokenize("I saw the saw."));
+        let saws: Vec<_> = analyzed.iter().filter(|t| t.token.text == "saw").collect();
+        assert_eq!(saws[0].pos, Some(PosTag::VBD));
+        assert_eq!(saws[0].lemma.as_deref(), Some("see"));
+        assert_eq!(saws[1].pos, Some(PosTag::NN));
+        assert_eq!(saws[1].readings.len(), 2);
+
+        // Unknown words take the model tag instead of the suffix guesses
+        let analyzed = tagger.analyze(SimpleTokenizer::new().tokenize("The blorf cuts wood."));
+        assert_eq!(analyzed[2].pos, Some(PosTag::NN));
+        assert_eq!(analyzed[2].readings.len(), 1);
+    }
+
+    #[test]
+    fn test_morphology_lemmas() {
+        use crate::core::traits::Tokenizer;
+        use crate::tokenizer::SimpleTokenizer;
+
+        let tagger = PosTagger::new().with_morphology(EnglishMorphology::load());
+
+        // Irregular forms come from the morphology
+        let readings = tagger.readings("went");
+        assert_eq!(readings[0].pos, PosTag::VBD);
+        assert_eq!(readings[0].lemma.as_deref(), Some("go"));
+
+        // Suffix guesses are lemmatized
+        let analyzed = tagger.analyze(SimpleTokenizer::new().tokenize("walked cities"));
+        assert_eq!(analyzed[0].lemma.as_deref(), Some("walk"));
+        assert_eq!(analyzed[2].lemma.as_deref(), Some("city"));
+    }
 }
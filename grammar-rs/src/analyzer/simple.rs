@@ -30,11 +30,7 @@ impl Analyzer for PassthroughAnalyzer {
                     TokenKind::Punctuation => Some(PosTag::Punctuation),
                     _ => None,
                 };
-                AnalyzedToken {
-                    token,
-                    lemma: None,
-                    pos,
-                }
+                AnalyzedToken::new(token, None, pos)
             })
             .collect()
     }
@@ -84,16 +80,13 @@ impl Analyzer for DictAnalyzer {
             .into_iter()
             .map(|token| {
                 let lookup = self.dict.get(&token.text.to_lowercase());
-                AnalyzedToken {
-                    lemma: lookup.map(|(l, _)| l.clone()),
-                    pos: lookup.map(|(_, p)| *p).or_else(|| {
-                        match token.kind {
-                            TokenKind::Punctuation => Some(PosTag::Punctuation),
-                            _ => None,
-                        }
-                    }),
-                    token,
-                }
+                let pos = lookup.map(|(_, p)| *p).or_else(|| {
+                    match token.kind {
+                        TokenKind::Punctuation => Some(PosTag::Punctuation),
+                        _ => None,
+                    }
+                });
+                AnalyzedToken::new(token, lookup.map(|(l, _)| l.clone()), pos)
             })
             .collect()
     }
@@ -0,0 +1,79 @@
+//! Configuration hot reload (SIGHUP and `POST /admin/reload`)
+
+use axum::{
+    extract::State,
+    http::{header::AUTHORIZATION, HeaderMap, StatusCode},
+};
+use std::sync::Arc;
+
+use crate::state::AppState;
+
+/// Handle POST /admin/reload
+///
+/// Requires `Authorization: Bearer <admin_token>`; the route does not exist
+/// (404) when no token is configured.
+pub async fn reload_handler(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let Some(token) = state.admin_token.as_deref() else {
+        return Err((StatusCode::NOT_FOUND, "admin endpoints are disabled".to_string()));
+    };
+    let given = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    if !given.is_some_and(|given| same_token(given.trim(), token)) {
+        return Err((StatusCode::UNAUTHORIZED, "invalid admin token".to_string()));
+    }
+
+    state.reload().await.map_err(|e| {
+        tracing::error!("Reload failed: {}", e);
+        (StatusCode::INTERNAL_SERVER_ERROR, e)
+    })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Reload the configuration on every SIGHUP
+#[cfg(unix)]
+pub fn spawn_sighup_listener(state: Arc<AppState>) {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut hangups = match signal(SignalKind::hangup()) {
+        Ok(hangups) => hangups,
+        Err(e) => {
+            tracing::warn!("Cannot listen for SIGHUP, reload with POST /admin/reload only: {}", e);
+            return;
+        }
+    };
+    tokio::spawn(async move {
+        while hangups.recv().await.is_some() {
+            tracing::info!("SIGHUP received");
+            if let Err(e) = state.reload().await {
+                tracing::error!("Reload failed, keeping the current configuration: {}", e);
+            }
+        }
+    });
+}
+
+#[cfg(not(unix))]
+pub fn spawn_sighup_listener(_state: Arc<AppState>) {}
+
+/// Token comparison that does not stop at the first differing byte
+pub fn same_token(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given.bytes().zip(expected.bytes()).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_token() {
+        assert!(same_token("s3cret", "s3cret"));
+        assert!(!same_token("s3cret", "s3cre"));
+        assert!(!same_token("s3creT", "s3cret"));
+        assert!(!same_token("", "s3cret"));
+    }
+}
@@ -0,0 +1,277 @@
+//! Server configuration file (`grammar-api --config server.toml`)
+//!
+//! Every key is optional; see `config/server.example.toml`. The environment
+//! variables of the previous releases (`PORT`, `CACHE_SIZE`, ...) still work
+//! and take precedence over the file.
+//!
+//! Relative paths set in the file are relative to the file's directory;
+//! paths left out keep their defaults, relative to the working directory.
+//! `[pipelines]` and `[cache]` are re-read on reload (SIGHUP or
+//! `POST /admin/reload`); the other sections and the morphology paths of
+//! `[pipelines.data]` only at startup.
+
+use grammar_rs::presets::PresetConfig;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Environment variable holding the config path when `--config` is not given
+pub const CONFIG_ENV: &str = "GRAMMAR_API_CONFIG";
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+    pub server: ServerSection,
+    pub cache: CacheConfig,
+    pub user_dictionaries: UserDictionaryConfig,
+    /// Checkers, filters and data paths of each language
+    pub pipelines: PresetConfig,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerSection {
+    /// `PORT`
+    pub port: u16,
+    /// Rayon threads, 0 for all CPUs (`MAX_CPUS`)
+    pub max_cpus: usize,
+    /// Body limit of /v2/check/batch (`BATCH_MAX_BYTES`)
+    pub batch_max_bytes: usize,
+    /// Documents per /v2/check/batch request (`BATCH_MAX_DOCUMENTS`)
+    pub batch_max_documents: usize,
+    /// Enables `POST /admin/reload`, which requires this token as
+    /// `Authorization: Bearer <token>` (`ADMIN_TOKEN`)
+    pub admin_token: Option<String>,
+}
+
+impl Default for ServerSection {
+    fn default() -> Self {
+        Self {
+            port: 8081,
+            max_cpus: 0,
+            batch_max_bytes: 20 * 1024 * 1024,
+            batch_max_documents: 1_000,
+            admin_token: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheConfig {
+    /// Cached /v2/check responses (`CACHE_SIZE`)
+    pub size: u64,
+    /// Lifetime of a cached response (`CACHE_TTL_SECS`)
+    pub ttl_secs: u64,
+    /// Cached paragraphs of incremental checks (`PARAGRAPH_CACHE_SIZE`)
+    pub paragraph_size: usize,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            size: 10_000,
+            ttl_secs: 3600,
+            paragraph_size: 50_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UserDictionaryConfig {
+    /// `USER_DICT_DIR` (default: `data/user_dictionaries`)
+    pub dir: Option<PathBuf>,
+    /// `USER_DICT_MAX_WORDS`
+    pub max_words: usize,
+    /// `apiKey` of each user allowed to use a dictionary; /v2/words is
+    /// disabled without any
+    pub api_keys: BTreeMap<String, String>,
+}
+
+impl Default for UserDictionaryConfig {
+    fn default() -> Self {
+        Self {
+            dir: None,
+            max_words: 10_000,
+            api_keys: BTreeMap::new(),
+        }
+    }
+}
+
+impl UserDictionaryConfig {
+    /// Directory of the word lists
+    pub fn dir(&self) -> &Path {
+        self.dir.as_deref().unwrap_or(Path::new("data/user_dictionaries"))
+    }
+}
+
+impl ServerConfig {
+    /// Read the config file (defaults without one), then the environment
+    pub fn load(path: Option<&Path>) -> Result<Self, String> {
+        let mut config = match path {
+            Some(path) => Self::from_file(path)?,
+            None => Self::default(),
+        };
+        config.apply_env(|name| std::env::var(name).ok())?;
+        Ok(config)
+    }
+
+    /// Parse and validate a config file
+    pub fn from_file(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        let mut config = Self::parse(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        let base = path.parent().unwrap_or(Path::new(""));
+        config.pipelines.resolve_paths(base);
+        if let Some(dir) = config.user_dictionaries.dir.as_mut().filter(|dir| dir.is_relative()) {
+            *dir = base.join(&*dir);
+        }
+        Ok(config)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let config: Self = toml::from_str(text).map_err(|e| e.to_string())?;
+        config.pipelines.validate()?;
+        Ok(config)
+    }
+
+    /// Override the file with the environment variables that are set
+    fn apply_env(&mut self, var: impl Fn(&str) -> Option<String>) -> Result<(), String> {
+        fn set<T: FromStr>(value: &mut T, name: &str, var: &impl Fn(&str) -> Option<String>) -> Result<(), String> {
+            if let Some(text) = var(name) {
+                *value = text.trim().parse().map_err(|_| format!("invalid {}: {:?}", name, text))?;
+            }
+            Ok(())
+        }
+
+        set(&mut self.server.port, "PORT", &var)?;
+        set(&mut self.server.max_cpus, "MAX_CPUS", &var)?;
+        set(&mut self.server.batch_max_bytes, "BATCH_MAX_BYTES", &var)?;
+        set(&mut self.server.batch_max_documents, "BATCH_MAX_DOCUMENTS", &var)?;
+        set(&mut self.cache.size, "CACHE_SIZE", &var)?;
+        set(&mut self.cache.ttl_secs, "CACHE_TTL_SECS", &var)?;
+        set(&mut self.cache.paragraph_size, "PARAGRAPH_CACHE_SIZE", &var)?;
+        if let Some(dir) = var("USER_DICT_DIR") {
+            self.user_dictionaries.dir = Some(PathBuf::from(dir));
+        }
+        set(&mut self.user_dictionaries.max_words, "USER_DICT_MAX_WORDS", &var)?;
+        if let Some(token) = var("ADMIN_TOKEN") {
+            self.server.admin_token = Some(token);
+        }
+        Ok(())
+    }
+}
+
+/// Config path from `--config <path>` / `--config=<path>`, else `GRAMMAR_API_CONFIG`
+pub fn config_path(args: impl IntoIterator<Item = String>) -> Option<PathBuf> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--config" {
+            return args.next().map(PathBuf::from);
+        }
+        if let Some(path) = arg.strip_prefix("--config=") {
+            return Some(PathBuf::from(path));
+        }
+    }
+    std::env::var_os(CONFIG_ENV).map(PathBuf::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sections() {
+        let config = ServerConfig::parse(
+            r#"
+            [server]
+            port = 9000
+
+            [cache]
+            size = 500
+
+            [user_dictionaries.api_keys]
+            alice = "s3cret"
+
+            [pipelines.en]
+            disabled_checkers = ["style"]
+            ngram_min_factor = 10.0
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.server.port, 9000);
+        assert_eq!(config.server.batch_max_documents, 1_000);
+        assert_eq!(config.cache.size, 500);
+        assert_eq!(config.cache.ttl_secs, 3600);
+        assert_eq!(config.user_dictionaries.api_keys["alice"], "s3cret");
+        assert!(!config.pipelines.en.is_enabled("style"));
+        assert_eq!(config.pipelines.en.ngram_min_factor, Some(10.0));
+    }
+
+    #[test]
+    fn test_invalid_files_are_rejected() {
+        assert!(ServerConfig::parse("[server]\nport = \"http\"").is_err());
+        assert!(ServerConfig::parse("[sever]\nport = 9000").is_err());
+        assert!(ServerConfig::parse("[pipelines.fr]\ncheckers = [\"speling\"]").is_err());
+    }
+
+    #[test]
+    fn test_env_overrides_file() {
+        let mut config = ServerConfig::parse("[cache]\nsize = 500\nttl_secs = 60").unwrap();
+        config
+            .apply_env(|name| (name == "CACHE_SIZE").then(|| "42".to_string()))
+            .unwrap();
+        assert_eq!(config.cache.size, 42);
+        assert_eq!(config.cache.ttl_secs, 60);
+
+        let err = config.apply_env(|name| (name == "PORT").then(|| "http".to_string()));
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_relative_paths_follow_the_file() {
+        let dir = std::env::temp_dir().join(format!("grammar-rs-config-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("server.toml");
+        std::fs::write(&path, "[user_dictionaries]\ndir = \"words\"\n[pipelines.data]\nngrams = \"/srv/ngrams\"\n").unwrap();
+
+        let config = ServerConfig::from_file(&path).unwrap();
+        assert_eq!(config.user_dictionaries.dir(), dir.join("words"));
+        assert_eq!(config.pipelines.data.ngrams.as_deref(), Some(Path::new("/srv/ngrams")));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_default_paths_ignore_the_file() {
+        let dir = std::env::temp_dir().join(format!("grammar-rs-config-defaults-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("server.toml");
+        std::fs::write(&path, "[pipelines.en]\ndisabled_checkers = [\"style\"]\n").unwrap();
+
+        let config = ServerConfig::from_file(&path).unwrap();
+        assert_eq!(config.user_dictionaries.dir(), Path::new("data/user_dictionaries"));
+        assert_eq!(config.pipelines.data.dictionaries_dir(), Path::new("data/dictionaries"));
+        assert_eq!(config.pipelines.data.models_dir(), Path::new("data/models"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_example_file() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("config/server.example.toml");
+        let config = ServerConfig::from_file(&path).unwrap();
+        assert_eq!(config.server, ServerSection::default());
+        assert_eq!(config.cache, CacheConfig::default());
+        assert_eq!(config.pipelines.en, Default::default());
+        assert!(config.pipelines.data.dictionaries_dir().ends_with("data/dictionaries"));
+    }
+
+    #[test]
+    fn test_config_path_argument() {
+        let args = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(config_path(args(&["grammar-api", "--config", "a.toml"])), Some(PathBuf::from("a.toml")));
+        assert_eq!(config_path(args(&["grammar-api", "--config=b.toml"])), Some(PathBuf::from("b.toml")));
+    }
+}
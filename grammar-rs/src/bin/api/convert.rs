@@ -1,8 +1,19 @@
 //! Convert grammar-rs types to LanguageTool-compatible format
 
-use grammar_rs::core::{CheckResult, Match as GrsMatch, Severity};
+use grammar_rs::checker::data::get_en_word_definition;
+use grammar_rs::core::registry::{split_sub_rule_id, RuleCategory, RuleMetadata, RuleRegistry};
+use grammar_rs::core::traits::SentenceSplitter;
+use grammar_rs::core::profile::CheckProfile;
+use grammar_rs::core::{CheckResult, IssueType, Match as GrsMatch, Severity};
+use grammar_rs::prelude::SimpleSentenceSplitter;
+use std::ops::Range;
+use std::time::Duration;
 use crate::types::*;
 
+/// Rules that judge the whole sentence: they may fire on a sentence the
+/// user is still typing (LanguageTool `ignoreForIncompleteSentence`)
+const SENTENCE_LEVEL_RULES: &[&str] = &["SENTENCE_FRAGMENT", "SENTENCE_LENGTH", "COMMA_SPLICE"];
+
 /// Convert byte offset to character offset
 /// Rust strings are UTF-8 (bytes), but JavaScript uses UTF-16 code units
 /// LanguageTool returns character offsets, so we need to convert
@@ -18,34 +29,93 @@ pub fn convert_result(
     text: &str,
     lang_code: &str,
     confidence: f32,
+    registry: &RuleRegistry,
 ) -> LanguageToolResponse {
-    let matches: Vec<LTMatch> = result
-        .matches
-        .into_iter()
-        .map(|m| convert_match(m, text))
-        .collect();
+    let matches = convert_matches(result.matches, text, lang_code, registry);
 
     LanguageToolResponse {
-        software: Software {
-            name: "grammar-rs".to_string(),
-            version: env!("CARGO_PKG_VERSION").to_string(),
-            api_version: 1,
-        },
-        language: LanguageInfo {
+        software: software(),
+        warnings: Warnings::default(),
+        language: language_info(lang_code, confidence),
+        matches,
+        profile: None,
+    }
+}
+
+/// Timings of a profiled check, slowest checker first
+pub fn profile_info(profile: &CheckProfile) -> ProfileInfo {
+    let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+    ProfileInfo {
+        total_ms: ms(profile.total),
+        filter_ms: ms(profile.filter),
+        tokenize_ms: ms(profile.tokenize),
+        analyze_ms: ms(profile.analyze),
+        checkers: profile
+            .checkers_by_time()
+            .into_iter()
+            .map(|checker| CheckerTiming {
+                name: checker.name,
+                elapsed_ms: ms(checker.elapsed),
+                matches: checker.matches,
+                slowest_rules: checker
+                    .slowest_rules
+                    .iter()
+                    .map(|rule| RuleTimingInfo {
+                        rule_id: rule.rule_id.clone(),
+                        elapsed_ms: ms(rule.elapsed),
+                        matches: rule.matches,
+                    })
+                    .collect(),
+            })
+            .collect(),
+    }
+}
+
+/// Software block of a response
+pub fn software() -> Software {
+    Software {
+        name: "grammar-rs".to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        api_version: 1,
+    }
+}
+
+/// Language block of a response
+pub fn language_info(lang_code: &str, confidence: f32) -> LanguageInfo {
+    LanguageInfo {
+        code: lang_code.to_string(),
+        name: language_name(lang_code).to_string(),
+        detected_language: DetectedLanguage {
             code: lang_code.to_string(),
             name: language_name(lang_code).to_string(),
-            detected_language: DetectedLanguage {
-                code: lang_code.to_string(),
-                name: language_name(lang_code).to_string(),
-                confidence,
-            },
+            confidence,
         },
-        matches,
     }
 }
 
+/// Convert grammar-rs matches to LanguageTool format (offsets relative to `text`)
+pub fn convert_matches(
+    matches: Vec<GrsMatch>,
+    text: &str,
+    lang_code: &str,
+    registry: &RuleRegistry,
+) -> Vec<LTMatch> {
+    // Split once: each match then finds its sentence by binary search
+    let sentences = SimpleSentenceSplitter::new().split(text);
+    matches
+        .into_iter()
+        .map(|m| convert_match(m, text, lang_code, registry, &sentences))
+        .collect()
+}
+
 /// Convert a single grammar-rs Match to LanguageTool format
-fn convert_match(m: GrsMatch, text: &str) -> LTMatch {
+fn convert_match(
+    m: GrsMatch,
+    text: &str,
+    lang_code: &str,
+    registry: &RuleRegistry,
+    sentences: &[Range<usize>],
+) -> LTMatch {
     // Calculate context (40 chars around the error, capped at text boundaries)
     let context_start = m.span.start.saturating_sub(20);
     let context_end = (m.span.end + 20).min(text.len());
@@ -65,6 +135,15 @@ fn convert_match(m: GrsMatch, text: &str) -> LTMatch {
     let context_char_offset = byte_offset_to_char_offset(text, m.span.start)
         - byte_offset_to_char_offset(text, context_start);
 
+    let rule = rule_info(registry, &m);
+    let type_name = if rule.issue_type == IssueType::Misspelling.as_str() {
+        "UnknownWord"
+    } else if m.severity == Severity::Hint {
+        "Hint"
+    } else {
+        "Other"
+    };
+
     LTMatch {
         message: m.message.clone(),
         short_message: short_message(&m.message),
@@ -74,18 +153,40 @@ fn convert_match(m: GrsMatch, text: &str) -> LTMatch {
             .suggestions
             .iter()
             .take(5) // Limit to 5 suggestions
-            .map(|s| Replacement { value: s.clone() })
+            .map(|s| Replacement {
+                value: s.clone(),
+                short_description: short_description(s, lang_code),
+            })
             .collect(),
-        rule: RuleInfo {
-            id: m.rule_id.clone(),
-            category: categorize_rule(&m.rule_id, m.severity),
-        },
         context: Context {
             text: context_text.to_string(),
             offset: context_char_offset,
             length: char_length,
         },
+        sentence: sentence_at(text, &m.span, sentences).to_string(),
+        match_type: MatchType { type_name },
+        ignore_for_incomplete_sentence: SENTENCE_LEVEL_RULES.contains(&m.rule_id.as_str()),
+        // Matches are final once the checkers ran: no extra context is needed
+        context_for_sure_match: 0,
+        rule,
+    }
+}
+
+/// Sentence containing the start of `span`
+///
+/// `sentences` are the sorted sentence spans of the whole `text`; a match
+/// that starts between two sentences belongs to the next one.
+fn sentence_at<'a>(text: &'a str, span: &Range<usize>, sentences: &[Range<usize>]) -> &'a str {
+    let index = sentences.partition_point(|sentence| sentence.end <= span.start);
+    sentences.get(index).map_or("", |sentence| &text[sentence.clone()])
+}
+
+/// Short definition of a replacement word (LanguageTool `word_definitions.txt`)
+fn short_description(replacement: &str, lang_code: &str) -> Option<String> {
+    if !lang_code.starts_with("en") {
+        return None;
     }
+    get_en_word_definition(replacement).map(str::to_string)
 }
 
 /// Extract a short message from the full message
@@ -100,67 +201,66 @@ fn short_message(message: &str) -> String {
         .collect()
 }
 
-/// Map rule IDs to categories
-fn categorize_rule(rule_id: &str, severity: Severity) -> Category {
-    let (cat_id, cat_name) = match rule_id {
-        // Spelling
-        "SPELL" | "HUNSPELL" => ("TYPOS", "Typo"),
-
-        // Typography
-        "DOUBLE_SPACE" | "REPEATED_PUNCTUATION" | "MISSING_SPACE_AFTER_PUNCT"
-        | "TYPOGRAPHIC_QUOTES" => ("TYPOGRAPHY", "Typography"),
-
-        // Repeated words
-        "REPEATED_WORD" => ("DUPLICATION", "Duplication"),
-
-        // Grammar - English
-        "A_AN" | "IMPROVED_A_AN" | "SUBJECT_VERB_AGREEMENT" | "ITS_ITS" | "YOUR_YOURE"
-        | "THEIR_THEYRE_THERE" | "COMMA_SPLICE" | "LESS_FEWER" | "WHO_WHOM"
-        | "GOOD_WELL" | "DOUBLE_NEGATIVE" | "SENTENCE_FRAGMENT" => ("GRAMMAR", "Grammar"),
-
-        // Grammar - French
-        _ if rule_id.starts_with("FR_") => ("GRAMMAR", "French Grammar"),
-
-        // Style
-        "PASSIVE_VOICE" | "WORDINESS" | "SENTENCE_LENGTH" | "CLICHE" | "REDUNDANCY" => {
-            ("STYLE", "Style")
-        }
+/// Rule information of a match: the match's own issue type first, then the
+/// registry metadata, then a guess from the rule ID
+fn rule_info(registry: &RuleRegistry, m: &GrsMatch) -> RuleInfo {
+    let metadata = registry.get(&m.rule_id);
+    let category = rule_category(registry, &m.rule_id, m.severity);
+    let issue_type = m
+        .issue_type
+        .or_else(|| metadata.map(|rule| rule.issue_type))
+        .unwrap_or_else(|| RuleCategory::guess(&m.rule_id, m.severity).issue_type());
 
-        // Confusion
-        "EN_CONFUSION" | "FR_CONFUSION" => ("CONFUSED_WORDS", "Commonly Confused Words"),
-
-        // Coherency
-        "COHERENCY" | "EN_COHERENCY" => ("CONSISTENCY", "Consistency"),
-
-        // Diacritics
-        "DIACRITICS" | "EN_DIACRITICS" => ("TYPOGRAPHY", "Typography"),
-
-        // Contractions
-        "CONTRACTION" | "EN_CONTRACTION" => ("GRAMMAR", "Grammar"),
-
-        // Pattern-based rules
-        _ if rule_id.starts_with("PATTERN_") => {
-            match severity {
-                Severity::Error => ("GRAMMAR", "Grammar"),
-                Severity::Warning => ("STYLE", "Style"),
-                Severity::Hint => ("HINTS", "Hints"),
-            }
-        }
+    let (id, sub_id) = split_sub_rule_id(&m.rule_id);
+    RuleInfo {
+        id: id.to_string(),
+        sub_id: sub_id.map(str::to_string),
+        description: metadata.map_or_else(|| m.rule_id.clone(), |rule| rule.description.clone()),
+        issue_type: issue_type.as_str().to_string(),
+        urls: metadata
+            .and_then(|rule| rule.url.clone())
+            .map(|value| Url { value })
+            .into_iter()
+            .collect(),
+        category,
+    }
+}
 
-        // Replace rules
-        _ if rule_id.starts_with("REPLACE_") => ("STYLE", "Style"),
+/// Category of a rule, from the pipeline registry when the rule is described there
+///
+/// Rules missing from the registry fall back to `RuleCategory::guess`.
+pub fn rule_category(registry: &RuleRegistry, rule_id: &str, severity: Severity) -> Category {
+    let category = registry
+        .get(rule_id)
+        .map_or_else(|| RuleCategory::guess(rule_id, severity), |rule| rule.category);
+    Category {
+        id: category.id().to_string(),
+        name: category.name().to_string(),
+    }
+}
 
-        // Default
-        _ => match severity {
-            Severity::Error => ("GRAMMAR", "Grammar"),
-            Severity::Warning => ("STYLE", "Style"),
-            Severity::Hint => ("MISC", "Miscellaneous"),
+/// Convert registry metadata to the /v2/rules format
+pub fn convert_rule(rule: &RuleMetadata) -> RuleDescription {
+    RuleDescription {
+        id: rule.id.clone(),
+        description: rule.description.clone(),
+        category: Category {
+            id: rule.category.id().to_string(),
+            name: rule.category.name().to_string(),
         },
-    };
-
-    Category {
-        id: cat_id.to_string(),
-        name: cat_name.to_string(),
+        issue_type: rule.issue_type.as_str(),
+        level: rule.level.as_str(),
+        default_on: rule.default_on,
+        examples: rule
+            .examples
+            .iter()
+            .map(|e| RuleExampleInfo {
+                text: e.text.clone(),
+                is_correct: e.is_correct,
+                correction: e.correction.clone(),
+            })
+            .collect(),
+        url: rule.url.clone(),
     }
 }
 
@@ -197,3 +297,110 @@ fn ceil_char_boundary(s: &str, mut index: usize) -> usize {
     }
     index
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use grammar_rs::core::registry::RuleMetadata;
+
+    fn grs_match(span: Range<usize>, rule_id: &str, suggestions: &[&str]) -> GrsMatch {
+        GrsMatch {
+            span,
+            message: "Possible mistake. Check it.".to_string(),
+            rule_id: rule_id.to_string(),
+            suggestions: suggestions.iter().map(|s| s.to_string()).collect(),
+            severity: Severity::Error,
+            issue_type: None,
+        }
+    }
+
+    #[test]
+    fn test_sentence_at() {
+        let text = "First one. Second teh one.\n\nOther paragraph.";
+        let sentences = SimpleSentenceSplitter::new().split(text);
+        assert_eq!(sentence_at(text, &(18..21), &sentences), "Second teh one.");
+        assert_eq!(sentence_at(text, &(0..5), &sentences), "First one.");
+        assert_eq!(sentence_at(text, &(30..35), &sentences), "Other paragraph.");
+
+        let crlf = "First one.\r\n\r\nSecond teh one.";
+        let sentences = SimpleSentenceSplitter::new().split(crlf);
+        assert_eq!(sentence_at(crlf, &(0..5), &sentences), "First one.");
+        assert_eq!(sentence_at(crlf, &(21..24), &sentences), "Second teh one.");
+    }
+
+    #[test]
+    fn test_full_match_schema() {
+        let mut registry = RuleRegistry::new();
+        registry.register(
+            RuleMetadata::new("SPELL", "Possible spelling mistake", RuleCategory::Typos)
+                .with_url("https://example.com/spell"),
+        );
+        let text = "I accept teh offer.";
+        let matches = vec![
+            grs_match(9..12, "SPELL", &["the", "accept"]),
+            grs_match(0..19, "SENTENCE_FRAGMENT", &[]),
+            grs_match(2..8, "GROUP[3]", &[]),
+        ];
+
+        let converted = convert_matches(matches, text, "en-US", &registry);
+        let json = serde_json::to_value(&converted).unwrap();
+
+        let spell = &json[0];
+        assert_eq!(spell["sentence"], text);
+        assert_eq!(spell["type"]["typeName"], "UnknownWord");
+        assert_eq!(spell["rule"]["description"], "Possible spelling mistake");
+        assert_eq!(spell["rule"]["issueType"], "misspelling");
+        assert_eq!(spell["rule"]["urls"][0]["value"], "https://example.com/spell");
+        assert_eq!(spell["contextForSureMatch"], 0);
+        assert_eq!(spell["ignoreForIncompleteSentence"], false);
+        assert!(spell["replacements"][0].get("shortDescription").is_none());
+        assert_eq!(spell["replacements"][1]["shortDescription"], "to agree, to endure");
+
+        let fragment = &json[1];
+        assert_eq!(fragment["type"]["typeName"], "Other");
+        assert_eq!(fragment["ignoreForIncompleteSentence"], true);
+        assert!(fragment["rule"].get("urls").is_none());
+        assert!(fragment["rule"].get("subId").is_none());
+
+        // Sub-rules report their group ID, as LanguageTool does
+        assert_eq!(json[2]["rule"]["id"], "GROUP");
+        assert_eq!(json[2]["rule"]["subId"], "3");
+    }
+
+    #[test]
+    fn test_short_description_is_english_only() {
+        assert_eq!(short_description("accept", "en-GB").as_deref(), Some("to agree, to endure"));
+        assert_eq!(short_description("accept", "fr"), None);
+    }
+
+    #[test]
+    fn test_profile_schema() {
+        use grammar_rs::core::profile::{CheckerProfile, RuleTiming};
+
+        let checker = |name, micros, slowest_rules| CheckerProfile {
+            name,
+            elapsed: Duration::from_micros(micros),
+            matches: 1,
+            slowest_rules,
+        };
+        let profile = CheckProfile {
+            total: Duration::from_millis(3),
+            checkers: vec![
+                checker("SpellChecker", 200, Vec::new()),
+                checker(
+                    "DynamicPatternChecker",
+                    1500,
+                    vec![RuleTiming { rule_id: "EN_RULE".to_string(), elapsed: Duration::from_micros(900), matches: 1 }],
+                ),
+            ],
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(profile_info(&profile)).unwrap();
+        assert_eq!(json["totalMs"], 3.0);
+        assert_eq!(json["checkers"][0]["name"], "DynamicPatternChecker");
+        assert_eq!(json["checkers"][0]["slowestRules"][0]["ruleId"], "EN_RULE");
+        assert_eq!(json["checkers"][0]["slowestRules"][0]["elapsedMs"], 0.9);
+        assert!(json["checkers"][1].get("slowestRules").is_none());
+    }
+}
@@ -0,0 +1,117 @@
+//! Request body extractor accepting form encoding and JSON
+//!
+//! LanguageTool clients post `application/x-www-form-urlencoded` bodies;
+//! newer clients and scripts send the same fields as `application/json`.
+
+use axum::{
+    extract::{FromRequest, Request},
+    http::{header::CONTENT_TYPE, StatusCode},
+    Form, Json,
+};
+use serde::de::DeserializeOwned;
+
+/// Body decoded from JSON when `Content-Type` is JSON, from a form otherwise
+#[derive(Debug)]
+pub struct FormOrJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for FormOrJson<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = (StatusCode, String);
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        if is_json(req.headers().get(CONTENT_TYPE).and_then(|v| v.to_str().ok())) {
+            Json::<T>::from_request(req, state)
+                .await
+                .map(|Json(value)| Self(value))
+                .map_err(|e| (e.status(), e.body_text()))
+        } else {
+            Form::<T>::from_request(req, state)
+                .await
+                .map(|Form(value)| Self(value))
+                .map_err(|e| (e.status(), e.body_text()))
+        }
+    }
+}
+
+/// `application/json`, with or without parameters, or a `+json` media type
+fn is_json(content_type: Option<&str>) -> bool {
+    let Some(content_type) = content_type else { return false };
+    let mime = content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+    mime == "application/json" || (mime.starts_with("application/") && mime.ends_with("+json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BatchRequest, CheckRequest};
+    use axum::body::Body;
+
+    async fn extract_as<T: DeserializeOwned>(content_type: &str, body: &str) -> Result<T, (StatusCode, String)> {
+        let req = Request::builder()
+            .method("POST")
+            .header(CONTENT_TYPE, content_type)
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        FormOrJson::<T>::from_request(req, &()).await.map(|FormOrJson(req)| req)
+    }
+
+    async fn extract(content_type: &str, body: &str) -> Result<CheckRequest, (StatusCode, String)> {
+        extract_as(content_type, body).await
+    }
+
+    #[test]
+    fn test_is_json() {
+        assert!(is_json(Some("application/json")));
+        assert!(is_json(Some("application/json; charset=utf-8")));
+        assert!(is_json(Some("application/vnd.api+json")));
+        assert!(!is_json(Some("application/x-www-form-urlencoded")));
+        assert!(!is_json(None));
+    }
+
+    #[tokio::test]
+    async fn test_form_and_json_bodies() {
+        let form = extract(
+            "application/x-www-form-urlencoded",
+            "language=fr&text=Bonjour&enabledOnly=true&enabledRules=A%2CB",
+        )
+        .await
+        .unwrap();
+        let json = extract(
+            "application/json",
+            r#"{"language":"fr","text":"Bonjour","enabledOnly":true,"enabledRules":["A","B"]}"#,
+        )
+        .await
+        .unwrap();
+
+        for req in [form, json] {
+            assert_eq!(req.text, "Bonjour");
+            assert!(req.enabled_only);
+            assert_eq!(req.enabled_rules.as_deref(), Some("A,B"));
+        }
+
+        // Batch bodies accept the same list and flag forms
+        let batch: BatchRequest = extract_as(
+            "application/json",
+            r#"{"language":"fr","documents":[{"text":"Bonjour"}],"enabledOnly":"true",
+                "enabledRules":["A","B"],"disabledCategories":"STYLE"}"#,
+        )
+        .await
+        .unwrap();
+        assert!(batch.enabled_only);
+        assert_eq!(batch.enabled_rules.as_deref(), Some("A,B"));
+        assert_eq!(batch.disabled_categories.as_deref(), Some("STYLE"));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_bodies_are_rejected() {
+        let (status, _) = extract("application/json", "{not json").await.unwrap_err();
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (status, _) = extract("application/x-www-form-urlencoded", "language=fr&enabledOnly=maybe")
+            .await
+            .unwrap_err();
+        assert!(status.is_client_error());
+    }
+}
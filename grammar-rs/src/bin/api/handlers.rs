@@ -1,109 +1,221 @@
 //! HTTP request handlers
 
 use axum::{
-    extract::{Form, State},
+    extract::{Query, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
 };
 use std::sync::Arc;
 
-use crate::convert::convert_result;
+use crate::convert::{
+    convert_matches, convert_result, convert_rule, language_info, profile_info, rule_category, software,
+};
+use crate::extract::FormOrJson;
 use crate::state::AppState;
 use crate::types::*;
+use crate::words::request_words;
 
-use grammar_rs::prelude::{GrammarChecker, SimpleTokenizer, PassthroughAnalyzer};
+use grammar_rs::prelude::{SimpleTokenizer, PassthroughAnalyzer};
 use grammar_rs::core::traits::{Tokenizer, Analyzer, Checker};
-use grammar_rs::checker::L2ConfusionChecker;
+use grammar_rs::checker::{L2ConfusionChecker, UserDictionary};
+use grammar_rs::core::pipeline::par_map_bounded;
+use grammar_rs::core::profile::DEFAULT_TOP_RULES;
+use grammar_rs::presets::normalize_language;
+use grammar_rs::core::registry::RuleRegistry;
+use grammar_rs::core::{CheckResult, Match};
 
 /// Handle POST /v2/check
 ///
 /// Main grammar checking endpoint, compatible with LanguageTool API
+/// (form-encoded or JSON body)
 pub async fn check_handler(
     State(state): State<Arc<AppState>>,
-    Form(req): Form<CheckRequest>,
-) -> impl IntoResponse {
+    FormOrJson(req): FormOrJson<CheckRequest>,
+) -> Result<Json<LanguageToolResponse>, (StatusCode, String)> {
     let start = std::time::Instant::now();
 
+    // Rule and category selection (enabledRules, disabledRules, enabledOnly, ...)
+    let rules = RuleSelection::from_request(&req)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+    // `data` or `text` + `markup`: the checkers only see the plain text
+    let annotated = req.annotated_text()
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
     // Detect or use specified language
-    let (lang_code, confidence) = if req.language == "auto" {
-        let detection = state.language_detector.detect(&req.text);
-        let code = detection.code().to_string();
-        let conf = if detection == grammar_rs::lang_detect::Language::Unknown {
-            0.5
-        } else {
-            0.95
-        };
-        (code, conf)
-    } else {
-        (normalize_language(&req.language), 1.0)
-    };
+    let (lang_code, confidence) = resolve_language(
+        &state,
+        &req.language,
+        annotated.plain_text(),
+        req.preferred_variants.as_deref(),
+    );
+
+    // Personal dictionary of `username` (its version is part of the cache key)
+    let user_words = request_words(&state, req.username.as_deref(), req.api_key.as_deref()).await?;
+    let words_version = user_words.as_ref().map_or(0, |words| words.version);
+    let user_dictionary = user_words.map(|words| Arc::clone(&words.dictionary));
 
     // Create cache key
-    let cache_key = crate::types::CacheKey::from_request(&req, &lang_code);
-
-    // Check cache first
-    if let Some(cached_response) = state.cache.get(&cache_key).await {
-        let elapsed = start.elapsed();
-        tracing::info!(
-            lang = %lang_code,
-            text_len = req.text.len(),
-            elapsed_ms = elapsed.as_millis(),
-            cache_hit = true,
-            "Check completed (cache hit)"
-        );
-        return Json((*cached_response).clone());
+    let cache_key = crate::types::CacheKey::from_request(&req, &lang_code, &rules, words_version);
+
+    // Pipelines and cache of the current configuration, kept for the whole request
+    let checkers = state.checkers();
+
+    // Check cache first (profiled checks always run)
+    if !req.profile {
+        let cached = checkers.cache.get(&cache_key).await;
+        state.metrics.record_cache(cached.is_some());
+        if let Some(cached_response) = cached {
+            let elapsed = start.elapsed();
+            tracing::info!(
+                lang = %lang_code,
+                text_len = req.text.len(),
+                elapsed_ms = elapsed.as_millis(),
+                cache_hit = true,
+                "Check completed (cache hit)"
+            );
+            return Ok(Json((*cached_response).clone()));
+        }
     }
 
     // Cache miss - compute result
-    let text = req.text.clone();
-    let pipeline_clone = Arc::clone(if lang_code.starts_with("fr") {
-        &state.fr_pipeline
-    } else {
-        &state.en_pipeline
-    });
+    let pipeline_clone = Arc::clone(checkers.get_pipeline(&lang_code));
+    let profiled = req.profile;
+    let incremental = (req.incremental && !profiled).then(|| Arc::clone(checkers.get_incremental(&lang_code)));
 
     // Check if L2 French confusion checking should be enabled
     let use_l2_fr = req.mother_tongue.as_deref() == Some("fr") && !lang_code.starts_with("fr");
 
-    let result = tokio::task::spawn_blocking(move || {
-        let mut result = pipeline_clone.check_text(&text);
-
-        // Add L2 French confusion checking for French native speakers writing in English
-        if use_l2_fr {
-            let l2_checker = L2ConfusionChecker::new();
-            let tokenizer = SimpleTokenizer::new();
-            let analyzer = PassthroughAnalyzer::new();
-            let tokens = tokenizer.tokenize(&text);
-            let analyzed = analyzer.analyze(tokens);
-            let l2_result = l2_checker.check(&text, &analyzed);
-            result.matches.extend(l2_result.matches);
-        }
+    let filter_rules = rules.clone();
+
+    let level = req.check_level();
 
-        result
+    let check_start = std::time::Instant::now();
+    let (result, annotated, profile) = tokio::task::spawn_blocking(move || {
+        let text = annotated.plain_text();
+        let (mut result, profile) = match incremental {
+            _ if profiled => {
+                let (result, profile) = pipeline_clone.check_text_profiled_with_level(text, level, DEFAULT_TOP_RULES);
+                (result, Some(profile))
+            }
+            // Incremental mode only re-checks the paragraphs that changed
+            Some(checker) => (checker.check_with_level(text, level).result, None),
+            None => (pipeline_clone.check_text_with_level(text, level), None),
+        };
+        let registry = pipeline_clone.rule_registry();
+        postprocess(&mut result, text, registry, use_l2_fr, &filter_rules, user_dictionary.as_deref());
+        annotated.map_matches(&mut result.matches);
+        (result, annotated, profile)
     })
     .await
     .unwrap();
 
-    // Convert to LanguageTool format
-    let response = convert_result(result, &req.text, &lang_code, confidence);
+    // Convert to LanguageTool format (offsets in the original document)
+    let registry = checkers.get_pipeline(&lang_code).rule_registry();
+    state.metrics.record_check(&lang_code, annotated.plain_text().len(), check_start.elapsed());
+    state.metrics.record_matches(&lang_code, result.matches.iter().map(|m| m.rule_id.as_str()));
+    let mut response = convert_result(result, annotated.original_text(), &lang_code, confidence, registry);
 
-    // Store in cache
-    let response_arc = Arc::new(response.clone());
-    state.cache.insert(cache_key, response_arc).await;
+    // Store in cache (profiled responses carry timings of this run only)
+    match profile {
+        Some(profile) => response.profile = Some(profile_info(&profile)),
+        None => checkers.cache.insert(cache_key, Arc::new(response.clone())).await,
+    }
 
     let elapsed = start.elapsed();
     tracing::info!(
         lang = %lang_code,
         mother_tongue = ?req.mother_tongue,
+        level = level.as_str(),
+        incremental = req.incremental,
+        profile = req.profile,
         matches = response.matches.len(),
-        text_len = req.text.len(),
+        text_len = annotated.original_text().len(),
         elapsed_ms = elapsed.as_millis(),
         cache_hit = false,
         "Check completed (cache miss)"
     );
 
-    Json(response)
+    Ok(Json(response))
+}
+
+/// Handle POST /v2/check/batch
+///
+/// Checks every document of a JSON batch in parallel and returns one
+/// LanguageTool-style result per document, with its checking time
+pub async fn check_batch_handler(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<BatchRequest>,
+) -> Result<Json<BatchResponse>, (StatusCode, String)> {
+    let start = std::time::Instant::now();
+
+    let rules = RuleSelection::from_batch_request(&req)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+    if req.documents.len() > state.batch_max_documents {
+        return Err((
+            StatusCode::PAYLOAD_TOO_LARGE,
+            format!(
+                "batch has {} documents, the limit is {}",
+                req.documents.len(),
+                state.batch_max_documents
+            ),
+        ));
+    }
+
+    let level = req.check_level();
+    let documents = req.documents.len();
+    let user_words = request_words(&state, req.username.as_deref(), req.api_key.as_deref()).await?;
+    let blocking_state = Arc::clone(&state);
+    let checkers = state.checkers();
+
+    let results = tokio::task::spawn_blocking(move || {
+        let state = blocking_state;
+        par_map_bounded(&req.documents, |doc| {
+            let doc_start = std::time::Instant::now();
+            let (lang_code, confidence) = resolve_language(
+                &state,
+                &req.language,
+                &doc.text,
+                req.preferred_variants.as_deref(),
+            );
+            let pipeline = checkers.get_pipeline(&lang_code);
+            let registry = pipeline.rule_registry();
+            let use_l2_fr =
+                req.mother_tongue.as_deref() == Some("fr") && !lang_code.starts_with("fr");
+
+            let mut result = pipeline.check_text_with_level(&doc.text, level);
+            let user_dictionary = user_words.as_ref().map(|words| words.dictionary.as_ref());
+            postprocess(&mut result, &doc.text, registry, use_l2_fr, &rules, user_dictionary);
+            state.metrics.record_check(&lang_code, doc.text.len(), doc_start.elapsed());
+            state.metrics.record_matches(&lang_code, result.matches.iter().map(|m| m.rule_id.as_str()));
+
+            BatchResult {
+                id: doc.id.clone(),
+                language: language_info(&lang_code, confidence),
+                matches: convert_matches(result.matches, &doc.text, &lang_code, registry),
+                elapsed_ms: doc_start.elapsed().as_secs_f64() * 1000.0,
+            }
+        })
+    })
+    .await
+    .unwrap();
+
+    let elapsed = start.elapsed();
+    tracing::info!(
+        documents,
+        level = level.as_str(),
+        matches = results.iter().map(|r| r.matches.len()).sum::<usize>(),
+        elapsed_ms = elapsed.as_millis(),
+        "Batch check completed"
+    );
+
+    Ok(Json(BatchResponse {
+        software: software(),
+        results,
+        elapsed_ms: elapsed.as_millis() as u64,
+    }))
 }
 
 /// Handle GET /v2/languages
@@ -136,18 +248,186 @@ pub async fn languages_handler() -> impl IntoResponse {
     Json(languages)
 }
 
+/// Handle GET /v2/rules
+///
+/// Lists every rule the pipeline of the given language can emit
+pub async fn rules_handler(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<RulesQuery>,
+) -> impl IntoResponse {
+    let lang_code = normalize_language(query.language.as_deref().unwrap_or("en"));
+    let pipeline = Arc::clone(state.checkers().get_pipeline(&lang_code));
+
+    // The first call builds the registry, which walks every checker
+    let rules = tokio::task::spawn_blocking(move || {
+        pipeline.rule_registry().iter().map(convert_rule).collect()
+    })
+    .await
+    .unwrap();
+
+    Json(RulesResponse { language: lang_code, rules })
+}
+
 /// Handle GET / (health check)
 pub async fn health_handler() -> impl IntoResponse {
     (StatusCode::OK, "OK")
 }
 
-/// Normalize language code to a standard format
-fn normalize_language(lang: &str) -> String {
-    match lang.to_lowercase().as_str() {
-        "en" | "en-us" | "english" => "en-US".to_string(),
-        "en-gb" | "en-uk" => "en-GB".to_string(),
-        "fr" | "fr-fr" | "french" => "fr-FR".to_string(),
-        "fr-ca" => "fr-CA".to_string(),
-        other => other.to_string(),
+/// Language of a request: the requested code, or the language detected in
+/// `text` (resolved to a preferred variant) with its confidence for "auto"
+pub fn resolve_language(
+    state: &AppState,
+    language: &str,
+    text: &str,
+    preferred_variants: Option<&str>,
+) -> (String, f32) {
+    if language != "auto" {
+        return (normalize_language(language), 1.0);
+    }
+
+    let detection = state.language_detector.detect(text);
+    // preferredVariants picks the variant of the detected language (e.g. en-GB)
+    let code = apply_preferred_variant(detection.code(), preferred_variants);
+    let confidence = if detection == grammar_rs::lang_detect::Language::Unknown {
+        0.5
+    } else {
+        0.95
+    };
+    (code, confidence)
+}
+
+/// L2 French confusion matches (false friends of French native speakers writing in English)
+pub fn l2_french_matches(text: &str) -> Vec<Match> {
+    let l2_checker = L2ConfusionChecker::new();
+    let tokenizer = SimpleTokenizer::new();
+    let analyzer = PassthroughAnalyzer::new();
+    let tokens = tokenizer.tokenize(text);
+    let analyzed = analyzer.analyze(tokens);
+    l2_checker.check(text, &analyzed).matches
+}
+
+/// Add the L2 matches when enabled, apply the personal dictionary, then
+/// the client's rule selection
+pub fn postprocess(
+    result: &mut CheckResult,
+    text: &str,
+    registry: &RuleRegistry,
+    use_l2_fr: bool,
+    rules: &RuleSelection,
+    user_dictionary: Option<&UserDictionary>,
+) {
+    // Add L2 French confusion checking for French native speakers writing in English
+    if use_l2_fr {
+        result.matches.extend(l2_french_matches(text));
+    }
+
+    if let Some(dictionary) = user_dictionary {
+        dictionary.apply(text, &mut result.matches);
+    }
+
+    filter_matches(result, registry, rules);
+}
+
+/// Drop default-off rules the client did not enable and the rules or
+/// categories it did not ask for
+///
+/// A default-off rule reports when its ID is in `enabledRules` or its
+/// category in `enabledCategories`, with or without `enabledOnly`.
+pub fn filter_matches(result: &mut CheckResult, registry: &RuleRegistry, rules: &RuleSelection) {
+    result.matches.retain(|m| {
+        let default_on = registry.is_default_on(&m.rule_id);
+        if default_on && rules.is_default() {
+            return true;
+        }
+        let category = rule_category(registry, &m.rule_id, m.severity);
+        (default_on || rules.explicitly_enables(&m.rule_id, &category.id))
+            && rules.allows(&m.rule_id, &category.id)
+    });
+}
+
+/// Resolve a detected base language ("en", "fr") to the first matching
+/// variant in `preferredVariants` (e.g. "en-GB,fr-CA")
+fn apply_preferred_variant(base: &str, preferred: Option<&str>) -> String {
+    preferred
+        .into_iter()
+        .flat_map(|list| list.split(','))
+        .map(|variant| normalize_language(variant.trim()))
+        .find(|variant| variant.split('-').next() == Some(base))
+        .unwrap_or_else(|| base.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_preferred_variant() {
+        assert_eq!(apply_preferred_variant("en", Some("fr-CA,en-GB")), "en-GB");
+        assert_eq!(apply_preferred_variant("fr", Some("fr-CA,en-GB")), "fr-CA");
+        assert_eq!(apply_preferred_variant("en", Some("fr-CA")), "en");
+        assert_eq!(apply_preferred_variant("en", None), "en");
+    }
+
+    #[test]
+    fn test_enabled_category_turns_on_default_off_rules() {
+        use grammar_rs::core::registry::{RuleCategory, RuleMetadata};
+        use grammar_rs::core::Severity;
+
+        let mut registry = RuleRegistry::new();
+        registry.register(RuleMetadata::new("OFF_RULE", "Off by default", RuleCategory::Style).with_default_on(false));
+        let matches = vec![Match {
+            span: 0..4,
+            message: String::new(),
+            rule_id: "OFF_RULE".to_string(),
+            suggestions: vec![],
+            severity: Severity::Hint,
+            issue_type: None,
+        }];
+        let reported = |rules: RuleSelection| {
+            let mut result = CheckResult { matches: matches.clone() };
+            filter_matches(&mut result, &registry, &rules);
+            result.matches.len()
+        };
+
+        let category = RuleCategory::Style.id().to_string();
+        assert_eq!(reported(RuleSelection::default()), 0);
+        assert_eq!(reported(RuleSelection { enabled_categories: [category.clone()].into(), ..Default::default() }), 1);
+        assert_eq!(reported(RuleSelection {
+            enabled_categories: [category].into(),
+            disabled_rules: ["OFF_RULE".to_string()].into(),
+            ..Default::default()
+        }), 0);
+    }
+
+    #[test]
+    fn test_sub_rules_keep_their_own_default() {
+        use grammar_rs::core::registry::{RuleCategory, RuleMetadata};
+        use grammar_rs::core::Severity;
+
+        let mut registry = RuleRegistry::new();
+        registry.register(RuleMetadata::new("GROUP[1]", "Group", RuleCategory::Grammar).with_default_on(false));
+        registry.register(RuleMetadata::new("GROUP[2]", "Group", RuleCategory::Grammar));
+        let matches: Vec<Match> = ["GROUP[1]", "GROUP[2]"]
+            .into_iter()
+            .map(|id| Match {
+                span: 0..4,
+                message: String::new(),
+                rule_id: id.to_string(),
+                suggestions: vec![],
+                severity: Severity::Error,
+                issue_type: None,
+            })
+            .collect();
+        let reported = |rules: RuleSelection| {
+            let mut result = CheckResult { matches: matches.clone() };
+            filter_matches(&mut result, &registry, &rules);
+            result.matches.into_iter().map(|m| m.rule_id).collect::<Vec<_>>()
+        };
+
+        assert_eq!(reported(RuleSelection::default()), vec!["GROUP[2]"]);
+        // The group ID selects every sub-rule
+        let group = || ["GROUP".to_string()].into();
+        assert_eq!(reported(RuleSelection { enabled_rules: group(), ..Default::default() }).len(), 2);
+        assert!(reported(RuleSelection { disabled_rules: group(), ..Default::default() }).is_empty());
     }
 }
@@ -4,27 +4,57 @@
 //!
 //! ## Endpoints
 //!
-//! - `POST /v2/check` - Check text for grammar/spelling errors
+//! - `POST /v2/check` - Check text for grammar/spelling errors (form or JSON body)
+//! - `POST /v2/check/batch` - Check many documents at once (JSON body)
+//! - `POST /v2/check/stream` - Same as `/v2/check`, streamed per paragraph (SSE)
+//! - `POST /v2/check/cancel` - Cancel the running stream of a `documentId`
 //! - `GET /v2/languages` - List supported languages
+//! - `GET /v2/rules?language=` - List the rules of a language with their metadata
+//! - `GET /v2/words?username=` - List the words of a personal dictionary
+//! - `POST /v2/words/add`, `POST /v2/words/delete` - Edit a personal dictionary
+//! - `POST /admin/reload` - Reload the configuration (needs `admin_token`)
+//! - `GET /metrics` - Prometheus metrics (requests, latencies, cache, checkers, rules)
 //! - `GET /` - Health check
 //!
+//! ## Configuration
+//!
+//! `--config server.toml` (or `GRAMMAR_API_CONFIG`) selects the checkers and
+//! filters of each language, the data paths and the caches; see
+//! `config/server.example.toml`. Environment variables override the file.
+//! `kill -HUP <pid>` or `POST /admin/reload` rebuilds the pipelines from the
+//! file without dropping the requests in flight.
+//!
 //! ## Usage
 //!
 //! ```bash
 //! # Start the server
 //! cargo run --release --bin grammar-api
+//! cargo run --release --bin grammar-api -- --config config/server.example.toml
 //!
 //! # Test with curl
 //! curl -X POST http://localhost:8081/v2/check \
 //!   -d "text=I have a apple&language=en"
+//!
+//! # Same request with a JSON body
+//! curl -X POST http://localhost:8081/v2/check \
+//!   -H "Content-Type: application/json" \
+//!   -d '{"text": "I have a apple", "language": "en"}'
 //! ```
 
+mod admin;
+mod config;
 mod convert;
+mod extract;
 mod handlers;
+mod metrics;
 mod state;
+mod stream;
 mod types;
+mod words;
 
 use axum::{
+    extract::DefaultBodyLimit,
+    middleware,
     routing::{get, post},
     Router,
 };
@@ -32,8 +62,13 @@ use std::sync::Arc;
 use tower_http::cors::{Any, CorsLayer};
 use tower_http::trace::TraceLayer;
 
+use admin::{reload_handler, spawn_sighup_listener};
+use config::{config_path, ServerConfig};
 use state::AppState;
-use handlers::{check_handler, languages_handler, health_handler};
+use metrics::{metrics_handler, track_http};
+use handlers::{check_handler, check_batch_handler, languages_handler, rules_handler, health_handler};
+use stream::{cancel_handler, check_stream_handler};
+use words::{words_add_handler, words_delete_handler, words_handler};
 
 #[tokio::main]
 async fn main() {
@@ -45,11 +80,18 @@ async fn main() {
 
     tracing::info!("Starting grammar-rs API server...");
 
+    // Config file (optional) + environment variables
+    let config_path = config_path(std::env::args().skip(1));
+    let config = ServerConfig::load(config_path.as_deref()).unwrap_or_else(|e| {
+        tracing::error!("Invalid configuration: {}", e);
+        std::process::exit(1);
+    });
+    if let Some(path) = &config_path {
+        tracing::info!("Configuration loaded from {}", path.display());
+    }
+
     // Configure rayon thread pool for CPU parallelism
-    let max_cpus = std::env::var("MAX_CPUS")
-        .ok()
-        .and_then(|s| s.parse::<usize>().ok())
-        .unwrap_or(0); // 0 = use all CPUs
+    let max_cpus = config.server.max_cpus; // 0 = use all CPUs
 
     let actual_cpus = grammar_rs::core::pipeline::num_cpus();
     let threads = if max_cpus == 0 { actual_cpus } else { max_cpus.min(actual_cpus) };
@@ -58,12 +100,19 @@ async fn main() {
     tracing::info!("Rayon thread pool: {} threads (MAX_CPUS={})", threads,
         if max_cpus == 0 { "auto".to_string() } else { max_cpus.to_string() });
 
+    // Morphology files of [pipelines.data], before warm_up loads the defaults
+    if let Err(e) = config.pipelines.data.init_morphology() {
+        tracing::error!("Invalid configuration: {}", e);
+        std::process::exit(1);
+    }
+
     // Pre-warm lazy statics for faster first request
     tracing::info!("Pre-warming lazy statics...");
     grammar_rs::warm_up();
 
     // Build application state (pre-initialize pipelines)
-    let state = Arc::new(AppState::new());
+    let state = Arc::new(AppState::new(&config, config_path));
+    spawn_sighup_listener(Arc::clone(&state));
 
     // Configure CORS (permissive for browser extension compatibility)
     let cors = CorsLayer::new()
@@ -71,18 +120,33 @@ async fn main() {
         .allow_methods(Any)
         .allow_headers(Any);
 
+    // Batches carry many documents: allow bigger bodies on that route only
+    let batch_max_bytes = config.server.batch_max_bytes;
+
     // Build router
     let app = Router::new()
         .route("/", get(health_handler))
         .route("/v2/check", post(check_handler))
+        .route(
+            "/v2/check/batch",
+            post(check_batch_handler).layer(DefaultBodyLimit::max(batch_max_bytes)),
+        )
+        .route("/v2/check/stream", post(check_stream_handler))
+        .route("/v2/check/cancel", post(cancel_handler))
         .route("/v2/languages", get(languages_handler))
+        .route("/v2/rules", get(rules_handler))
+        .route("/v2/words", get(words_handler))
+        .route("/v2/words/add", post(words_add_handler))
+        .route("/v2/words/delete", post(words_delete_handler))
+        .route("/admin/reload", post(reload_handler))
+        .route("/metrics", get(metrics_handler))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&state), track_http))
         .layer(cors)
         .layer(TraceLayer::new_for_http())
         .with_state(state);
 
     // Bind to port
-    let port = std::env::var("PORT").unwrap_or_else(|_| "8081".to_string());
-    let addr = format!("0.0.0.0:{}", port);
+    let addr = format!("0.0.0.0:{}", config.server.port);
 
     let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
     tracing::info!("Listening on http://{}", addr);
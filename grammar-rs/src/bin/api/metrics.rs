@@ -0,0 +1,262 @@
+//! Prometheus metrics (`GET /metrics`, OpenMetrics text format)
+//!
+//! - `grammar_http_requests_total{path, status}` and
+//!   `grammar_http_request_duration_seconds{path}` for every route
+//! - `grammar_check_duration_seconds{language}` and
+//!   `grammar_check_text_bytes{language}` per checked text (cache misses,
+//!   batch documents, stream runs)
+//! - `grammar_cache_requests_total{result="hit"|"miss"}` and
+//!   `grammar_cache_entries` for the /v2/check response cache
+//! - `grammar_checker_duration_seconds{language, checker}`, the wall time
+//!   of each checker inside the pipelines (paragraphs of incremental and
+//!   stream checks included)
+//! - `grammar_rule_matches_total{language, rule}`, the matches returned to
+//!   clients (cached responses are not counted again)
+//!
+//! `language` is the pipeline that ran (en-US, en-GB, fr-FR, fr-CA), so
+//! client-supplied codes never create new series.
+
+use axum::{
+    extract::{MatchedPath, Request, State},
+    http::{header::CONTENT_TYPE, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use grammar_rs::core::pipeline::CheckObserver;
+use grammar_rs::core::CheckResult;
+use grammar_rs::presets::pipeline_language;
+use prometheus_client::encoding::{text::encode, EncodeLabelSet};
+use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::family::Family;
+use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::metrics::histogram::{exponential_buckets, Histogram};
+use prometheus_client::registry::{Registry, Unit};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::state::AppState;
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct HttpLabels {
+    path: String,
+    status: u16,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct PathLabels {
+    path: String,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct LanguageLabels {
+    language: &'static str,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct CacheLabels {
+    result: &'static str,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct CheckerLabels {
+    language: &'static str,
+    checker: &'static str,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct RuleLabels {
+    language: &'static str,
+    rule: String,
+}
+
+type HistogramFamily<L> = Family<L, Histogram, fn() -> Histogram>;
+
+/// Every metric of the server
+pub struct Metrics {
+    registry: Registry,
+    http_requests: Family<HttpLabels, Counter>,
+    http_duration: HistogramFamily<PathLabels>,
+    check_duration: HistogramFamily<LanguageLabels>,
+    text_bytes: HistogramFamily<LanguageLabels>,
+    cache_requests: Family<CacheLabels, Counter>,
+    cache_entries: Gauge,
+    checker_duration: HistogramFamily<CheckerLabels>,
+    rule_matches: Family<RuleLabels, Counter>,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        // 100µs .. ~3.3s
+        fn request_seconds() -> Histogram {
+            Histogram::new(exponential_buckets(0.0001, 2.0, 16))
+        }
+        // 10µs .. ~0.33s
+        fn checker_seconds() -> Histogram {
+            Histogram::new(exponential_buckets(0.00001, 2.0, 16))
+        }
+        // 64 B .. 16 MB
+        fn text_bytes() -> Histogram {
+            Histogram::new(exponential_buckets(64.0, 4.0, 10))
+        }
+
+        let metrics = Self {
+            registry: Registry::with_prefix("grammar"),
+            http_requests: Family::default(),
+            http_duration: Family::new_with_constructor(request_seconds),
+            check_duration: Family::new_with_constructor(request_seconds),
+            text_bytes: Family::new_with_constructor(text_bytes),
+            cache_requests: Family::default(),
+            cache_entries: Gauge::default(),
+            checker_duration: Family::new_with_constructor(checker_seconds),
+            rule_matches: Family::default(),
+        };
+        metrics.register()
+    }
+
+    fn register(mut self) -> Self {
+        let registry = &mut self.registry;
+        registry.register("http_requests", "HTTP requests by route and status", self.http_requests.clone());
+        registry.register_with_unit(
+            "http_request_duration",
+            "HTTP request latency by route",
+            Unit::Seconds,
+            self.http_duration.clone(),
+        );
+        registry.register_with_unit(
+            "check_duration",
+            "Checking time of a text by pipeline language",
+            Unit::Seconds,
+            self.check_duration.clone(),
+        );
+        registry.register_with_unit(
+            "check_text",
+            "Length of the checked texts by pipeline language",
+            Unit::Bytes,
+            self.text_bytes.clone(),
+        );
+        registry.register("cache_requests", "Response cache lookups of /v2/check", self.cache_requests.clone());
+        registry.register("cache_entries", "Responses in the /v2/check cache", self.cache_entries.clone());
+        registry.register_with_unit(
+            "checker_duration",
+            "Wall time of each checker inside the pipelines",
+            Unit::Seconds,
+            self.checker_duration.clone(),
+        );
+        registry.register("rule_matches", "Matches returned to clients by rule", self.rule_matches.clone());
+        self
+    }
+
+    /// Record a response cache lookup of /v2/check
+    pub fn record_cache(&self, hit: bool) {
+        let result = if hit { "hit" } else { "miss" };
+        self.cache_requests.get_or_create(&CacheLabels { result }).inc();
+    }
+
+    /// Record the check of one text (not a cache hit)
+    pub fn record_check(&self, lang: &str, text_len: usize, elapsed: Duration) {
+        let labels = LanguageLabels { language: pipeline_language(lang) };
+        self.check_duration.get_or_create(&labels).observe(elapsed.as_secs_f64());
+        self.text_bytes.get_or_create(&labels).observe(text_len as f64);
+    }
+
+    /// Count the matches returned to a client, by rule
+    pub fn record_matches<'a>(&self, lang: &str, rule_ids: impl IntoIterator<Item = &'a str>) {
+        let language = pipeline_language(lang);
+        for rule in rule_ids {
+            self.rule_matches.get_or_create(&RuleLabels { language, rule: rule.to_string() }).inc();
+        }
+    }
+
+    fn record_http(&self, path: String, status: StatusCode, elapsed: Duration) {
+        self.http_duration.get_or_create(&PathLabels { path: path.clone() }).observe(elapsed.as_secs_f64());
+        self.http_requests.get_or_create(&HttpLabels { path, status: status.as_u16() }).inc();
+    }
+
+    fn record_checker(&self, language: &'static str, checker: &'static str, elapsed: Duration) {
+        self.checker_duration
+            .get_or_create(&CheckerLabels { language, checker })
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// Every metric in the OpenMetrics text format
+    pub fn encode(&self, cache_entries: u64) -> String {
+        self.cache_entries.set(cache_entries as i64);
+        let mut text = String::new();
+        encode(&mut text, &self.registry).expect("writing to a String cannot fail");
+        text
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Checker timings of one pipeline (see `Pipeline::set_observer`)
+pub struct PipelineObserver {
+    pub language: &'static str,
+    pub metrics: Arc<Metrics>,
+}
+
+impl CheckObserver for PipelineObserver {
+    fn checker_done(&self, checker: &'static str, elapsed: Duration, _result: &CheckResult) {
+        self.metrics.record_checker(self.language, checker, elapsed);
+    }
+}
+
+/// Middleware recording the count and latency of every routed request
+pub async fn track_http(State(state): State<Arc<AppState>>, req: Request, next: Next) -> Response {
+    let path = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or_else(|| "unmatched".to_string(), |path| path.as_str().to_string());
+    let start = Instant::now();
+    let response = next.run(req).await;
+    state.metrics.record_http(path, response.status(), start.elapsed());
+    response
+}
+
+/// Handle GET /metrics
+pub async fn metrics_handler(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let cache_entries = state.checkers().cache.entry_count();
+    (
+        [(CONTENT_TYPE, "application/openmetrics-text; version=1.0.0; charset=utf-8")],
+        state.metrics.encode(cache_entries),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_records() {
+        let metrics = Arc::new(Metrics::new());
+        metrics.record_cache(true);
+        metrics.record_cache(false);
+        metrics.record_check("fr-BE", 300, Duration::from_millis(2));
+        metrics.record_matches("fr-CA", ["FR_CA_PUNCT_SPACE", "FR_CA_PUNCT_SPACE", "SPELL"]);
+        metrics.record_http("/v2/check".to_string(), StatusCode::OK, Duration::from_millis(3));
+        PipelineObserver { language: "en-US", metrics: Arc::clone(&metrics) }.checker_done(
+            "SpellChecker",
+            Duration::from_micros(50),
+            &CheckResult::new(),
+        );
+
+        let text = metrics.encode(7);
+        for line in [
+            r#"grammar_cache_requests_total{result="hit"} 1"#,
+            r#"grammar_cache_requests_total{result="miss"} 1"#,
+            "grammar_cache_entries 7",
+            r#"grammar_check_duration_seconds_count{language="fr-FR"} 1"#,
+            r#"grammar_check_text_bytes_sum{language="fr-FR"} 300.0"#,
+            r#"grammar_rule_matches_total{language="fr-CA",rule="FR_CA_PUNCT_SPACE"} 2"#,
+            r#"grammar_http_requests_total{path="/v2/check",status="200"} 1"#,
+            r#"grammar_checker_duration_seconds_count{language="en-US",checker="SpellChecker"} 1"#,
+        ] {
+            assert!(text.contains(line), "missing {:?} in\n{}", line, text);
+        }
+        assert!(text.ends_with("# EOF\n"));
+    }
+}
@@ -1,273 +1,223 @@
 //! Application state - pre-built pipelines for each language
+//!
+//! The pipelines, their paragraph caches and the response cache form one
+//! `Checkers` snapshot. A reload builds a new snapshot from the config file
+//! and swaps it in: requests that already hold the previous snapshot finish
+//! with it, and it is dropped with the last of them.
 
 use grammar_rs::prelude::*;
-use grammar_rs::checker::{
-    EnglishConfusionRule, FrenchConfusionRule,
-    AhoPatternRuleChecker, ReplaceRuleChecker,
-    StyleChecker, CoherencyChecker, DiacriticsChecker,
-    ContractionChecker, ContextChecker,
-    PosPatternChecker, UncountableNounChecker, CompoundWordChecker,
-    ProhibitChecker, SpellChecker, NgramConfusionChecker,
-    // Dynamic pattern checker for complex rules (regex, postag_regexp, skip, etc.)
-    create_en_dynamic_checker, create_fr_dynamic_checker,
-    EN_PATTERN_RULES, FR_PATTERN_RULES,
-    EN_REPLACE_RULES, FR_REPLACE_RULES,
-    EN_ANTIPATTERNS, FR_ANTIPATTERNS,
-    EN_POS_PATTERN_RULES, FR_POS_PATTERN_RULES,
-    EN_ADDED_WORDS,
-    // Number words for POS tagging
-    EN_NUMBERS, FR_NUMBERS,
-    // Spelling skip lists and dictionaries
-    EN_IGNORE, EN_PROPER_NOUNS,
-    FR_IGNORE, FR_SPELLING, FR_COMMON_WORDS,
-    // Disambiguation skip patterns
-    EN_DISAMBIG_SKIP, FR_DISAMBIG_SKIP,
-};
-use grammar_rs::dictionary::FstDictionary;
-use grammar_rs::core::PosTag;
-use std::sync::Arc;
-use std::path::Path;
+use grammar_rs::presets::LanguagePipelines;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
 use moka::future::Cache;
+use tokio::sync::Mutex;
+use crate::config::{CacheConfig, ServerConfig};
+use crate::metrics::{Metrics, PipelineObserver};
+use crate::stream::StreamRegistry;
 use crate::types::{CacheKey, LanguageToolResponse};
-
-/// Application state shared across all requests
-pub struct AppState {
-    pub en_pipeline: Arc<Pipeline>,
-    pub fr_pipeline: Arc<Pipeline>,
-    pub language_detector: LanguageDetector,
+use crate::words::WordStore;
+
+/// Everything rebuilt by a configuration reload
+pub struct Checkers {
+    /// en-US, en-GB, fr-FR and fr-CA pipelines (see `grammar_rs::presets`)
+    pub pipelines: LanguagePipelines,
+    /// Paragraph-level caches over each pipeline (same order as above)
+    pub en_incremental: Arc<IncrementalChecker>,
+    pub en_gb_incremental: Arc<IncrementalChecker>,
+    pub fr_incremental: Arc<IncrementalChecker>,
+    pub fr_ca_incremental: Arc<IncrementalChecker>,
+    /// /v2/check responses of these pipelines
     pub cache: Cache<CacheKey, Arc<LanguageToolResponse>>,
 }
 
-impl AppState {
-    /// Create a new application state with pre-built pipelines
-    pub fn new() -> Self {
-        tracing::info!("Building English pipeline...");
-        let en_pipeline = Self::create_en_pipeline();
-
-        tracing::info!("Building French pipeline...");
-        let fr_pipeline = Self::create_fr_pipeline();
-
-        tracing::info!("Initializing language detector...");
-        let language_detector = LanguageDetector::new();
-
-        // Initialize cache with configurable settings
-        let cache_size = std::env::var("CACHE_SIZE")
-            .ok()
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(10_000);
-
-        let cache_ttl = std::env::var("CACHE_TTL_SECS")
-            .ok()
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(3600); // 1 hour default
+impl Checkers {
+    /// Build the pipelines and caches of a configuration (slow: loads the data files)
+    ///
+    /// The checkers of each pipeline report their timings to `metrics`.
+    pub fn new(config: &ServerConfig, metrics: &Arc<Metrics>) -> Self {
+        let pipelines = LanguagePipelines::with_config(&config.pipelines);
+        for (language, pipeline) in pipelines.iter() {
+            pipeline.set_observer(Arc::new(PipelineObserver { language, metrics: Arc::clone(metrics) }));
+        }
+        let CacheConfig { size, ttl_secs, paragraph_size } = config.cache;
 
         let cache = Cache::builder()
-            .max_capacity(cache_size as u64)
-            .time_to_live(std::time::Duration::from_secs(cache_ttl))
+            .max_capacity(size)
+            .time_to_live(std::time::Duration::from_secs(ttl_secs))
             .build();
 
-        tracing::info!("Cache initialized (capacity: {}, TTL: {}s)", cache_size, cache_ttl);
-        tracing::info!("Application state initialized");
+        tracing::info!("Cache initialized (capacity: {}, TTL: {}s)", size, ttl_secs);
+
+        // Paragraph cache for incremental checks (shared by all documents)
+        let incremental = |pipeline: &Arc<Pipeline>| {
+            Arc::new(IncrementalChecker::new(Arc::clone(pipeline)).with_capacity(paragraph_size))
+        };
 
         Self {
-            en_pipeline: Arc::new(en_pipeline),
-            fr_pipeline: Arc::new(fr_pipeline),
-            language_detector,
+            en_incremental: incremental(&pipelines.en_us),
+            en_gb_incremental: incremental(&pipelines.en_gb),
+            fr_incremental: incremental(&pipelines.fr_fr),
+            fr_ca_incremental: incremental(&pipelines.fr_ca),
+            pipelines,
             cache,
         }
     }
 
-    /// Create an English POS tagger with the added words dictionary
-    fn create_en_pos_tagger() -> PosTagger {
-        let mut tagger = PosTagger::new();
+    /// Get the appropriate pipeline for a language code (en-US, en-GB, fr-FR, fr-CA, ...)
+    pub fn get_pipeline(&self, lang: &str) -> &Arc<Pipeline> {
+        self.pipelines.get(lang)
+    }
 
-        // Load the POS-tagged words from LanguageTool
-        for entry in EN_ADDED_WORDS {
-            if let Some(pos) = PosTag::from_str(entry.pos_tag) {
-                tagger.add_word(entry.word, entry.base_form, pos);
-            }
+    /// Get the incremental (paragraph-cached) checker for a language code
+    pub fn get_incremental(&self, lang: &str) -> &Arc<IncrementalChecker> {
+        match lang {
+            "en-GB" => &self.en_gb_incremental,
+            "fr-CA" => &self.fr_ca_incremental,
+            _ if lang.starts_with("fr") => &self.fr_incremental,
+            _ => &self.en_incremental,
         }
-
-        // Load number words (twenty-one, thirty-five, etc.) as CD (cardinal number)
-        tagger.load_from_lines(EN_NUMBERS.iter().copied());
-
-        tracing::debug!("EN POS tagger loaded with {} dictionary entries + suffix heuristics",
-                       tagger.dictionary_size());
-        tagger
     }
+}
 
-    /// Create a French POS tagger with number words
-    fn create_fr_pos_tagger() -> PosTagger {
-        let mut tagger = PosTagger::new();
+/// Application state shared across all requests
+pub struct AppState {
+    /// Current checkers; clone the `Arc` once per request (see `checkers`)
+    checkers: RwLock<Arc<Checkers>>,
+    /// File re-read on reload (`None`: defaults and environment only)
+    config_path: Option<PathBuf>,
+    /// Serializes reloads
+    reloading: Mutex<()>,
+    /// Token of `POST /admin/reload` (disabled without one)
+    pub admin_token: Option<String>,
+    pub language_detector: LanguageDetector,
+    /// Running /v2/check/stream runs, for cancellation
+    pub streams: StreamRegistry,
+    /// Maximum number of documents in one /v2/check/batch request
+    pub batch_max_documents: usize,
+    /// Personal dictionaries (/v2/words)
+    pub words: WordStore,
+    /// Prometheus metrics (/metrics), kept across reloads
+    pub metrics: Arc<Metrics>,
+}
 
-        // Load French number words (vingt-et-un, trente-deux, etc.) as CD (cardinal number)
-        tagger.load_from_lines(FR_NUMBERS.iter().copied());
+impl AppState {
+    /// Create a new application state with pre-built pipelines
+    pub fn new(config: &ServerConfig, config_path: Option<PathBuf>) -> Self {
+        let metrics = Arc::new(Metrics::new());
+        let checkers = Checkers::new(config, &metrics);
 
-        tracing::debug!("FR POS tagger loaded with {} dictionary entries + suffix heuristics",
-                       tagger.dictionary_size());
-        tagger
-    }
+        tracing::info!("Initializing language detector...");
+        let language_detector = LanguageDetector::new();
 
-    /// Get the appropriate pipeline for a language
-    pub fn get_pipeline(&self, lang: &str) -> &Pipeline {
-        if lang.starts_with("fr") {
-            &self.fr_pipeline
+        // Personal dictionaries, one file per user
+        let words_config = &config.user_dictionaries;
+        let words_dir = words_config.dir();
+        let words = WordStore::open(words_dir, words_config.max_words, words_config.api_keys.clone())
+            .unwrap_or_else(|e| panic!("cannot open user dictionary directory {}: {}", words_dir.display(), e));
+        if words.is_enabled() {
+            tracing::info!(
+                "User dictionaries in {} ({} users, max {} words each)",
+                words_dir.display(),
+                words_config.api_keys.len(),
+                words_config.max_words
+            );
         } else {
-            &self.en_pipeline
-        }
-    }
-
-    /// Create an English spell checker with FST dictionary (370K words)
-    fn create_en_spell_checker() -> Option<SpellChecker> {
-        let dict_path = Path::new("data/dictionaries/en_US.fst");
-        if !dict_path.exists() {
-            tracing::warn!("EN dictionary not found at {:?}, spell checking disabled", dict_path);
-            return None;
+            tracing::info!("User dictionaries disabled (no [user_dictionaries] api_keys)");
         }
+        tracing::info!("Application state initialized");
 
-        match FstDictionary::from_fst(dict_path) {
-            Ok(dict) => {
-                let word_count = dict.len();
-                let skip_count = EN_IGNORE.len() + EN_PROPER_NOUNS.len() + EN_DISAMBIG_SKIP.len();
-                let checker = SpellChecker::with_fst_dictionary(dict)
-                    .with_skip_words(EN_IGNORE.iter().copied())
-                    .with_skip_words(EN_PROPER_NOUNS.iter().copied())
-                    .with_skip_words(EN_DISAMBIG_SKIP.iter().copied());
-                tracing::info!("EN spell checker enabled ({} dictionary words, {} skip words)",
-                              word_count, skip_count);
-                Some(checker)
-            }
-            Err(e) => {
-                tracing::warn!("Failed to load EN dictionary: {}", e);
-                None
-            }
+        Self {
+            checkers: RwLock::new(Arc::new(checkers)),
+            config_path,
+            reloading: Mutex::new(()),
+            admin_token: config.server.admin_token.clone(),
+            language_detector,
+            streams: StreamRegistry::new(),
+            batch_max_documents: config.server.batch_max_documents,
+            words,
+            metrics,
         }
     }
 
-    /// Create a French spell checker using FR_COMMON_WORDS + FR_SPELLING as dictionary
-    fn create_fr_spell_checker() -> Option<SpellChecker> {
-        // FR doesn't have a full FST dictionary, combine FR_COMMON_WORDS (9.7K) + FR_SPELLING (34K)
-        // This gives ~44K words which covers basic French vocabulary
-        let checker = SpellChecker::new()
-            .with_words(FR_COMMON_WORDS.iter().copied())
-            .with_words(FR_SPELLING.iter().copied())
-            .with_skip_words(FR_IGNORE.iter().copied())
-            .with_skip_words(FR_DISAMBIG_SKIP.iter().copied());
-
-        let total_words = FR_COMMON_WORDS.len() + FR_SPELLING.len();
-        let skip_count = FR_IGNORE.len() + FR_DISAMBIG_SKIP.len();
-        tracing::info!("FR spell checker enabled ({} dictionary words, {} skip words)",
-                      total_words, skip_count);
-        Some(checker)
+    /// Current pipelines and caches
+    ///
+    /// A request keeps the returned snapshot until it completes, so a
+    /// reload never changes the pipeline under a running check.
+    pub fn checkers(&self) -> Arc<Checkers> {
+        Arc::clone(&self.checkers.read().unwrap())
     }
 
-    /// Create the English pipeline with all checkers
-    fn create_en_pipeline() -> Pipeline {
-        // Use POS tagger instead of passthrough for better rule matching
-        let pos_tagger = Self::create_en_pos_tagger();
-
-        let mut pipeline = Pipeline::new(
-            SimpleTokenizer::new(),
-            pos_tagger,
-        )
-        // Basic grammar rules + confusion pairs
-        .with_checker(
-            RuleChecker::new()
-                .with_english_rules()
-                .with_rule(EnglishConfusionRule)
-        )
-        // Pattern-based rules (Aho-Corasick for speed) with antipattern filtering
-        .with_checker(AhoPatternRuleChecker::with_antipatterns(EN_PATTERN_RULES, EN_ANTIPATTERNS))
-        // POS pattern rules (require POS tagging) - 94 rules from LanguageTool
-        .with_checker(PosPatternChecker::with_rules(EN_POS_PATTERN_RULES))
-        // Simple replacements
-        .with_checker(ReplaceRuleChecker::new(EN_REPLACE_RULES, "EN_REPLACE"))
-        // Style checking (wordiness, redundancy) - uses default EN_STYLE_RULES
-        .with_checker(StyleChecker::new())
-        // Coherency checking (US/UK spelling consistency) - uses default
-        .with_checker(CoherencyChecker::new())
-        // Diacritics (café, résumé, etc.) - uses default
-        .with_checker(DiacriticsChecker::new())
-        // Contractions (don't, won't, etc.) - uses default
-        .with_checker(ContractionChecker::new())
-        // Context-sensitive words - uses default
-        .with_checker(ContextChecker::new())
-        // Uncountable noun pluralization errors (informations → information)
-        .with_checker(UncountableNounChecker::new())
-        // Compound word errors (air plane → airplane, well being → well-being)
-        .with_checker(CompoundWordChecker::new())
-        // Prohibited words (common misspellings that are always wrong)
-        .with_checker(ProhibitChecker::new())
-        // Dynamic pattern checker (complex rules with regex, postag_regexp, skip, etc.)
-        // 2110 additional rules from LanguageTool that can't be compiled to static code
-        .with_checker(create_en_dynamic_checker());
-
-        tracing::info!("EN dynamic pattern checker enabled ({} rules)", create_en_dynamic_checker().rule_count());
-
-        // Spell checker (370K word FST dictionary + skip lists)
-        if let Some(spell_checker) = Self::create_en_spell_checker() {
-            pipeline = pipeline.with_checker(spell_checker);
-        }
-
-        // N-gram confusion checker (optional - requires data/ngrams/en_ngrams.bin)
-        if let Some(ngram_checker) = NgramConfusionChecker::try_load_en() {
-            tracing::info!("EN N-gram confusion checker enabled");
-            pipeline = pipeline.with_checker(ngram_checker);
-        }
-
-        // Default filters (URLs, code, quotes, etc.)
-        pipeline.with_default_filters()
-    }
-
-    /// Create the French pipeline with all checkers
-    fn create_fr_pipeline() -> Pipeline {
-        // Use POS tagger for better rule matching (includes French number words)
-        let pos_tagger = Self::create_fr_pos_tagger();
-
-        let mut pipeline = Pipeline::new(
-            SimpleTokenizer::new(),
-            pos_tagger,
-        )
-        // Basic French grammar rules + confusion pairs
-        .with_checker(
-            RuleChecker::new()
-                .with_french_rules()
-                .with_rule(FrenchConfusionRule)
-        )
-        // Pattern-based rules with antipattern filtering
-        .with_checker(AhoPatternRuleChecker::with_antipatterns(FR_PATTERN_RULES, FR_ANTIPATTERNS))
-        // POS pattern rules (25 rules from LanguageTool)
-        .with_checker(PosPatternChecker::with_rules(FR_POS_PATTERN_RULES))
-        // Simple replacements
-        .with_checker(ReplaceRuleChecker::new(FR_REPLACE_RULES, "FR_REPLACE"))
-        // Style checking (wordiness, redundancy) - 51 FR rules
-        .with_checker(StyleChecker::french())
-        // Compound word errors (aller retour → aller-retour)
-        .with_checker(CompoundWordChecker::french())
-        // Dynamic pattern checker (complex rules with regex, postag_regexp, skip, etc.)
-        // 826 additional rules from LanguageTool that can't be compiled to static code
-        .with_checker(create_fr_dynamic_checker());
-
-        tracing::info!("FR dynamic pattern checker enabled ({} rules)", create_fr_dynamic_checker().rule_count());
-
-        // Spell checker (34K word dictionary from FR_SPELLING + skip list)
-        if let Some(spell_checker) = Self::create_fr_spell_checker() {
-            pipeline = pipeline.with_checker(spell_checker);
-        }
-
-        // N-gram confusion checker (optional - requires data/ngrams/fr_ngrams.bin)
-        if let Some(ngram_checker) = NgramConfusionChecker::try_load_fr() {
-            tracing::info!("FR N-gram confusion checker enabled");
-            pipeline = pipeline.with_checker(ngram_checker);
-        }
-
-        // Default filters
-        pipeline.with_default_filters()
+    /// Re-read the config file, rebuild the checkers and swap them in
+    ///
+    /// On error the current checkers stay in place. Only `[pipelines]` and
+    /// `[cache]` are applied; the other sections need a restart.
+    pub async fn reload(&self) -> Result<(), String> {
+        let _guard = self.reloading.lock().await;
+        let start = std::time::Instant::now();
+        tracing::info!("Reloading configuration from {:?}...", self.config_path);
+
+        let path = self.config_path.clone();
+        let metrics = Arc::clone(&self.metrics);
+        let checkers = tokio::task::spawn_blocking(move || {
+            let config = ServerConfig::load(path.as_deref())?;
+            Ok::<_, String>(Checkers::new(&config, &metrics))
+        })
+        .await
+        .map_err(|e| format!("reload failed: {}", e))??;
+
+        *self.checkers.write().unwrap() = Arc::new(checkers);
+        tracing::info!(elapsed_ms = start.elapsed().as_millis(), "Configuration reloaded");
+        Ok(())
     }
 }
 
 impl Default for AppState {
     fn default() -> Self {
-        Self::new()
+        Self::new(&ServerConfig::default(), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[tokio::test]
+    async fn test_reload_swaps_checkers() {
+        let dir = std::env::temp_dir().join(format!("grammar-rs-reload-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("server.toml");
+        let write_config = |en_checkers: &str| {
+            let text = format!(
+                "[user_dictionaries]\ndir = \"words\"\n\
+                 [pipelines.en]\ncheckers = [{}]\n\
+                 [pipelines.fr]\ncheckers = [\"rules\"]\n",
+                en_checkers
+            );
+            fs::write(&path, text).unwrap();
+        };
+
+        write_config("\"rules\"");
+        let config = ServerConfig::load(Some(&path)).unwrap();
+        let state = AppState::new(&config, Some(path.clone()));
+        let before = state.checkers();
+        assert_eq!(before.get_pipeline("en-US").checker_count(), 1);
+
+        write_config("\"rules\", \"style\"");
+        state.reload().await.unwrap();
+        assert_eq!(state.checkers().get_pipeline("en-US").checker_count(), 2);
+        // A request started before the reload keeps its pipelines
+        assert_eq!(before.get_pipeline("en-US").checker_count(), 1);
+
+        // Reloaded pipelines still report their checker timings
+        state.checkers().get_pipeline("en-GB").check_text("I has a apple.");
+        let metrics = state.metrics.encode(0);
+        assert!(metrics.contains(r#"grammar_checker_duration_seconds_count{language="en-GB",checker="StyleChecker"} 1"#));
+
+        // An invalid file keeps the current checkers
+        fs::write(&path, "[pipelines.en]\ncheckers = [\"nope\"]\n").unwrap();
+        assert!(state.reload().await.is_err());
+        assert_eq!(state.checkers().get_pipeline("en-US").checker_count(), 2);
+        fs::remove_dir_all(&dir).unwrap();
     }
 }
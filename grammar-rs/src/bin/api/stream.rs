@@ -0,0 +1,261 @@
+//! Streaming check endpoint (Server-Sent Events)
+//!
+//! `POST /v2/check/stream` checks the text paragraph by paragraph and pushes
+//! a `matches` event as soon as a paragraph is done, then the matches of the
+//! document-level checkers and a final `done` summary.
+//!
+//! A run can be tied to a `documentId`: starting a new run for the same
+//! document, or calling `POST /v2/check/cancel`, stops the previous run
+//! before its next paragraph. Closing the connection stops it as well.
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::sse::{Event, KeepAlive, Sse},
+};
+use futures_util::stream::{self, Stream};
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+
+use crate::convert::{convert_matches, language_info};
+use crate::extract::FormOrJson;
+use crate::handlers::{filter_matches, l2_french_matches, resolve_language};
+use crate::state::AppState;
+use crate::types::*;
+use crate::words::request_words;
+
+use grammar_rs::checker::UserDictionary;
+use grammar_rs::core::incremental::split_paragraphs;
+use grammar_rs::core::CheckResult;
+use grammar_rs::markup::AnnotatedText;
+
+/// Number of events buffered before the checking thread waits for the client
+const EVENT_BUFFER: usize = 16;
+
+/// Cancellation flags of the running streams, by document ID
+#[derive(Default)]
+pub struct StreamRegistry {
+    runs: Mutex<HashMap<String, Arc<AtomicBool>>>,
+}
+
+impl StreamRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a new run, cancelling the previous run of the same document
+    pub fn start(&self, document_id: Option<&str>) -> Arc<AtomicBool> {
+        let flag = Arc::new(AtomicBool::new(false));
+        if let Some(id) = document_id {
+            let previous = self.runs.lock().unwrap().insert(id.to_string(), Arc::clone(&flag));
+            if let Some(previous) = previous {
+                previous.store(true, Ordering::Relaxed);
+            }
+        }
+        flag
+    }
+
+    /// Cancel the running check of a document (false if none is running)
+    pub fn cancel(&self, document_id: &str) -> bool {
+        match self.runs.lock().unwrap().remove(document_id) {
+            Some(flag) => {
+                flag.store(true, Ordering::Relaxed);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Forget a finished run, unless a newer run already replaced it
+    pub fn finish(&self, document_id: Option<&str>, flag: &Arc<AtomicBool>) {
+        let Some(id) = document_id else { return };
+        let mut runs = self.runs.lock().unwrap();
+        if runs.get(id).is_some_and(|current| Arc::ptr_eq(current, flag)) {
+            runs.remove(id);
+        }
+    }
+}
+
+/// Handle POST /v2/check/stream
+///
+/// Same parameters as /v2/check, plus an optional `documentId`
+pub async fn check_stream_handler(
+    State(state): State<Arc<AppState>>,
+    FormOrJson(req): FormOrJson<CheckRequest>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, (StatusCode, String)> {
+    let rules = RuleSelection::from_request(&req)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+    let annotated = req.annotated_text()
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+    let (lang_code, confidence) = resolve_language(
+        &state,
+        &req.language,
+        annotated.plain_text(),
+        req.preferred_variants.as_deref(),
+    );
+    let user_words = request_words(&state, req.username.as_deref(), req.api_key.as_deref()).await?;
+    let cancelled = state.streams.start(req.document_id.as_deref());
+
+    let (tx, rx) = mpsc::channel(EVENT_BUFFER);
+    tokio::task::spawn_blocking(move || {
+        let user_dictionary = user_words.as_ref().map(|words| words.dictionary.as_ref());
+        run_stream(&state, &req, &annotated, &rules, &lang_code, confidence, user_dictionary, &cancelled, tx);
+        state.streams.finish(req.document_id.as_deref(), &cancelled);
+    });
+
+    let events = stream::unfold(rx, |mut rx| async move {
+        rx.recv().await.map(|event| (Ok(event), rx))
+    });
+
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+/// Handle POST /v2/check/cancel
+///
+/// Stops the running stream of `documentId` (404 if none is running)
+pub async fn cancel_handler(
+    State(state): State<Arc<AppState>>,
+    FormOrJson(req): FormOrJson<CancelRequest>,
+) -> StatusCode {
+    if state.streams.cancel(&req.document_id) {
+        StatusCode::NO_CONTENT
+    } else {
+        StatusCode::NOT_FOUND
+    }
+}
+
+/// Check the text paragraph by paragraph, sending events to `tx`
+///
+/// Paragraph results go through the incremental checker cache, so a run
+/// that follows a small edit only re-checks the changed paragraphs.
+/// Paragraphs are split on the plain text and filtered with the masks of
+/// the whole text (code blocks and quotes can span paragraphs); match
+/// offsets are sent in original document coordinates.
+#[allow(clippy::too_many_arguments)]
+fn run_stream(
+    state: &AppState,
+    req: &CheckRequest,
+    annotated: &AnnotatedText,
+    rules: &RuleSelection,
+    lang_code: &str,
+    confidence: f32,
+    user_dictionary: Option<&UserDictionary>,
+    cancelled: &AtomicBool,
+    tx: mpsc::Sender<Event>,
+) {
+    let start = std::time::Instant::now();
+    let checkers = state.checkers();
+    let checker = checkers.get_incremental(lang_code);
+    let pipeline = checker.pipeline();
+    let registry = pipeline.rule_registry();
+    let level = req.check_level();
+    let use_l2_fr = req.mother_tongue.as_deref() == Some("fr") && !lang_code.starts_with("fr");
+    let text = annotated.plain_text();
+    let paragraphs = split_paragraphs(text);
+    let masks = pipeline.find_masks(text);
+
+    // Filter, convert and send one `matches` event (false once the client is gone)
+    let mut total = 0;
+    let mut send_matches = |paragraph: Option<usize>, mut result: CheckResult| {
+        if let Some(dictionary) = user_dictionary {
+            dictionary.apply(text, &mut result.matches);
+        }
+        filter_matches(&mut result, registry, rules);
+        result.sort_and_dedupe();
+        annotated.map_matches(&mut result.matches);
+        total += result.matches.len();
+        state.metrics.record_matches(lang_code, result.matches.iter().map(|m| m.rule_id.as_str()));
+        let matches = convert_matches(result.matches, annotated.original_text(), lang_code, registry);
+        let event = Event::default()
+            .event("matches")
+            .json_data(StreamMatches { paragraph, matches })
+            .unwrap();
+        tx.blocking_send(event).is_ok()
+    };
+
+    for (index, span) in paragraphs.iter().enumerate() {
+        if cancelled.load(Ordering::Relaxed) {
+            break;
+        }
+
+        let paragraph = &text[span.clone()];
+        let mut result = CheckResult {
+            matches: checker.check_paragraph(paragraph, level).matches.clone(),
+        };
+        if use_l2_fr {
+            result.matches.extend(l2_french_matches(paragraph));
+        }
+
+        // Paragraph offsets → document offsets
+        for m in &mut result.matches {
+            m.span = (m.span.start + span.start)..(m.span.end + span.start);
+        }
+        let result = result.filter_masked(&masks);
+
+        if !send_matches(Some(index), result) {
+            return;
+        }
+    }
+
+    // Document-level checkers (e.g. US/UK coherency) need the whole text
+    if !cancelled.load(Ordering::Relaxed) && pipeline.has_document_checkers() {
+        let result = pipeline.check_document_with_level(text, level);
+        if !send_matches(None, result) {
+            return;
+        }
+    }
+
+    let was_cancelled = cancelled.load(Ordering::Relaxed);
+    let elapsed = start.elapsed();
+    let summary = StreamSummary {
+        language: language_info(lang_code, confidence),
+        paragraphs: paragraphs.len(),
+        matches: total,
+        elapsed_ms: elapsed.as_millis() as u64,
+        cancelled: was_cancelled,
+    };
+    let _ = tx.blocking_send(Event::default().event("done").json_data(summary).unwrap());
+    state.metrics.record_check(lang_code, text.len(), elapsed);
+
+    tracing::info!(
+        lang = %lang_code,
+        level = level.as_str(),
+        paragraphs = paragraphs.len(),
+        matches = total,
+        text_len = annotated.original_text().len(),
+        elapsed_ms = elapsed.as_millis(),
+        cancelled = was_cancelled,
+        "Stream check completed"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_run_cancels_previous() {
+        let registry = StreamRegistry::new();
+        let first = registry.start(Some("doc"));
+        let second = registry.start(Some("doc"));
+        assert!(first.load(Ordering::Relaxed));
+        assert!(!second.load(Ordering::Relaxed));
+
+        // The superseded run must not unregister the newer one
+        registry.finish(Some("doc"), &first);
+        assert!(registry.cancel("doc"));
+        assert!(second.load(Ordering::Relaxed));
+        assert!(!registry.cancel("doc"));
+    }
+
+    #[test]
+    fn test_runs_without_document_id_are_independent() {
+        let registry = StreamRegistry::new();
+        let first = registry.start(None);
+        let _second = registry.start(None);
+        assert!(!first.load(Ordering::Relaxed));
+    }
+}
@@ -2,12 +2,24 @@
 //!
 //! These types match the LanguageTool API format for drop-in compatibility.
 
-use serde::{Deserialize, Serialize};
+use grammar_rs::core::CheckLevel;
+use grammar_rs::core::registry::split_sub_rule_id;
+use grammar_rs::markup::{AnnotatedText, MarkupFormat};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeSet;
 
-/// Request for /v2/check endpoint
+/// Request for /v2/check endpoint (form-encoded or JSON body)
 #[derive(Debug, Deserialize)]
 pub struct CheckRequest {
+    /// Plain text (or `markup` source); may be omitted when `data` is sent
+    #[serde(default)]
     pub text: String,
+    /// LanguageTool annotated text: `{"annotation": [{"text": ...}, {"markup": ..., "interpretAs": ...}]}`
+    /// (a JSON string, or the object itself in a JSON body)
+    #[serde(default, deserialize_with = "string_or_object")]
+    pub data: Option<String>,
+    /// Markup of `text` ("markdown", "html", "latex"), stripped before checking
+    pub markup: Option<String>,
     pub language: String,
     /// Native language of the writer (e.g., "fr" for French speakers)
     /// Enables L2-specific false friend detection
@@ -15,19 +27,325 @@ pub struct CheckRequest {
     pub mother_tongue: Option<String>,
     #[serde(rename = "preferredVariants")]
     pub preferred_variants: Option<String>,
-    #[serde(rename = "disabledRules")]
+    /// Comma-separated IDs (or an array in a JSON body)
+    #[serde(rename = "disabledRules", default, deserialize_with = "id_list")]
     pub disabled_rules: Option<String>,
-    #[serde(rename = "enabledRules")]
+    #[serde(rename = "enabledRules", default, deserialize_with = "id_list")]
     pub enabled_rules: Option<String>,
+    #[serde(rename = "disabledCategories", default, deserialize_with = "id_list")]
+    pub disabled_categories: Option<String>,
+    #[serde(rename = "enabledCategories", default, deserialize_with = "id_list")]
+    pub enabled_categories: Option<String>,
+    /// If true, only the rules and categories in `enabledRules` /
+    /// `enabledCategories` are active (LanguageTool semantics)
+    #[serde(rename = "enabledOnly", default, deserialize_with = "flag")]
+    pub enabled_only: bool,
+    /// "default" or "picky" (picky also runs the stricter style rules)
     pub level: Option<String>,
+    /// If true, only the paragraphs that changed since a previous request
+    /// are re-checked (for editors that re-send the whole document)
+    #[serde(default, deserialize_with = "flag")]
+    pub incremental: bool,
+    /// Client-side document ID for /v2/check/stream: a new run for the same
+    /// document cancels the previous one
+    #[serde(rename = "documentId")]
+    pub document_id: Option<String>,
+    /// Owner of the personal dictionary to apply (see /v2/words)
+    pub username: Option<String>,
+    /// Debug: time each step, checker and slowest rule and return the
+    /// breakdown in `profile` (bypasses the response cache and `incremental`)
+    #[serde(default, deserialize_with = "flag")]
+    pub profile: bool,
+    /// Key of `username` (see `[user_dictionaries] api_keys`)
+    #[serde(rename = "apiKey")]
+    pub api_key: Option<String>,
+}
+
+impl CheckRequest {
+    /// Strictness level requested by the client (unknown values fall back to default)
+    pub fn check_level(&self) -> CheckLevel {
+        parse_level(self.level.as_deref())
+    }
+
+    /// Text to check: `data`, or `text` with its `markup` stripped
+    ///
+    /// Match offsets are computed on the plain text, then mapped back to
+    /// the original document (`AnnotatedText::original_text`).
+    pub fn annotated_text(&self) -> Result<AnnotatedText, String> {
+        if let Some(data) = &self.data {
+            return AnnotatedText::from_lt_json(data);
+        }
+        let markup = match self.markup.as_deref() {
+            Some(markup) => markup.parse()?,
+            None => MarkupFormat::Plain,
+        };
+        Ok(markup.annotate(&self.text))
+    }
+}
+
+/// Request for /v2/check/batch endpoint (JSON body)
+///
+/// Options have the same meaning as in /v2/check and apply to every document.
+#[derive(Debug, Deserialize)]
+pub struct BatchRequest {
+    pub documents: Vec<BatchDocument>,
+    /// Language code, or "auto" to detect the language of each document
+    pub language: String,
+    #[serde(rename = "motherTongue")]
+    pub mother_tongue: Option<String>,
+    #[serde(rename = "preferredVariants")]
+    pub preferred_variants: Option<String>,
+    /// Comma-separated IDs or an array, as in /v2/check
+    #[serde(rename = "disabledRules", default, deserialize_with = "id_list")]
+    pub disabled_rules: Option<String>,
+    #[serde(rename = "enabledRules", default, deserialize_with = "id_list")]
+    pub enabled_rules: Option<String>,
+    #[serde(rename = "disabledCategories", default, deserialize_with = "id_list")]
+    pub disabled_categories: Option<String>,
+    #[serde(rename = "enabledCategories", default, deserialize_with = "id_list")]
+    pub enabled_categories: Option<String>,
+    #[serde(rename = "enabledOnly", default, deserialize_with = "flag")]
+    pub enabled_only: bool,
+    pub level: Option<String>,
+    /// Owner of the personal dictionary to apply (see /v2/words)
+    pub username: Option<String>,
+    /// Key of `username` (see `[user_dictionaries] api_keys`)
+    #[serde(rename = "apiKey")]
+    pub api_key: Option<String>,
+}
+
+impl BatchRequest {
+    /// Strictness level requested by the client (unknown values fall back to default)
+    pub fn check_level(&self) -> CheckLevel {
+        parse_level(self.level.as_deref())
+    }
+}
+
+/// One document of a batch
+#[derive(Debug, Deserialize)]
+pub struct BatchDocument {
+    /// Client ID echoed back in the result (e.g. a file path)
+    pub id: Option<String>,
+    pub text: String,
+}
+
+/// `data` field: a string (form body) or a JSON object (JSON body)
+fn string_or_object<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Data {
+        Text(String),
+        Object(serde_json::Value),
+    }
+
+    Ok(Option::<Data>::deserialize(deserializer)?.map(|data| match data {
+        Data::Text(text) => text,
+        Data::Object(value) => value.to_string(),
+    }))
+}
+
+/// ID list: "A,B" (form body) or `["A", "B"]` (JSON body)
+fn id_list<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum List {
+        Text(String),
+        Items(Vec<String>),
+    }
+
+    Ok(Option::<List>::deserialize(deserializer)?.map(|list| match list {
+        List::Text(text) => text,
+        List::Items(items) => items.join(","),
+    }))
+}
+
+/// Boolean: `true` (JSON body) or "true" (form body, or JSON clients that
+/// send every field as a string)
+fn flag<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Flag {
+        Bool(bool),
+        Text(String),
+    }
+
+    match Flag::deserialize(deserializer)? {
+        Flag::Bool(value) => Ok(value),
+        Flag::Text(text) => text.trim().parse().map_err(|_| {
+            serde::de::Error::custom(format!("expected true or false, got {:?}", text))
+        }),
+    }
+}
+
+/// Parse the `level` parameter (unknown values fall back to default)
+fn parse_level(level: Option<&str>) -> CheckLevel {
+    level
+        .and_then(|level| level.parse().ok())
+        .unwrap_or_default()
+}
+
+/// Rule and category selection requested by the client
+///
+/// Lists are stored as ordered sets so that two requests with the same
+/// options in a different order share a cache entry.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct RuleSelection {
+    pub enabled_rules: BTreeSet<String>,
+    pub disabled_rules: BTreeSet<String>,
+    pub enabled_categories: BTreeSet<String>,
+    pub disabled_categories: BTreeSet<String>,
+    pub enabled_only: bool,
+}
+
+impl RuleSelection {
+    /// Build the selection from the request parameters
+    ///
+    /// Returns an error if `enabledOnly=true` is sent without any enabled
+    /// rule or category, as LanguageTool does.
+    pub fn from_request(req: &CheckRequest) -> Result<Self, String> {
+        Self::from_lists(
+            req.enabled_rules.as_deref(),
+            req.disabled_rules.as_deref(),
+            req.enabled_categories.as_deref(),
+            req.disabled_categories.as_deref(),
+            req.enabled_only,
+        )
+    }
+
+    /// Build the selection from the options of a batch request
+    pub fn from_batch_request(req: &BatchRequest) -> Result<Self, String> {
+        Self::from_lists(
+            req.enabled_rules.as_deref(),
+            req.disabled_rules.as_deref(),
+            req.enabled_categories.as_deref(),
+            req.disabled_categories.as_deref(),
+            req.enabled_only,
+        )
+    }
+
+    fn from_lists(
+        enabled_rules: Option<&str>,
+        disabled_rules: Option<&str>,
+        enabled_categories: Option<&str>,
+        disabled_categories: Option<&str>,
+        enabled_only: bool,
+    ) -> Result<Self, String> {
+        let selection = Self {
+            enabled_rules: parse_id_list(enabled_rules),
+            disabled_rules: parse_id_list(disabled_rules),
+            enabled_categories: parse_id_list(enabled_categories),
+            disabled_categories: parse_id_list(disabled_categories),
+            enabled_only,
+        };
+
+        if selection.enabled_only
+            && selection.enabled_rules.is_empty()
+            && selection.enabled_categories.is_empty()
+        {
+            return Err(
+                "enabledOnly=true requires enabledRules or enabledCategories".to_string(),
+            );
+        }
+
+        Ok(selection)
+    }
+
+    /// Check if a match from `rule_id` in `category_id` should be returned
+    pub fn allows(&self, rule_id: &str, category_id: &str) -> bool {
+        if listed(&self.disabled_rules, rule_id) || self.disabled_categories.contains(category_id) {
+            return false;
+        }
+
+        if self.enabled_only {
+            return listed(&self.enabled_rules, rule_id)
+                || self.enabled_categories.contains(category_id);
+        }
+
+        true
+    }
+
+    /// Check if `rule_id` or its category is listed in `enabledRules` /
+    /// `enabledCategories` (turns on default-off rules)
+    pub fn explicitly_enables(&self, rule_id: &str, category_id: &str) -> bool {
+        listed(&self.enabled_rules, rule_id) || self.enabled_categories.contains(category_id)
+    }
+
+    /// True if the selection keeps every match (nothing to filter)
+    pub fn is_default(&self) -> bool {
+        self.disabled_rules.is_empty() && self.disabled_categories.is_empty() && !self.enabled_only
+    }
+}
+
+/// True if `rule_id` is in `ids`, or its group ID for a sub-rule `GROUP[n]`
+fn listed(ids: &BTreeSet<String>, rule_id: &str) -> bool {
+    ids.contains(rule_id) || ids.contains(split_sub_rule_id(rule_id).0)
+}
+
+/// Parse a comma-separated list of rule or category IDs
+fn parse_id_list(list: Option<&str>) -> BTreeSet<String> {
+    list.map(|s| {
+        s.split(',')
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(str::to_string)
+            .collect()
+    })
+    .unwrap_or_default()
 }
 
 /// Response from /v2/check endpoint
 #[derive(Debug, Clone, Serialize)]
 pub struct LanguageToolResponse {
     pub software: Software,
+    pub warnings: Warnings,
     pub language: LanguageInfo,
     pub matches: Vec<LTMatch>,
+    /// Timings, only when the request sets `profile`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<ProfileInfo>,
+}
+
+/// Timings of a profiled check (milliseconds)
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileInfo {
+    #[serde(rename = "totalMs")]
+    pub total_ms: f64,
+    #[serde(rename = "filterMs")]
+    pub filter_ms: f64,
+    #[serde(rename = "tokenizeMs")]
+    pub tokenize_ms: f64,
+    #[serde(rename = "analyzeMs")]
+    pub analyze_ms: f64,
+    /// Slowest checker first; checkers run in parallel
+    pub checkers: Vec<CheckerTiming>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckerTiming {
+    pub name: &'static str,
+    #[serde(rename = "elapsedMs")]
+    pub elapsed_ms: f64,
+    /// Matches before filtering and deduplication
+    pub matches: usize,
+    #[serde(rename = "slowestRules", skip_serializing_if = "Vec::is_empty")]
+    pub slowest_rules: Vec<RuleTimingInfo>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleTimingInfo {
+    #[serde(rename = "ruleId")]
+    pub rule_id: String,
+    #[serde(rename = "elapsedMs")]
+    pub elapsed_ms: f64,
+    pub matches: usize,
+}
+
+/// Warnings block of a response
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Warnings {
+    /// True if checking stopped before the end of the text (never the case here)
+    #[serde(rename = "incompleteResults")]
+    pub incomplete_results: bool,
 }
 
 /// Software information
@@ -67,21 +385,57 @@ pub struct LTMatch {
     pub replacements: Vec<Replacement>,
     pub rule: RuleInfo,
     pub context: Context,
+    /// Sentence containing the match
+    pub sentence: String,
+    #[serde(rename = "type")]
+    pub match_type: MatchType,
+    /// True if the match may be a false alarm while the sentence is still being typed
+    #[serde(rename = "ignoreForIncompleteSentence")]
+    pub ignore_for_incomplete_sentence: bool,
+    /// Number of tokens after the match needed to be sure of it (0: none)
+    #[serde(rename = "contextForSureMatch")]
+    pub context_for_sure_match: i32,
 }
 
 /// A suggested replacement
 #[derive(Debug, Clone, Serialize)]
 pub struct Replacement {
     pub value: String,
+    /// Short definition of the replacement word, when known
+    #[serde(rename = "shortDescription", skip_serializing_if = "Option::is_none")]
+    pub short_description: Option<String>,
+}
+
+/// LanguageTool match type
+#[derive(Debug, Clone, Serialize)]
+pub struct MatchType {
+    /// "UnknownWord" (spelling), "Hint" or "Other"
+    #[serde(rename = "typeName")]
+    pub type_name: &'static str,
 }
 
 /// Information about the rule that triggered the match
 #[derive(Debug, Clone, Serialize)]
 pub struct RuleInfo {
+    /// Rule ID (the group ID for a sub-rule of a LanguageTool rulegroup)
     pub id: String,
+    /// Index of the sub-rule within its group
+    #[serde(rename = "subId", skip_serializing_if = "Option::is_none")]
+    pub sub_id: Option<String>,
+    pub description: String,
+    #[serde(rename = "issueType")]
+    pub issue_type: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub urls: Vec<Url>,
     pub category: Category,
 }
 
+/// Link to a page explaining a rule
+#[derive(Debug, Clone, Serialize)]
+pub struct Url {
+    pub value: String,
+}
+
 /// Category of the rule
 #[derive(Debug, Clone, Serialize)]
 pub struct Category {
@@ -106,20 +460,296 @@ pub struct LanguageResponse {
     pub long_code: String,
 }
 
+/// Query parameters for /v2/rules endpoint
+#[derive(Debug, Deserialize)]
+pub struct RulesQuery {
+    /// Language code (e.g. "en-US", "fr"), defaults to English
+    pub language: Option<String>,
+}
+
+/// Response for /v2/rules endpoint
+#[derive(Debug, Serialize)]
+pub struct RulesResponse {
+    pub language: String,
+    pub rules: Vec<RuleDescription>,
+}
+
+/// Metadata of one rule the server can emit
+#[derive(Debug, Serialize)]
+pub struct RuleDescription {
+    pub id: String,
+    pub description: String,
+    pub category: Category,
+    #[serde(rename = "issueType")]
+    pub issue_type: &'static str,
+    /// "default" or "picky"
+    pub level: &'static str,
+    #[serde(rename = "defaultOn")]
+    pub default_on: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<RuleExampleInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Example sentence attached to a rule
+#[derive(Debug, Serialize)]
+pub struct RuleExampleInfo {
+    pub text: String,
+    #[serde(rename = "isCorrect")]
+    pub is_correct: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correction: Option<String>,
+}
+
+/// Response from /v2/check/batch endpoint
+#[derive(Debug, Serialize)]
+pub struct BatchResponse {
+    pub software: Software,
+    /// One result per document, in request order
+    pub results: Vec<BatchResult>,
+    #[serde(rename = "elapsedMs")]
+    pub elapsed_ms: u64,
+}
+
+/// Result of one document of a batch
+#[derive(Debug, Serialize)]
+pub struct BatchResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub language: LanguageInfo,
+    pub matches: Vec<LTMatch>,
+    /// Time spent checking this document
+    #[serde(rename = "elapsedMs")]
+    pub elapsed_ms: f64,
+}
+
+/// `matches` event of /v2/check/stream
+#[derive(Debug, Serialize)]
+pub struct StreamMatches {
+    /// Index of the checked paragraph, `None` for document-level checkers
+    pub paragraph: Option<usize>,
+    pub matches: Vec<LTMatch>,
+}
+
+/// Final `done` event of /v2/check/stream
+#[derive(Debug, Serialize)]
+pub struct StreamSummary {
+    pub language: LanguageInfo,
+    pub paragraphs: usize,
+    pub matches: usize,
+    #[serde(rename = "elapsedMs")]
+    pub elapsed_ms: u64,
+    /// True if a newer run or a cancel request stopped this run early
+    pub cancelled: bool,
+}
+
+/// Request for /v2/check/cancel
+#[derive(Debug, Deserialize)]
+pub struct CancelRequest {
+    #[serde(rename = "documentId")]
+    pub document_id: String,
+}
+
+/// Query parameters for GET /v2/words
+#[derive(Debug, Deserialize)]
+pub struct WordsListQuery {
+    pub username: Option<String>,
+    /// Key of `username` (see `[user_dictionaries] api_keys`)
+    #[serde(rename = "apiKey")]
+    pub api_key: Option<String>,
+    /// Number of words to skip (default 0)
+    pub offset: Option<usize>,
+    /// Maximum number of words returned (default 10)
+    pub limit: Option<usize>,
+}
+
+/// Request for /v2/words/add and /v2/words/delete (form-encoded or JSON body)
+#[derive(Debug, Deserialize)]
+pub struct WordRequest {
+    pub word: String,
+    pub username: Option<String>,
+    /// Key of `username` (see `[user_dictionaries] api_keys`)
+    #[serde(rename = "apiKey")]
+    pub api_key: Option<String>,
+}
+
+/// Response for GET /v2/words
+#[derive(Debug, Serialize)]
+pub struct WordsListResponse {
+    pub words: Vec<String>,
+}
+
+/// Response for /v2/words/add
+#[derive(Debug, Serialize)]
+pub struct WordsAddResponse {
+    /// False if the word was already in the dictionary
+    pub added: bool,
+}
+
+/// Response for /v2/words/delete
+#[derive(Debug, Serialize)]
+pub struct WordsDeleteResponse {
+    /// False if the word was not in the dictionary
+    pub deleted: bool,
+}
+
 /// Cache key for response caching
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct CacheKey {
     pub text: String,
+    pub data: Option<String>,
+    pub markup: Option<String>,
     pub language: String,
     pub mother_tongue: Option<String>,
+    pub rules: RuleSelection,
+    pub level: CheckLevel,
+    pub incremental: bool,
+    /// Version of the personal dictionary applied (0: none)
+    pub user_words: u64,
 }
 
 impl CacheKey {
-    pub fn from_request(req: &CheckRequest, lang_code: &str) -> Self {
+    pub fn from_request(req: &CheckRequest, lang_code: &str, rules: &RuleSelection, user_words: u64) -> Self {
         Self {
             text: req.text.clone(),
+            data: req.data.clone(),
+            markup: req.markup.clone(),
             language: lang_code.to_string(),
             mother_tongue: req.mother_tongue.clone(),
+            rules: rules.clone(),
+            level: req.check_level(),
+            incremental: req.incremental,
+            user_words,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(enabled: &str, disabled: &str, categories: &str, enabled_only: bool) -> CheckRequest {
+        let non_empty = |s: &str| (!s.is_empty()).then(|| s.to_string());
+        CheckRequest {
+            text: "text".to_string(),
+            data: None,
+            markup: None,
+            language: "en-US".to_string(),
+            mother_tongue: None,
+            preferred_variants: None,
+            disabled_rules: non_empty(disabled),
+            enabled_rules: non_empty(enabled),
+            disabled_categories: None,
+            enabled_categories: non_empty(categories),
+            enabled_only,
+            level: None,
+            incremental: false,
+            document_id: None,
+            username: None,
+            profile: false,
+            api_key: None,
         }
     }
+
+    #[test]
+    fn test_check_level() {
+        let mut req = request("", "", "", false);
+        assert_eq!(req.check_level(), CheckLevel::Default);
+        req.level = Some("picky".to_string());
+        assert_eq!(req.check_level(), CheckLevel::Picky);
+        req.level = Some("unknown".to_string());
+        assert_eq!(req.check_level(), CheckLevel::Default);
+    }
+
+    #[test]
+    fn test_disabled_rules() {
+        let selection = RuleSelection::from_request(&request("", "PASSIVE_VOICE, SENTENCE_LENGTH", "", false)).unwrap();
+        assert!(!selection.allows("PASSIVE_VOICE", "STYLE"));
+        assert!(!selection.allows("SENTENCE_LENGTH", "STYLE"));
+        assert!(selection.allows("SPELL", "TYPOS"));
+    }
+
+    #[test]
+    fn test_explicitly_enables() {
+        let selection = RuleSelection::from_request(&request("OFF_RULE", "", "", false)).unwrap();
+        assert!(selection.explicitly_enables("OFF_RULE", "STYLE"));
+        assert!(!selection.explicitly_enables("OTHER_RULE", "STYLE"));
+
+        // Enabling a category turns on its default-off rules, even without enabledOnly
+        let selection = RuleSelection::from_request(&request("", "", "STYLE", false)).unwrap();
+        assert!(selection.explicitly_enables("OTHER_RULE", "STYLE"));
+        assert!(!selection.explicitly_enables("OTHER_RULE", "TYPOS"));
+    }
+
+    #[test]
+    fn test_enabled_only() {
+        let selection = RuleSelection::from_request(&request("EN_A_AN", "", "TYPOS", true)).unwrap();
+        assert!(selection.allows("EN_A_AN", "GRAMMAR"));
+        assert!(selection.allows("SPELL", "TYPOS"));
+        assert!(!selection.allows("PASSIVE_VOICE", "STYLE"));
+    }
+
+    #[test]
+    fn test_enabled_only_requires_ids() {
+        assert!(RuleSelection::from_request(&request("", "", "", true)).is_err());
+    }
+
+    #[test]
+    fn test_batch_request_from_json() {
+        let req: BatchRequest = serde_json::from_str(
+            r#"{"language":"en-US","level":"picky","disabledRules":"A,B",
+                "documents":[{"id":"a.md","text":"One."},{"text":"Two."}]}"#,
+        )
+        .unwrap();
+        assert_eq!(req.documents.len(), 2);
+        assert_eq!(req.documents[0].id.as_deref(), Some("a.md"));
+        assert_eq!(req.check_level(), CheckLevel::Picky);
+
+        let selection = RuleSelection::from_batch_request(&req).unwrap();
+        assert!(!selection.allows("A", "STYLE"));
+        assert!(selection.allows("C", "STYLE"));
+    }
+
+    #[test]
+    fn test_check_request_from_json() {
+        let req: CheckRequest = serde_json::from_str(
+            r#"{"language":"en-US","text":"Hello.","enabledOnly":true,
+                "enabledRules":["A","B"],"disabledCategories":"STYLE","incremental":"true"}"#,
+        )
+        .unwrap();
+        assert!(req.enabled_only);
+        assert!(req.incremental);
+        assert_eq!(req.enabled_rules.as_deref(), Some("A,B"));
+        assert_eq!(req.disabled_categories.as_deref(), Some("STYLE"));
+        assert!(req.data.is_none());
+
+        let selection = RuleSelection::from_request(&req).unwrap();
+        assert!(selection.allows("B", "GRAMMAR"));
+        assert!(!selection.allows("C", "GRAMMAR"));
+    }
+
+    #[test]
+    fn test_check_request_data_object() {
+        let req: CheckRequest = serde_json::from_str(
+            r#"{"language":"en-US","data":{"annotation":[{"text":"A "},{"markup":"<b>"},{"text":"test."}]}}"#,
+        )
+        .unwrap();
+        assert_eq!(req.annotated_text().unwrap().plain_text(), "A test.");
+    }
+
+    #[test]
+    fn test_cache_key_ignores_list_order() {
+        let a = RuleSelection::from_request(&request("", "A,B", "", false)).unwrap();
+        let b = RuleSelection::from_request(&request("", "B, A", "", false)).unwrap();
+        let req = request("", "", "", false);
+        assert_eq!(CacheKey::from_request(&req, "en-US", &a, 0), CacheKey::from_request(&req, "en-US", &b, 0));
+    }
+
+    #[test]
+    fn test_cache_key_includes_dictionary_version() {
+        let rules = RuleSelection::default();
+        let req = request("", "", "", false);
+        assert_ne!(CacheKey::from_request(&req, "en-US", &rules, 0), CacheKey::from_request(&req, "en-US", &rules, 7));
+    }
 }
@@ -0,0 +1,395 @@
+//! Personal dictionaries (LanguageTool `/v2/words` endpoints)
+//!
+//! Each user has one word list stored as a text file (one word per line)
+//! in `USER_DICT_DIR` (default `data/user_dictionaries`). Lists are loaded
+//! on first use and kept in memory; every change gets a new version,
+//! which is part of the response cache key of /v2/check.
+//!
+//! Dictionaries are disabled unless `[user_dictionaries] api_keys` lists
+//! the users: every request must then send the `apiKey` of its `username`.
+//! Without keys the /v2/words routes answer 404 and `username` is ignored
+//! on the check endpoints.
+//!
+//! Only non-empty lists are kept in memory, so the cache holds at most the
+//! users that have words (themselves bounded by the configured keys).
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    Json,
+};
+use grammar_rs::checker::UserDictionary;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+
+use crate::admin::same_token;
+use crate::extract::FormOrJson;
+use crate::state::AppState;
+use crate::types::{WordsAddResponse, WordsDeleteResponse, WordsListQuery, WordsListResponse, WordRequest};
+
+/// Longest accepted word or username, in chars
+const MAX_LENGTH: usize = 100;
+
+/// Word list of one user
+pub struct UserWords {
+    /// Unique across all users and changes (0: never used)
+    pub version: u64,
+    pub words: BTreeSet<String>,
+    pub dictionary: Arc<UserDictionary>,
+}
+
+impl UserWords {
+    fn new(version: u64, words: BTreeSet<String>) -> Self {
+        let dictionary = Arc::new(UserDictionary::new(&words));
+        Self { version, words, dictionary }
+    }
+}
+
+/// On-disk store of the personal dictionaries
+pub struct WordStore {
+    dir: PathBuf,
+    /// Non-empty word lists only
+    users: RwLock<HashMap<String, Arc<UserWords>>>,
+    /// Shared by every user without words
+    empty: Arc<UserWords>,
+    next_version: AtomicU64,
+    max_words: usize,
+    /// `apiKey` of each user (empty: dictionaries disabled)
+    api_keys: BTreeMap<String, String>,
+}
+
+impl WordStore {
+    /// Open (and create if needed) the store directory
+    pub fn open(dir: impl Into<PathBuf>, max_words: usize, api_keys: BTreeMap<String, String>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir,
+            users: RwLock::new(HashMap::new()),
+            empty: Arc::new(UserWords::new(0, BTreeSet::new())),
+            next_version: AtomicU64::new(1),
+            max_words,
+            api_keys,
+        })
+    }
+
+    /// False when no user has an API key
+    pub fn is_enabled(&self) -> bool {
+        !self.api_keys.is_empty()
+    }
+
+    /// Check the `apiKey` of a user
+    pub fn authorize(&self, username: &str, api_key: Option<&str>) -> Result<(), (StatusCode, String)> {
+        if !self.is_enabled() {
+            return Err((StatusCode::NOT_FOUND, "personal dictionaries are disabled".to_string()));
+        }
+        let expected = self.api_keys.get(username);
+        match (expected, api_key) {
+            (Some(expected), Some(given)) if same_token(given.trim(), expected) => Ok(()),
+            _ => Err((StatusCode::UNAUTHORIZED, "invalid username or apiKey".to_string())),
+        }
+    }
+
+    /// Word list of a user (empty if the user never added a word)
+    ///
+    /// A cached user only needs the read lock; others are read from disk
+    /// outside the lock.
+    pub fn get(&self, username: &str) -> io::Result<Arc<UserWords>> {
+        if let Some(words) = self.users.read().unwrap().get(username) {
+            return Ok(Arc::clone(words));
+        }
+        let words = self.read(username)?;
+        if words.is_empty() {
+            return Ok(Arc::clone(&self.empty));
+        }
+        // A concurrent update may have cached a newer list meanwhile
+        let mut users = self.users.write().unwrap();
+        let words = users.entry(username.to_string()).or_insert_with(|| {
+            Arc::new(UserWords::new(self.next_version.fetch_add(1, Ordering::Relaxed), words))
+        });
+        Ok(Arc::clone(words))
+    }
+
+    /// Add a word (false if it was already there)
+    pub fn add(&self, username: &str, word: &str) -> Result<bool, (StatusCode, String)> {
+        self.update(username, |words| {
+            if words.len() >= self.max_words {
+                return Err((
+                    StatusCode::FORBIDDEN,
+                    format!("dictionary is full ({} words)", self.max_words),
+                ));
+            }
+            Ok(words.insert(word.to_string()))
+        })
+    }
+
+    /// Remove a word (false if it was not there)
+    pub fn delete(&self, username: &str, word: &str) -> Result<bool, (StatusCode, String)> {
+        self.update(username, |words| Ok(words.remove(word)))
+    }
+
+    /// Apply a change to a user's words, then save them if they changed
+    fn update(
+        &self,
+        username: &str,
+        change: impl FnOnce(&mut BTreeSet<String>) -> Result<bool, (StatusCode, String)>,
+    ) -> Result<bool, (StatusCode, String)> {
+        // Held for the whole change so that two updates cannot lose a word
+        let mut users = self.users.write().unwrap();
+        let mut words = match users.get(username) {
+            Some(current) => current.words.clone(),
+            None => self.read(username).map_err(internal_error)?,
+        };
+        if !change(&mut words)? {
+            return Ok(false);
+        }
+        write_words(&self.user_path(username), &words).map_err(internal_error)?;
+
+        if words.is_empty() {
+            users.remove(username);
+        } else {
+            let version = self.next_version.fetch_add(1, Ordering::Relaxed);
+            users.insert(username.to_string(), Arc::new(UserWords::new(version, words)));
+        }
+        Ok(true)
+    }
+
+    /// Words of a user on disk (empty without a file)
+    fn read(&self, username: &str) -> io::Result<BTreeSet<String>> {
+        match fs::read_to_string(self.user_path(username)) {
+            Ok(content) => Ok(content.lines().map(str::trim).filter(|w| !w.is_empty()).map(str::to_string).collect()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(BTreeSet::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn user_path(&self, username: &str) -> PathBuf {
+        self.dir.join(format!("{}.txt", file_name(username)))
+    }
+}
+
+/// File name of a user: safe characters are kept, the others `%XX`-escaped
+fn file_name(username: &str) -> String {
+    let mut name = String::new();
+    for byte in username.bytes() {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'@' => name.push(byte as char),
+            b'.' if !name.is_empty() => name.push('.'),
+            _ => name.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    name
+}
+
+/// Write the words atomically (temporary file, then rename)
+fn write_words(path: &Path, words: &BTreeSet<String>) -> io::Result<()> {
+    let mut content = String::new();
+    for word in words {
+        content.push_str(word);
+        content.push('\n');
+    }
+    let tmp = path.with_extension("txt.tmp");
+    fs::write(&tmp, content)?;
+    fs::rename(&tmp, path)
+}
+
+fn internal_error(e: io::Error) -> (StatusCode, String) {
+    tracing::error!("User dictionary store error: {}", e);
+    (StatusCode::INTERNAL_SERVER_ERROR, "user dictionary store error".to_string())
+}
+
+/// Check the `username` parameter
+fn validate_username(username: Option<&str>) -> Result<&str, (StatusCode, String)> {
+    match username.map(str::trim) {
+        Some(name) if !name.is_empty() && name.chars().count() <= MAX_LENGTH => Ok(name),
+        Some(name) if !name.is_empty() => Err((StatusCode::BAD_REQUEST, "username is too long".to_string())),
+        _ => Err((StatusCode::BAD_REQUEST, "missing username".to_string())),
+    }
+}
+
+/// Check a word to add or delete (LanguageTool rejects words with spaces)
+fn validate_word(word: &str) -> Result<&str, (StatusCode, String)> {
+    let word = word.trim();
+    if word.is_empty() {
+        Err((StatusCode::BAD_REQUEST, "missing word".to_string()))
+    } else if word.chars().any(char::is_whitespace) {
+        Err((StatusCode::BAD_REQUEST, "words must not contain whitespace".to_string()))
+    } else if word.chars().count() > MAX_LENGTH {
+        Err((StatusCode::BAD_REQUEST, "word is too long".to_string()))
+    } else {
+        Ok(word)
+    }
+}
+
+/// Personal dictionary of a check request (`None` without a username or words)
+///
+/// `username` is ignored while dictionaries are disabled; otherwise its
+/// `apiKey` must match.
+pub async fn request_words(
+    state: &Arc<AppState>,
+    username: Option<&str>,
+    api_key: Option<&str>,
+) -> Result<Option<Arc<UserWords>>, (StatusCode, String)> {
+    let Some(username) = username.map(str::trim).filter(|name| !name.is_empty()) else {
+        return Ok(None);
+    };
+    if !state.words.is_enabled() {
+        return Ok(None);
+    }
+    let username = validate_username(Some(username))?.to_string();
+    state.words.authorize(&username, api_key)?;
+    let state = Arc::clone(state);
+    let words = tokio::task::spawn_blocking(move || state.words.get(&username))
+        .await
+        .unwrap()
+        .map_err(internal_error)?;
+    Ok((!words.words.is_empty()).then_some(words))
+}
+
+/// Handle GET /v2/words
+///
+/// Lists the words of a user (`offset`, `limit`)
+pub async fn words_handler(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<WordsListQuery>,
+) -> Result<Json<WordsListResponse>, (StatusCode, String)> {
+    let username = validate_username(query.username.as_deref())?.to_string();
+    state.words.authorize(&username, query.api_key.as_deref())?;
+    let words = tokio::task::spawn_blocking(move || state.words.get(&username))
+        .await
+        .unwrap()
+        .map_err(internal_error)?;
+
+    let words = words
+        .words
+        .iter()
+        .skip(query.offset.unwrap_or(0))
+        .take(query.limit.unwrap_or(10))
+        .cloned()
+        .collect();
+    Ok(Json(WordsListResponse { words }))
+}
+
+/// Handle POST /v2/words/add
+pub async fn words_add_handler(
+    State(state): State<Arc<AppState>>,
+    FormOrJson(req): FormOrJson<WordRequest>,
+) -> Result<Json<WordsAddResponse>, (StatusCode, String)> {
+    let username = validate_username(req.username.as_deref())?.to_string();
+    state.words.authorize(&username, req.api_key.as_deref())?;
+    let word = validate_word(&req.word)?.to_string();
+    let added = tokio::task::spawn_blocking(move || state.words.add(&username, &word))
+        .await
+        .unwrap()?;
+    Ok(Json(WordsAddResponse { added }))
+}
+
+/// Handle POST /v2/words/delete
+pub async fn words_delete_handler(
+    State(state): State<Arc<AppState>>,
+    FormOrJson(req): FormOrJson<WordRequest>,
+) -> Result<Json<WordsDeleteResponse>, (StatusCode, String)> {
+    let username = validate_username(req.username.as_deref())?.to_string();
+    state.words.authorize(&username, req.api_key.as_deref())?;
+    let word = validate_word(&req.word)?.to_string();
+    let deleted = tokio::task::spawn_blocking(move || state.words.delete(&username, &word))
+        .await
+        .unwrap()?;
+    Ok(Json(WordsDeleteResponse { deleted }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_store(name: &str) -> (PathBuf, WordStore) {
+        let dir = std::env::temp_dir().join(format!("grammar-rs-words-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let keys = BTreeMap::from([("alice".to_string(), "s3cret".to_string())]);
+        let store = WordStore::open(&dir, 3, keys).unwrap();
+        (dir, store)
+    }
+
+    #[test]
+    fn test_add_delete_and_reload() {
+        let (dir, store) = temp_store("reload");
+        assert_eq!(store.get("alice").unwrap().version, 0);
+
+        assert!(store.add("alice", "Kubernetes").unwrap());
+        assert!(!store.add("alice", "Kubernetes").unwrap());
+        assert!(store.add("alice", "Grafana").unwrap());
+        let first = store.get("alice").unwrap();
+        assert!(first.dictionary.contains("Kubernetes"));
+
+        assert!(store.delete("alice", "Grafana").unwrap());
+        assert!(!store.delete("alice", "Grafana").unwrap());
+        let second = store.get("alice").unwrap();
+        assert!(second.version > first.version);
+        assert!(store.get("bob").unwrap().words.is_empty());
+
+        // A new store reads the words back from disk
+        let reopened = WordStore::open(&dir, 3, BTreeMap::new()).unwrap();
+        let words: Vec<_> = reopened.get("alice").unwrap().words.iter().cloned().collect();
+        assert_eq!(words, vec!["Kubernetes"]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_unknown_users_are_not_cached() {
+        let (dir, store) = temp_store("unknown");
+        for i in 0..100 {
+            assert_eq!(store.get(&format!("user{}", i)).unwrap().version, 0);
+        }
+        assert!(store.users.read().unwrap().is_empty());
+
+        // Deleting the last word forgets the user
+        store.add("alice", "Kubernetes").unwrap();
+        assert_eq!(store.users.read().unwrap().len(), 1);
+        store.delete("alice", "Kubernetes").unwrap();
+        assert!(store.users.read().unwrap().is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_authorize() {
+        let (dir, store) = temp_store("authorize");
+        assert!(store.authorize("alice", Some("s3cret")).is_ok());
+        assert_eq!(store.authorize("alice", Some("guess")).unwrap_err().0, StatusCode::UNAUTHORIZED);
+        assert_eq!(store.authorize("alice", None).unwrap_err().0, StatusCode::UNAUTHORIZED);
+        assert_eq!(store.authorize("bob", Some("s3cret")).unwrap_err().0, StatusCode::UNAUTHORIZED);
+
+        let disabled = WordStore::open(&dir, 3, BTreeMap::new()).unwrap();
+        assert_eq!(disabled.authorize("alice", Some("s3cret")).unwrap_err().0, StatusCode::NOT_FOUND);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_word_limit() {
+        let (dir, store) = temp_store("limit");
+        for word in ["a", "b", "c"] {
+            store.add("alice", word).unwrap();
+        }
+        let (status, _) = store.add("alice", "d").unwrap_err();
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_file_name_escapes_paths() {
+        assert_eq!(file_name("alice@example.com"), "alice@example.com");
+        assert_eq!(file_name("../etc/passwd"), "%2E.%2Fetc%2Fpasswd");
+        assert_eq!(file_name("a b"), "a%20b");
+    }
+
+    #[test]
+    fn test_validate_word() {
+        assert_eq!(validate_word(" Kubernetes ").unwrap(), "Kubernetes");
+        assert!(validate_word("two words").is_err());
+        assert!(validate_word("").is_err());
+        assert!(validate_username(None).is_err());
+    }
+}
@@ -1,28 +1,103 @@
-//! Build FST dictionary from wordlist
+//! Build FST dictionary from wordlists
 //!
-//! Usage: cargo run --bin build_dict -- <input.txt> <output.fst>
+//! Usage: cargo run --bin build-dict -- <input>... <output.fst> [--fr-builtins]
+//!        cargo run --bin build-dict -- --hunspell <input.dic> [input.aff] <output.fst>
+//!
+//! Inputs are wordlists (one word per line) or lexicons whose first
+//! tab-separated column is the form (Lefff `form\tlemma\ttag`); they are
+//! merged, lowercased and deduplicated. `--fr-builtins` adds the French
+//! lists compiled into the crate (common words, spelling, hyphenated words).
+//!
+//! `--hunspell` expands a Hunspell dictionary with its affix rules; the
+//! `.aff` defaults to the `.dic` path with the `.aff` extension.
 
+use grammar_rs::checker::data::fr_hyphenated::FR_HYPHENATED;
+use grammar_rs::checker::{FR_COMMON_WORDS, FR_SPELLING};
 use grammar_rs::dictionary::FstDictionary;
 use std::env;
+use std::path::Path;
 use std::time::Instant;
 
+fn usage(program: &str) -> ! {
+    eprintln!("Usage: {} <input>... <output.fst> [--fr-builtins]", program);
+    eprintln!("       {} --hunspell <input.dic> [input.aff] <output.fst>", program);
+    eprintln!("  input         - Wordlist (one word per line) or TSV lexicon (form in first column)");
+    eprintln!("  output.fst    - Output FST file");
+    eprintln!("  --fr-builtins - Add FR_COMMON_WORDS, FR_SPELLING and FR_HYPHENATED");
+    eprintln!("  --hunspell    - Expand a Hunspell .dic/.aff pair (affix rules)");
+    std::process::exit(1);
+}
+
+/// Built-in French words; hyphenated words also contribute their parts,
+/// since the tokenizer splits on hyphens
+fn fr_builtin_words() -> Vec<String> {
+    let hyphenated = FR_HYPHENATED.iter().flat_map(|word| {
+        std::iter::once(*word).chain(word.split('-').filter(|part| !part.is_empty()))
+    });
+    FR_COMMON_WORDS.iter().copied()
+        .chain(FR_SPELLING.iter().copied())
+        .chain(hyphenated)
+        .map(str::to_string)
+        .collect()
+}
+
+/// Expand a Hunspell dictionary and print the expansion stats
+fn load_hunspell(dic_path: &str, aff_path: Option<&String>) -> FstDictionary {
+    let aff_path = aff_path.cloned()
+        .unwrap_or_else(|| Path::new(dic_path).with_extension("aff").to_string_lossy().into_owned());
+    println!("Expanding Hunspell dictionary: {} + {}", dic_path, aff_path);
+
+    let (dict, stats) = match FstDictionary::from_hunspell(dic_path, aff_path.as_str()) {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("Error loading Hunspell dictionary: {}", e);
+            std::process::exit(1);
+        }
+    };
+    println!("  stems:           {}", stats.stems);
+    println!("  affix rules:     {}", stats.affix_rules);
+    println!("  expanded forms:  {}", stats.expanded_forms);
+    println!("  forbidden words: {}", stats.forbidden);
+    println!("  unique words:    {}", dict.len());
+    dict
+}
+
 fn main() {
     let args: Vec<String> = env::args().collect();
+    let fr_builtins = args.iter().any(|a| a == "--fr-builtins");
+    let hunspell = args.iter().any(|a| a == "--hunspell");
+    let paths: Vec<&String> = args.iter().skip(1)
+        .filter(|a| *a != "--fr-builtins" && *a != "--hunspell")
+        .collect();
 
-    if args.len() != 3 {
-        eprintln!("Usage: {} <input.txt> <output.fst>", args[0]);
-        eprintln!("  input.txt  - Wordlist file (one word per line, sorted)");
-        eprintln!("  output.fst - Output FST file");
-        std::process::exit(1);
+    let Some((output_path, input_paths)) = paths.split_last() else {
+        usage(&args[0]);
+    };
+    if input_paths.is_empty() && !fr_builtins {
+        usage(&args[0]);
     }
 
-    let input_path = &args[1];
-    let output_path = &args[2];
-
-    println!("Loading wordlist from: {}", input_path);
     let start = Instant::now();
 
-    let dict = match FstDictionary::from_wordlist(input_path) {
+    let dict = if hunspell {
+        match input_paths {
+            [dic] => Ok(load_hunspell(dic, None)),
+            [dic, aff] => Ok(load_hunspell(dic, Some(aff))),
+            _ => usage(&args[0]),
+        }
+    } else {
+        println!("Loading words from: {:?}", input_paths);
+        FstDictionary::from_wordlists(input_paths.iter().map(|p| p.as_str()))
+    };
+    let dict = dict.and_then(|dict| {
+        if !fr_builtins {
+            return Ok(dict);
+        }
+        let builtins = fr_builtin_words();
+        println!("Adding {} built-in French words", builtins.len());
+        dict.with_words(builtins)
+    });
+    let dict = match dict {
         Ok(d) => d,
         Err(e) => {
             eprintln!("Error loading wordlist: {}", e);
@@ -31,7 +106,7 @@ fn main() {
     };
 
     let load_time = start.elapsed();
-    println!("Loaded {} words in {:.2?}", dict.len(), load_time);
+    println!("Built {} words in {:.2?}", dict.len(), load_time);
 
     println!("Saving FST to: {}", output_path);
     let start = Instant::now();
@@ -0,0 +1,61 @@
+//! Compile a morphology lexicon (Lefff TSV) into the memory-mapped format
+//!
+//! Usage: cargo run --release --bin build-morphology -- <input.tsv> <output.morph>
+//!
+//! The input has one `form<TAB>lemma<TAB>tag` per line. Put the output at
+//! `data/morphology/fr_lefff.morph` (or point `GRAMMAR_RS_FR_MORPHOLOGY` to
+//! it) to skip parsing the TSV at startup.
+
+use grammar_rs::morphology::{CompiledMorphology, MorphologyBuilder};
+use std::env;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::time::Instant;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    if args.len() != 3 {
+        eprintln!("Usage: {} <input.tsv> <output.morph>", args[0]);
+        eprintln!("  input.tsv    - Lexicon (form<TAB>lemma<TAB>tag per line)");
+        eprintln!("  output.morph - Output compiled morphology file");
+        std::process::exit(1);
+    }
+
+    let input_path = &args[1];
+    let output_path = Path::new(&args[2]);
+
+    println!("Loading lexicon from: {}", input_path);
+    let start = Instant::now();
+
+    let mut builder = MorphologyBuilder::new();
+    let loaded = File::open(input_path).and_then(|file| builder.add_tsv(BufReader::new(file)));
+    if let Err(e) = loaded {
+        eprintln!("Error loading lexicon: {}", e);
+        std::process::exit(1);
+    }
+    println!("Loaded {} forms in {:.2?}", builder.form_count(), start.elapsed());
+
+    println!("Saving compiled morphology to: {}", output_path.display());
+    let start = Instant::now();
+
+    if let Err(e) = builder.write(output_path) {
+        eprintln!("Error saving morphology: {}", e);
+        std::process::exit(1);
+    }
+    println!("Saved in {:.2?}", start.elapsed());
+
+    match CompiledMorphology::open(output_path) {
+        Ok(morph) => println!(
+            "Verified: {} forms, {} synthesis entries, {} KB",
+            morph.form_count(),
+            morph.synthesis_count(),
+            morph.file_size() / 1024
+        ),
+        Err(e) => {
+            eprintln!("Error reopening compiled morphology: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//! Input discovery - files, directories and glob patterns
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Extensions checked when walking a directory
+pub const DEFAULT_EXTENSIONS: &[&str] = &["md", "markdown", "txt", "rst", "adoc"];
+
+/// Expand the command line inputs into a sorted, de-duplicated file list
+///
+/// - a file is taken as is, whatever its extension
+/// - a directory is walked recursively, keeping `extensions` only
+/// - a pattern with `*` or `?` is matched against the files under its
+///   literal prefix (`**` matches any number of directories)
+pub fn collect_files(inputs: &[String], extensions: &[String]) -> Result<Vec<PathBuf>, String> {
+    let mut files = Vec::new();
+
+    for input in inputs {
+        if is_glob(input) {
+            let matched = expand_glob(input)?;
+            if matched.is_empty() {
+                return Err(format!("{}: pattern matched no files", input));
+            }
+            files.extend(matched);
+            continue;
+        }
+
+        let path = Path::new(input);
+        if path.is_dir() {
+            walk(path, &mut files, &|file| has_extension(file, extensions))
+                .map_err(|e| format!("{}: {}", input, e))?;
+        } else if path.is_file() {
+            files.push(path.to_path_buf());
+        } else {
+            return Err(format!("{}: no such file or directory", input));
+        }
+    }
+
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+fn has_extension(path: &Path, extensions: &[String]) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| extensions.iter().any(|e| e.eq_ignore_ascii_case(ext)))
+}
+
+/// Recursively collect the files of `dir` accepted by `keep` (hidden entries are skipped)
+fn walk(dir: &Path, files: &mut Vec<PathBuf>, keep: &dyn Fn(&Path) -> bool) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let hidden = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with('.'));
+        if hidden {
+            continue;
+        }
+
+        if path.is_dir() {
+            walk(&path, files, keep)?;
+        } else if keep(&path) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn is_glob(input: &str) -> bool {
+    input.contains(['*', '?'])
+}
+
+/// Files matching a glob pattern such as `docs/**/*.md`
+fn expand_glob(pattern: &str) -> Result<Vec<PathBuf>, String> {
+    let components: Vec<&str> = pattern.split('/').filter(|c| !c.is_empty() && *c != ".").collect();
+    let literal = components.iter().take_while(|c| !is_glob(c)).count();
+
+    let mut base = PathBuf::from(if pattern.starts_with('/') { "/" } else { "." });
+    base.extend(&components[..literal]);
+    let rest = &components[literal..];
+
+    if !base.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut files = Vec::new();
+    walk(&base, &mut files, &|file| {
+        let relative = file.strip_prefix(&base).unwrap_or(file);
+        let parts: Vec<&str> = relative.iter().filter_map(|c| c.to_str()).collect();
+        match_path(rest, &parts)
+    })
+    .map_err(|e| format!("{}: {}", pattern, e))?;
+
+    // Report paths the way the user wrote them (no leading "./")
+    Ok(files
+        .into_iter()
+        .map(|file| file.strip_prefix(".").map(Path::to_path_buf).unwrap_or(file))
+        .collect())
+}
+
+/// Match path components against pattern components (`**` = zero or more components)
+fn match_path(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| match_path(rest, &path[skip..])),
+        Some((first, rest)) => {
+            !path.is_empty() && match_component(first, path[0]) && match_path(rest, &path[1..])
+        }
+    }
+}
+
+/// Wildcard match of a single component (`*` = any run, `?` = any character)
+fn match_component(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, n));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            n = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_component() {
+        assert!(match_component("*.md", "readme.md"));
+        assert!(match_component("a?c", "abc"));
+        assert!(match_component("*", ""));
+        assert!(!match_component("*.md", "readme.txt"));
+        assert!(!match_component("a?c", "ac"));
+    }
+
+    #[test]
+    fn test_match_path() {
+        assert!(match_path(&["**", "*.md"], &["a.md"]));
+        assert!(match_path(&["**", "*.md"], &["guides", "setup", "a.md"]));
+        assert!(match_path(&["*", "*.md"], &["guides", "a.md"]));
+        assert!(!match_path(&["*", "*.md"], &["a.md"]));
+        assert!(!match_path(&["**", "*.md"], &["guides", "a.txt"]));
+    }
+
+    #[test]
+    fn test_collect_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("guides/.hidden")).unwrap();
+        fs::write(root.join("a.md"), "a").unwrap();
+        fs::write(root.join("guides/b.md"), "b").unwrap();
+        fs::write(root.join("guides/c.rs"), "c").unwrap();
+        fs::write(root.join("guides/.hidden/d.md"), "d").unwrap();
+
+        let extensions = vec!["md".to_string()];
+        let files = collect_files(&[root.display().to_string()], &extensions).unwrap();
+        assert_eq!(files, vec![root.join("a.md"), root.join("guides/b.md")]);
+
+        let pattern = format!("{}/**/*.rs", root.display());
+        let files = collect_files(&[pattern], &extensions).unwrap();
+        assert_eq!(files, vec![root.join("guides/c.rs")]);
+
+        assert!(collect_files(&[root.join("missing.md").display().to_string()], &extensions).is_err());
+    }
+}
@@ -0,0 +1,527 @@
+//! grammar-check - grammar linter for files, directories and stdin
+//!
+//! Uses the same checkers as the API server (`grammar_rs::presets`).
+//!
+//! ## Usage
+//!
+//! ```bash
+//! # Check a docs folder, fail the build on errors
+//! grammar-check docs/
+//!
+//! # Globs, explicit language, SARIF for GitHub code scanning
+//! grammar-check 'docs/**/*.md' --language en-GB --format sarif > grammar.sarif
+//!
+//! # stdin
+//! echo "I have a apple." | grammar-check -
+//!
+//! # Markup is stripped before checking (detected from the extension)
+//! grammar-check paper.tex README.md --markup auto
+//!
+//! # Apply the safe fixes in place, or preview them as a unified diff
+//! grammar-check docs/ --fix --fix-rules safe
+//! grammar-check docs/ --diff
+//!
+//! # Where does the time go? (per-step and per-checker timings on stderr)
+//! grammar-check README.md --profile
+//! ```
+//!
+//! ## Exit codes
+//!
+//! - `0` - no match at or above `--fail-on` (after fixes with `--fix`)
+//! - `1` - at least one match at or above `--fail-on`
+//! - `2` - usage or I/O error
+
+mod files;
+mod output;
+
+use grammar_rs::core::pipeline::par_map_bounded;
+use grammar_rs::core::profile::{CheckProfile, DEFAULT_TOP_RULES};
+use grammar_rs::core::registry::split_sub_rule_id;
+use grammar_rs::core::{CheckLevel, Severity};
+use grammar_rs::core::Match;
+use grammar_rs::fix::{apply_fixes, unified_diff, FixOptions, FixResult};
+use grammar_rs::lang_detect::{Language, LanguageDetector};
+use grammar_rs::markup::{AnnotatedText, MarkupFormat};
+use grammar_rs::prelude::Pipeline;
+use grammar_rs::presets::{en_pipelines, fr_pipelines, normalize_language, pipeline_language};
+use std::collections::HashSet;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::OnceLock;
+
+use files::{collect_files, DEFAULT_EXTENSIONS};
+use output::{render, FileReport, Finding, Format};
+
+/// Command line options
+struct Options {
+    inputs: Vec<String>,
+    /// Language code, or "auto" to detect it per file
+    language: String,
+    format: Format,
+    level: CheckLevel,
+    /// Markup of the inputs (`None` = from the file extension)
+    markup: Option<MarkupFormat>,
+    disabled: HashSet<String>,
+    enabled: HashSet<String>,
+    /// Lowest severity that makes the run fail (`None` = never fail)
+    fail_on: Option<Severity>,
+    extensions: Vec<String>,
+    /// What to do with the suggestions
+    fix_mode: FixMode,
+    /// Rules allowed to fix the text
+    fix_rules: FixOptions,
+    /// Print the timings of each file to stderr
+    profile: bool,
+}
+
+/// `--fix` / `--diff`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FixMode {
+    /// Only report
+    Report,
+    /// Rewrite the files (stdin: print the fixed text)
+    Write,
+    /// Print a unified diff of the fixes
+    Diff,
+}
+
+impl Options {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = Self {
+            inputs: Vec::new(),
+            language: "auto".to_string(),
+            format: Format::Human,
+            level: CheckLevel::Default,
+            markup: None,
+            disabled: HashSet::new(),
+            enabled: HashSet::new(),
+            fail_on: Some(Severity::Error),
+            extensions: DEFAULT_EXTENSIONS.iter().map(|e| e.to_string()).collect(),
+            fix_mode: FixMode::Report,
+            fix_rules: FixOptions::all(),
+            profile: false,
+        };
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .cloned()
+                    .ok_or_else(|| format!("{} requires a value", arg))
+            };
+
+            match arg.as_str() {
+                "-l" | "--language" => options.language = value()?,
+                "-f" | "--format" => options.format = value()?.parse()?,
+                "--level" => options.level = value()?.parse()?,
+                "--markup" => options.markup = parse_markup(&value()?)?,
+                "--disable" => options.disabled.extend(split_list(&value()?)),
+                "--enable" => options.enabled.extend(split_list(&value()?)),
+                "--fail-on" => options.fail_on = parse_fail_on(&value()?)?,
+                "--ext" => options.extensions = split_list(&value()?).collect(),
+                "--fix" => options.fix_mode = FixMode::Write,
+                "--diff" => options.fix_mode = FixMode::Diff,
+                "--fix-rules" => options.fix_rules = parse_fix_rules(&value()?),
+                "--profile" => options.profile = true,
+                "-" => options.inputs.push(arg.clone()),
+                _ if arg.starts_with('-') => return Err(format!("unknown option '{}'", arg)),
+                _ => options.inputs.push(arg.clone()),
+            }
+        }
+
+        if options.inputs.is_empty() {
+            options.inputs.push("-".to_string());
+        }
+
+        Ok(options)
+    }
+}
+
+fn split_list(list: &str) -> impl Iterator<Item = String> + '_ {
+    list.split(',').map(str::trim).filter(|s| !s.is_empty()).map(str::to_string)
+}
+
+fn parse_fail_on(value: &str) -> Result<Option<Severity>, String> {
+    match value.to_lowercase().as_str() {
+        "error" => Ok(Some(Severity::Error)),
+        "warning" => Ok(Some(Severity::Warning)),
+        "hint" => Ok(Some(Severity::Hint)),
+        "never" => Ok(None),
+        other => Err(format!("unknown severity '{}'", other)),
+    }
+}
+
+/// `auto` (from the file extension) or a markup format
+fn parse_markup(value: &str) -> Result<Option<MarkupFormat>, String> {
+    match value {
+        "auto" => Ok(None),
+        format => format.parse().map(Some),
+    }
+}
+
+/// `safe` (built-in allowlist), `all`, or a comma-separated list of rule IDs
+fn parse_fix_rules(value: &str) -> FixOptions {
+    match value {
+        "safe" => FixOptions::safe(),
+        "all" => FixOptions::all(),
+        list => {
+            let rules: Vec<String> = split_list(list).collect();
+            FixOptions::only(rules.iter().map(String::as_str))
+        }
+    }
+}
+
+/// Severity rank, higher is more severe
+fn severity_rank(severity: Severity) -> u8 {
+    match severity {
+        Severity::Error => 2,
+        Severity::Warning => 1,
+        Severity::Hint => 0,
+    }
+}
+
+fn print_help() {
+    println!("grammar-check - grammar linter for files, directories and stdin");
+    println!();
+    println!("Usage: grammar-check [OPTIONS] [PATH|GLOB|-]...");
+    println!();
+    println!("Inputs:");
+    println!("  PATH                  File, or directory checked recursively");
+    println!("  GLOB                  Pattern such as 'docs/**/*.md' (quote it)");
+    println!("  -                     Read stdin (default when no input is given)");
+    println!();
+    println!("Options:");
+    println!("  -l, --language CODE   en-US, en-GB, fr-FR, fr-CA or auto (default: auto)");
+    println!("  -f, --format FORMAT   human, json, sarif, checkstyle or github (default: human)");
+    println!("      --level LEVEL     default or picky (default: default)");
+    println!("      --markup FORMAT   plain, markdown, html, latex or auto (default: auto, from the extension)");
+    println!("      --disable IDS     Comma-separated rule IDs to ignore");
+    println!("      --enable IDS      Comma-separated rule IDs to turn on (default-off rules)");
+    println!("      --fail-on LEVEL   error, warning, hint or never (default: error)");
+    println!("      --ext EXTS        Extensions checked in directories (default: {})", DEFAULT_EXTENSIONS.join(","));
+    println!("      --fix             Apply the first suggestion of each match (stdin: print the fixed text)");
+    println!("      --diff            Print the fixes as a unified diff instead of writing them");
+    println!("      --fix-rules IDS   Rules allowed to fix: safe, all or rule IDs (default: all)");
+    println!("      --profile         Print per-step, per-checker and slowest-rule timings to stderr");
+    println!("  -h, --help            Show this help");
+}
+
+/// Pipelines built on first use, so that checking English files never
+/// loads the French dictionaries (and the other way around)
+#[derive(Default)]
+struct LazyPipelines {
+    en: OnceLock<(Pipeline, Pipeline)>,
+    fr: OnceLock<(Pipeline, Pipeline)>,
+}
+
+impl LazyPipelines {
+    fn get(&self, lang: &str) -> &Pipeline {
+        match pipeline_language(lang) {
+            "en-GB" => &self.en.get_or_init(en_pipelines).1,
+            "fr-CA" => &self.fr.get_or_init(fr_pipelines).1,
+            "fr-FR" => &self.fr.get_or_init(fr_pipelines).0,
+            _ => &self.en.get_or_init(en_pipelines).0,
+        }
+    }
+}
+
+/// A text to check: a file, or stdin
+struct Input {
+    path: String,
+    text: String,
+}
+
+fn read_inputs(options: &Options) -> Result<Vec<Input>, String> {
+    let (stdin, paths): (Vec<&String>, Vec<&String>) =
+        options.inputs.iter().partition(|input| *input == "-");
+
+    let mut inputs = Vec::new();
+    if !stdin.is_empty() {
+        let mut text = String::new();
+        std::io::stdin()
+            .read_to_string(&mut text)
+            .map_err(|e| format!("<stdin>: {}", e))?;
+        inputs.push(Input { path: "<stdin>".to_string(), text });
+    }
+
+    let paths: Vec<String> = paths.into_iter().cloned().collect();
+    for path in collect_files(&paths, &options.extensions)? {
+        inputs.push(Input {
+            text: std::fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?,
+            path: display_path(path),
+        });
+    }
+
+    Ok(inputs)
+}
+
+fn display_path(path: PathBuf) -> String {
+    path.to_string_lossy().into_owned()
+}
+
+/// Report of one input, with the fixed text when a fix mode is on
+struct Checked {
+    report: FileReport,
+    fixed: Option<String>,
+    /// Timings with `--profile`
+    profile: Option<CheckProfile>,
+}
+
+/// Check one input with the options' language, level and rule lists
+fn check_input(
+    input: &Input,
+    options: &Options,
+    pipelines: &LazyPipelines,
+    detector: &LanguageDetector,
+) -> Checked {
+    let language = if options.language == "auto" {
+        match detector.detect(&input.text) {
+            Language::French => "fr-FR".to_string(),
+            _ => "en-US".to_string(),
+        }
+    } else {
+        normalize_language(&options.language)
+    };
+
+    let pipeline = pipelines.get(&language);
+    let registry = pipeline.rule_registry();
+    // Markup is stripped before checking, spans point into the original file
+    let markup = options.markup.unwrap_or_else(|| MarkupFormat::from_path(Path::new(&input.path)));
+    let annotated = markup.annotate(&input.text);
+    let (mut result, profile) = if options.profile {
+        let (result, profile) =
+            pipeline.check_annotated_profiled_with_level(&annotated, options.level, DEFAULT_TOP_RULES);
+        (result, Some(profile))
+    } else {
+        (pipeline.check_annotated_with_level(&annotated, options.level), None)
+    };
+
+    // Default-off rules only report when enabled explicitly, as in the API
+    // A rulegroup ID also selects its sub-rules `GROUP[n]`
+    let listed = |ids: &HashSet<String>, id: &str| ids.contains(id) || ids.contains(split_sub_rule_id(id).0);
+    result.matches.retain(|m| {
+        !listed(&options.disabled, &m.rule_id)
+            && (registry.is_default_on(&m.rule_id) || listed(&options.enabled, &m.rule_id))
+    });
+
+    // Fixed matches are no longer reported
+    let mut fixed = None;
+    if options.fix_mode != FixMode::Report {
+        let fix = fix_annotated(&annotated, &result.matches, &options.fix_rules);
+        result.matches.retain(|m| {
+            !fix.applied.iter().any(|f| f.span == m.span && f.rule_id == m.rule_id)
+        });
+        fixed = Some(fix.text);
+    }
+
+    Checked {
+        report: FileReport {
+            path: input.path.clone(),
+            findings: result
+                .matches
+                .iter()
+                .map(|m| Finding::new(m, &input.text, registry))
+                .collect(),
+            language,
+        },
+        fixed,
+        profile,
+    }
+}
+
+/// Apply the fixes of `matches` (original spans) to the annotated document
+///
+/// A match whose span covers markup is still reported but never fixed:
+/// replacing it would delete the tags.
+fn fix_annotated(annotated: &AnnotatedText, matches: &[Match], options: &FixOptions) -> FixResult {
+    let fixable: Vec<Match> = matches
+        .iter()
+        .filter(|m| !annotated.covers_markup(m.span.clone()))
+        .cloned()
+        .collect();
+    apply_fixes(annotated.original_text(), &fixable, options)
+}
+
+/// Write the fixed files, or print the fixed stdin text / the diff
+fn output_fixes(options: &Options, inputs: &[Input], checked: &[Checked]) -> Result<(), String> {
+    for (input, checked) in inputs.iter().zip(checked) {
+        let Some(fixed) = &checked.fixed else { continue };
+
+        match options.fix_mode {
+            FixMode::Diff => print!("{}", unified_diff(&input.path, &input.text, fixed)),
+            FixMode::Write if input.path == "<stdin>" => print!("{}", fixed),
+            FixMode::Write if *fixed != input.text => {
+                std::fs::write(&input.path, fixed).map_err(|e| format!("{}: {}", input.path, e))?;
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    if args.iter().any(|a| a == "--help" || a == "-h") {
+        print_help();
+        return ExitCode::SUCCESS;
+    }
+
+    let options = match Options::parse(&args) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            eprintln!("Run with --help for usage");
+            return ExitCode::from(2);
+        }
+    };
+
+    let inputs = match read_inputs(&options) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::from(2);
+        }
+    };
+
+    let pipelines = LazyPipelines::default();
+    let detector = LanguageDetector::new();
+    let checked = par_map_bounded(&inputs, |input| {
+        check_input(input, &options, &pipelines, &detector)
+    });
+
+    if let Err(e) = output_fixes(&options, &inputs, &checked) {
+        eprintln!("error: {}", e);
+        return ExitCode::from(2);
+    }
+
+    // Files are checked in parallel: the timings of a file include some contention
+    for c in &checked {
+        if let Some(profile) = &c.profile {
+            eprintln!("{} ({})", c.report.path, c.report.language);
+            eprint!("{}", profile);
+        }
+    }
+
+    let reports: Vec<FileReport> = checked.into_iter().map(|c| c.report).collect();
+    let rendered = render(options.format, &reports);
+
+    // stdout carries the diff or the fixed text: the report goes to stderr
+    let stdout_taken = options.fix_mode == FixMode::Diff
+        || (options.fix_mode == FixMode::Write && inputs.iter().any(|i| i.path == "<stdin>"));
+    if stdout_taken {
+        eprint!("{}", rendered);
+    } else {
+        print!("{}", rendered);
+    }
+
+    let failed = options.fail_on.is_some_and(|threshold| {
+        reports
+            .iter()
+            .flat_map(|report| &report.findings)
+            .any(|f| severity_rank(f.severity) >= severity_rank(threshold))
+    });
+
+    if failed {
+        ExitCode::from(1)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Options, String> {
+        Options::parse(&args.iter().map(|a| a.to_string()).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let options = parse(&[
+            "docs", "-l", "en-GB", "--format", "sarif", "--disable", "A, B", "--fail-on", "never",
+        ])
+        .unwrap();
+        assert_eq!(options.inputs, vec!["docs"]);
+        assert_eq!(options.language, "en-GB");
+        assert_eq!(options.format, Format::Sarif);
+        assert!(options.disabled.contains("A") && options.disabled.contains("B"));
+        assert_eq!(options.fail_on, None);
+    }
+
+    #[test]
+    fn test_parse_defaults_to_stdin() {
+        let options = parse(&[]).unwrap();
+        assert_eq!(options.inputs, vec!["-"]);
+        assert_eq!(options.fail_on, Some(Severity::Error));
+    }
+
+    #[test]
+    fn test_parse_fix_options() {
+        let options = parse(&["docs", "--diff", "--fix-rules", "safe"]).unwrap();
+        assert_eq!(options.fix_mode, FixMode::Diff);
+        assert!(options.fix_rules.allowed_rules.unwrap().contains("DOUBLE_SPACE"));
+
+        let options = parse(&["docs", "--fix", "--fix-rules", "SPELL,EN_A_AN"]).unwrap();
+        assert_eq!(options.fix_mode, FixMode::Write);
+        assert_eq!(options.fix_rules.allowed_rules.unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_parse_profile() {
+        assert!(!parse(&["docs"]).unwrap().profile);
+        assert!(parse(&["docs", "--profile"]).unwrap().profile);
+    }
+
+    fn fix(source: &str, markup: MarkupFormat, matches: &[(&str, &str, &str)]) -> String {
+        let annotated = markup.annotate(source);
+        let plain = annotated.plain_text();
+        let mut matches: Vec<Match> = matches
+            .iter()
+            .map(|(rule, found, suggestion)| {
+                let start = plain.find(found).unwrap();
+                Match {
+                    span: start..start + found.len(),
+                    message: String::new(),
+                    rule_id: rule.to_string(),
+                    suggestions: vec![suggestion.to_string()],
+                    severity: Severity::Error,
+                    issue_type: None,
+                }
+            })
+            .collect();
+        annotated.map_matches(&mut matches);
+        fix_annotated(&annotated, &matches, &FixOptions::all()).text
+    }
+
+    #[test]
+    fn test_fix_keeps_markup() {
+        let html = "<p>I took an <b>air</b> plane to Paris. I has <b>a</b> apple.</p>";
+        assert_eq!(
+            fix(html, MarkupFormat::Html, &[("COMPOUND", "air plane", "airplane"), ("AGREEMENT", "has", "have")]),
+            "<p>I took an <b>air</b> plane to Paris. I have <b>a</b> apple.</p>"
+        );
+
+        let markdown = "An *air* plane and **teh** sky.";
+        assert_eq!(
+            fix(markdown, MarkupFormat::Markdown, &[("COMPOUND", "air plane", "airplane"), ("SPELL", "teh", "the")]),
+            "An *air* plane and **the** sky."
+        );
+    }
+
+    #[test]
+    fn test_parse_markup() {
+        assert_eq!(parse(&["a.tex"]).unwrap().markup, None);
+        assert_eq!(parse(&["-", "--markup", "html"]).unwrap().markup, Some(MarkupFormat::Html));
+        assert!(parse(&["--markup", "rtf"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse(&["--format", "xml"]).is_err());
+        assert!(parse(&["--language"]).is_err());
+        assert!(parse(&["--bogus"]).is_err());
+    }
+}
@@ -0,0 +1,317 @@
+//! Output formats - human, JSON, SARIF, Checkstyle and GitHub annotations
+
+use grammar_rs::core::{Match, Severity};
+use grammar_rs::core::registry::RuleRegistry;
+use serde::Serialize;
+use serde_json::json;
+use std::collections::BTreeMap;
+
+/// Output format selected with `--format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Human,
+    Json,
+    Sarif,
+    Checkstyle,
+    Github,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "human" | "text" => Ok(Format::Human),
+            "json" => Ok(Format::Json),
+            "sarif" => Ok(Format::Sarif),
+            "checkstyle" => Ok(Format::Checkstyle),
+            "github" => Ok(Format::Github),
+            other => Err(format!("unknown format '{}'", other)),
+        }
+    }
+}
+
+/// Matches of one checked file
+#[derive(Debug, Serialize)]
+pub struct FileReport {
+    pub path: String,
+    pub language: String,
+    pub findings: Vec<Finding>,
+}
+
+/// A match with its position as line / column (1-based, in characters)
+#[derive(Debug, Serialize)]
+pub struct Finding {
+    pub line: usize,
+    pub column: usize,
+    #[serde(rename = "endLine")]
+    pub end_line: usize,
+    #[serde(rename = "endColumn")]
+    pub end_column: usize,
+    /// Byte span in the file
+    pub offset: usize,
+    pub length: usize,
+    pub rule: String,
+    pub description: String,
+    #[serde(serialize_with = "serialize_severity")]
+    pub severity: Severity,
+    pub message: String,
+    pub suggestions: Vec<String>,
+}
+
+fn serialize_severity<S: serde::Serializer>(severity: &Severity, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_str(severity_name(*severity))
+}
+
+pub fn severity_name(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Hint => "hint",
+    }
+}
+
+impl Finding {
+    /// Locate a match in `text`
+    pub fn new(m: &Match, text: &str, registry: &RuleRegistry) -> Self {
+        let (line, column) = line_column(text, m.span.start);
+        let (end_line, end_column) = line_column(text, m.span.end);
+        Self {
+            line,
+            column,
+            end_line,
+            end_column,
+            offset: m.span.start,
+            length: m.span.len(),
+            rule: m.rule_id.clone(),
+            description: registry
+                .get(&m.rule_id)
+                .map_or_else(|| m.rule_id.clone(), |rule| rule.description.clone()),
+            severity: m.severity,
+            message: m.message.clone(),
+            suggestions: m.suggestions.clone(),
+        }
+    }
+}
+
+/// 1-based line and column (in characters) of a byte offset
+fn line_column(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset.min(text.len())];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let line = before.matches('\n').count() + 1;
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+/// Render every report in the requested format
+pub fn render(format: Format, reports: &[FileReport]) -> String {
+    match format {
+        Format::Human => render_human(reports),
+        Format::Json => serde_json::to_string_pretty(reports).unwrap(),
+        Format::Sarif => render_sarif(reports),
+        Format::Checkstyle => render_checkstyle(reports),
+        Format::Github => render_github(reports),
+    }
+}
+
+fn render_human(reports: &[FileReport]) -> String {
+    let mut out = String::new();
+    let mut counts = [0usize; 3];
+
+    for report in reports {
+        for f in &report.findings {
+            counts[f.severity as usize] += 1;
+            out.push_str(&format!(
+                "{}:{}:{}: {} [{}] {}\n",
+                report.path, f.line, f.column, severity_name(f.severity), f.rule, f.message
+            ));
+            if !f.suggestions.is_empty() {
+                out.push_str(&format!("    suggestions: {}\n", f.suggestions.join(", ")));
+            }
+        }
+    }
+
+    let total: usize = counts.iter().sum();
+    if total == 0 {
+        out.push_str(&format!("✓ No problems found in {} file(s)\n", reports.len()));
+    } else {
+        out.push_str(&format!(
+            "\n{} problem(s) ({} error(s), {} warning(s), {} hint(s)) in {} file(s)\n",
+            total, counts[0], counts[1], counts[2], reports.len()
+        ));
+    }
+    out
+}
+
+/// SARIF 2.1.0, as consumed by GitHub code scanning
+fn render_sarif(reports: &[FileReport]) -> String {
+    let mut rules = BTreeMap::new();
+    let mut results = Vec::new();
+
+    for report in reports {
+        for f in &report.findings {
+            rules
+                .entry(f.rule.clone())
+                .or_insert_with(|| json!({ "id": f.rule, "shortDescription": { "text": f.description } }));
+            results.push(json!({
+                "ruleId": f.rule,
+                "level": match f.severity {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                    Severity::Hint => "note",
+                },
+                "message": { "text": f.message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": report.path },
+                        "region": {
+                            "startLine": f.line,
+                            "startColumn": f.column,
+                            "endLine": f.end_line,
+                            "endColumn": f.end_column,
+                        },
+                    },
+                }],
+            }));
+        }
+    }
+
+    let sarif = json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "grammar-rs",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules.into_values().collect::<Vec<_>>(),
+                },
+            },
+            "columnKind": "unicodeCodePoints",
+            "results": results,
+        }],
+    });
+    serde_json::to_string_pretty(&sarif).unwrap()
+}
+
+fn render_checkstyle(reports: &[FileReport]) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<checkstyle version=\"4.3\">\n");
+    for report in reports {
+        out.push_str(&format!("  <file name=\"{}\">\n", xml_escape(&report.path)));
+        for f in &report.findings {
+            let severity = match f.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Hint => "info",
+            };
+            out.push_str(&format!(
+                "    <error line=\"{}\" column=\"{}\" severity=\"{}\" message=\"{}\" source=\"grammar-rs.{}\"/>\n",
+                f.line, f.column, severity, xml_escape(&f.message), xml_escape(&f.rule)
+            ));
+        }
+        out.push_str("  </file>\n");
+    }
+    out.push_str("</checkstyle>\n");
+    out
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+        .replace('\n', "&#10;")
+}
+
+/// GitHub Actions workflow commands (`::error file=...::message`)
+fn render_github(reports: &[FileReport]) -> String {
+    let mut out = String::new();
+    for report in reports {
+        for f in &report.findings {
+            let command = match f.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Hint => "notice",
+            };
+            out.push_str(&format!(
+                "::{} file={},line={},col={},endLine={},endColumn={},title={}::{}\n",
+                command,
+                github_escape_property(&report.path),
+                f.line,
+                f.column,
+                f.end_line,
+                f.end_column,
+                github_escape_property(&f.rule),
+                github_escape_data(&f.message),
+            ));
+        }
+    }
+    out
+}
+
+fn github_escape_data(s: &str) -> String {
+    s.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+fn github_escape_property(s: &str) -> String {
+    github_escape_data(s).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> FileReport {
+        FileReport {
+            path: "docs/a,b.md".to_string(),
+            language: "en-US".to_string(),
+            findings: vec![Finding {
+                line: 2,
+                column: 8,
+                end_line: 2,
+                end_column: 9,
+                offset: 14,
+                length: 1,
+                rule: "EN_A_AN".to_string(),
+                description: "a vs. an".to_string(),
+                severity: Severity::Error,
+                message: "Use \"an\": 100%".to_string(),
+                suggestions: vec!["an".to_string()],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_line_column() {
+        let text = "Title\nI have a apple.";
+        assert_eq!(line_column(text, 0), (1, 1));
+        assert_eq!(line_column(text, 13), (2, 8));
+        assert_eq!(line_column("é\né", 5), (2, 2));
+    }
+
+    #[test]
+    fn test_github_format() {
+        let out = render(Format::Github, &[report()]);
+        assert_eq!(
+            out,
+            "::error file=docs/a%2Cb.md,line=2,col=8,endLine=2,endColumn=9,title=EN_A_AN::Use \"an\": 100%25\n"
+        );
+    }
+
+    #[test]
+    fn test_checkstyle_format() {
+        let out = render(Format::Checkstyle, &[report()]);
+        assert!(out.contains("<file name=\"docs/a,b.md\">"));
+        assert!(out.contains("message=\"Use &quot;an&quot;: 100%\" source=\"grammar-rs.EN_A_AN\""));
+    }
+
+    #[test]
+    fn test_sarif_format() {
+        let sarif: serde_json::Value = serde_json::from_str(&render(Format::Sarif, &[report()])).unwrap();
+        let run = &sarif["runs"][0];
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "EN_A_AN");
+        assert_eq!(run["results"][0]["level"], "error");
+        assert_eq!(run["results"][0]["locations"][0]["physicalLocation"]["region"]["startColumn"], 8);
+    }
+}
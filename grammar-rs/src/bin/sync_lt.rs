@@ -32,6 +32,8 @@ struct PatternToken {
     negation: bool,
     min: u32,
     max: u32,
+    /// `skip="N"`: up to N tokens (-1: any number) between this token and the next
+    skip: Option<i32>,
 }
 
 impl Default for PatternToken {
@@ -46,6 +48,7 @@ impl Default for PatternToken {
             negation: false,
             min: 1,
             max: 1,
+            skip: None,
         }
     }
 }
@@ -295,6 +298,18 @@ struct ComplexRule {
     unification_groups: Vec<UnificationGroup>,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     examples: Vec<ComplexExample>,
+    /// Rule tags from `tags="..."` on the rule or its rulegroup (e.g. "picky")
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    tags: Vec<String>,
+    /// Issue type from `type="..."` on the rule, its rulegroup or its category
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none", default)]
+    issue_type: Option<String>,
+    /// `default="off"` (or "temp_off") on the rule, its rulegroup or its category
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    default_off: bool,
+    /// `<url>` of the rule or its rulegroup
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    url: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -334,6 +349,19 @@ struct DisambigRule {
     action: DisambigAction,
     wd: Option<DisambigWd>,
     postag: Option<String>,
+    /// A token has `<exception>`s, which the runtime matcher cannot express
+    has_exceptions: bool,
+}
+
+/// Multi-token disambiguation rule (for JSON serialization)
+#[derive(Debug, Clone, serde::Serialize)]
+struct DisambiguationRuleJson {
+    id: String,
+    pattern: Vec<ComplexPatternToken>,
+    marker: Vec<usize>,
+    action: &'static str,
+    postag: Option<String>,
+    lemma: Option<String>,
 }
 
 #[derive(Debug, Default)]
@@ -379,6 +407,7 @@ struct SyncStats {
     disambig_skip: usize,
     disambig_skip_regex: usize,
     disambig_pos: usize,
+    disambig_rules: usize,
     // Phase 7: N-gram confusion words
     ngram_confusion_words: usize,
     // Phase 8: Complex patterns
@@ -478,6 +507,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         total_stats.disambig_skip += stats.disambig_skip;
         total_stats.disambig_skip_regex += stats.disambig_skip_regex;
         total_stats.disambig_pos += stats.disambig_pos;
+        total_stats.disambig_rules += stats.disambig_rules;
         // Phase 7: N-gram
         total_stats.ngram_confusion_words += stats.ngram_confusion_words;
         // Phase 8
@@ -552,10 +582,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     );
     // Phase 6: Disambiguation stats
     println!(
-        "  Disambiguation skip: {} (+{} regex) | POS rules: {}",
+        "  Disambiguation skip: {} (+{} regex) | POS rules: {} | Contextual rules: {}",
         total_stats.disambig_skip,
         total_stats.disambig_skip_regex,
-        total_stats.disambig_pos
+        total_stats.disambig_pos,
+        total_stats.disambig_rules
     );
     // Phase 7: N-gram stats
     println!(
@@ -1262,10 +1293,17 @@ fn sync_language(lt_path: &Path, lang: &str) -> Result<SyncStats, Box<dyn std::e
 
     // 27. Sync disambiguation.xml -> ignore_spelling patterns + POS rules
     let disambig_path = resource_path.join("disambiguation.xml");
+    let custom_disambig_path = output_dir.join(format!("{}_custom_disambiguation.xml", lang));
     if disambig_path.exists() {
-        let rules = parse_disambiguation_xml(&disambig_path)?;
+        let mut rules = parse_disambiguation_xml(&disambig_path)?;
         println!("   disambiguation.xml: {} rules parsed", rules.len());
 
+        if custom_disambig_path.exists() {
+            let custom_rules = parse_disambiguation_xml(&custom_disambig_path)?;
+            println!("      custom: {} rules", custom_rules.len());
+            rules.extend(custom_rules);
+        }
+
         // Extract ignore_spelling patterns (single-token)
         let (skip_words, skip_regex) = extract_ignore_spelling_patterns(&rules);
         stats.disambig_skip = skip_words.len();
@@ -1288,6 +1326,15 @@ fn sync_language(lt_path: &Path, lang: &str) -> Result<SyncStats, Box<dyn std::e
             let output_path = output_dir.join(format!("{}_disambig_pos.rs", lang));
             fs::write(&output_path, code)?;
         }
+
+        // Full rules with context for the runtime disambiguator
+        let contextual_rules = extract_disambiguation_rules(&rules);
+        stats.disambig_rules = contextual_rules.len();
+        println!("      contextual rules: {}", stats.disambig_rules);
+
+        let json = serde_json::to_string_pretty(&contextual_rules)?;
+        let output_path = output_dir.join(format!("{}_disambiguation.json", lang));
+        fs::write(&output_path, json)?;
     }
 
     // ═══════════════════════════════════════════════════════════════════════════════
@@ -5629,10 +5676,15 @@ fn parse_disambiguation_xml(path: &Path) -> Result<Vec<DisambigRule>, Box<dyn st
     let mut buf = Vec::new();
 
     let mut current_rule_id = String::new();
+    // Id of the enclosing <rulegroup>, for its rules without an id
+    let mut current_group_id = String::new();
+    let mut group_rule_count = 0;
     let mut in_rule = false;
     let mut in_pattern = false;
     let mut in_marker = false;
     let mut in_token = false;
+    let mut in_exception = false;
+    let mut has_exceptions = false;
     let mut in_disambig = false;
     let mut current_pattern: Vec<PatternToken> = Vec::new();
     let mut marker_indices: Vec<usize> = Vec::new();
@@ -5648,8 +5700,17 @@ fn parse_disambiguation_xml(path: &Path) -> Result<Vec<DisambigRule>, Box<dyn st
                 let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
 
                 match name.as_str() {
+                    "rulegroup" => {
+                        current_group_id = get_attr(e, "id").unwrap_or_default();
+                        group_rule_count = 0;
+                    }
                     "rule" => {
-                        let id = get_attr(e, "id").unwrap_or_default();
+                        let mut id = get_attr(e, "id").unwrap_or_default();
+                        if id.is_empty() && !current_group_id.is_empty() {
+                            // Sub-rules of a group are numbered like LanguageTool's subId
+                            group_rule_count += 1;
+                            id = format!("{}[{}]", current_group_id, group_rule_count);
+                        }
                         if !id.is_empty() {
                             current_rule_id = id;
                             in_rule = true;
@@ -5658,6 +5719,7 @@ fn parse_disambiguation_xml(path: &Path) -> Result<Vec<DisambigRule>, Box<dyn st
                             current_action = None;
                             current_wd = None;
                             current_postag = None;
+                            has_exceptions = false;
                         }
                     }
                     "pattern" if in_rule => {
@@ -5683,6 +5745,7 @@ fn parse_disambiguation_xml(path: &Path) -> Result<Vec<DisambigRule>, Box<dyn st
                         token.postag_regexp = get_attr(e, "postag_regexp")
                             .map(|v| v == "yes")
                             .unwrap_or(false);
+                        token.skip = get_attr(e, "skip").and_then(|v| v.parse().ok());
                         if get_attr(e, "regexp").map(|v| v == "yes").unwrap_or(false) {
                             // Regexp is stored in token text, marked as regexp
                             token.regexp = Some("yes".to_string());
@@ -5691,6 +5754,11 @@ fn parse_disambiguation_xml(path: &Path) -> Result<Vec<DisambigRule>, Box<dyn st
                         in_token = true;
                         text_buffer.clear();
                     }
+                    "exception" if in_token => {
+                        // Its text belongs to the exception, not to the token
+                        in_exception = true;
+                        has_exceptions = true;
+                    }
                     "disambig" if in_rule => {
                         in_disambig = true;
                         let action_str = get_attr(e, "action").unwrap_or_default();
@@ -5731,11 +5799,18 @@ fn parse_disambiguation_xml(path: &Path) -> Result<Vec<DisambigRule>, Box<dyn st
                                 action,
                                 wd: current_wd.take(),
                                 postag: current_postag.take(),
+                                has_exceptions,
                             });
                         }
                         in_rule = false;
                         current_rule_id.clear();
                     }
+                    "rulegroup" => {
+                        current_group_id.clear();
+                    }
+                    "exception" if in_exception => {
+                        in_exception = false;
+                    }
                     "pattern" if in_pattern => {
                         in_pattern = false;
                     }
@@ -5769,7 +5844,7 @@ fn parse_disambiguation_xml(path: &Path) -> Result<Vec<DisambigRule>, Box<dyn st
             }
             Ok(Event::Text(e)) => {
                 let text = e.unescape().unwrap_or_default().to_string();
-                if in_token && in_pattern {
+                if in_token && in_pattern && !in_exception {
                     text_buffer.push_str(&text);
                 }
             }
@@ -5791,6 +5866,7 @@ fn parse_disambiguation_xml(path: &Path) -> Result<Vec<DisambigRule>, Box<dyn st
                         token.postag_regexp = get_attr(e, "postag_regexp")
                             .map(|v| v == "yes")
                             .unwrap_or(false);
+                        token.skip = get_attr(e, "skip").and_then(|v| v.parse().ok());
                         if get_attr(e, "regexp").map(|v| v == "yes").unwrap_or(false) {
                             token.regexp = Some("yes".to_string());
                         }
@@ -5821,6 +5897,9 @@ fn parse_disambiguation_xml(path: &Path) -> Result<Vec<DisambigRule>, Box<dyn st
                             pos: get_attr(e, "pos"),
                         });
                     }
+                    "exception" if in_token => {
+                        has_exceptions = true;
+                    }
                     _ => {}
                 }
             }
@@ -5971,6 +6050,53 @@ fn extract_single_token_pos_rules(rules: &[DisambigRule]) -> Vec<SingleTokenPosR
     pos_rules
 }
 
+/// Convert rules for the runtime disambiguator (`src/analyzer/disambiguation.rs`)
+///
+/// `ignore_spelling` is covered by the skip lists and `unify` is not supported.
+/// Rules with `<exception>`s are dropped: without them, a `replace`, `remove`
+/// or `filter` would also rewrite the readings of the excluded words.
+fn extract_disambiguation_rules(rules: &[DisambigRule]) -> Vec<DisambiguationRuleJson> {
+    rules
+        .iter()
+        .filter(|rule| !rule.pattern.is_empty() && !rule.has_exceptions)
+        .filter_map(|rule| {
+            let action = match rule.action {
+                DisambigAction::Replace => "replace",
+                DisambigAction::Add => "add",
+                DisambigAction::Remove => "remove",
+                DisambigAction::Filter => "filter",
+                DisambigAction::FilterAll => "filterall",
+                DisambigAction::Immunize => "immunize",
+                DisambigAction::IgnoreSpelling | DisambigAction::Unify => return None,
+            };
+            let wd = rule.wd.as_ref();
+            Some(DisambiguationRuleJson {
+                id: rule.id.clone(),
+                pattern: rule
+                    .pattern
+                    .iter()
+                    .map(|token| ComplexPatternToken {
+                        text: token.text.clone(),
+                        regexp: token.regexp.clone(),
+                        postag: token.postag.clone(),
+                        postag_regexp: token.postag_regexp,
+                        inflected: token.inflected,
+                        case_sensitive: token.case_sensitive,
+                        negation: token.negation,
+                        min: token.min,
+                        max: token.max,
+                        skip: token.skip,
+                    })
+                    .collect(),
+                marker: rule.marker_indices.clone(),
+                action,
+                postag: rule.postag.clone().or_else(|| wd.and_then(|wd| wd.pos.clone())),
+                lemma: wd.and_then(|wd| wd.lemma.clone()),
+            })
+        })
+        .collect()
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // Phase 6: Generators - disambiguation files
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -6555,6 +6681,20 @@ fn extract_complex_rules(path: &Path) -> Result<Vec<ComplexRule>, Box<dyn std::e
     let mut current_unify_negate = false;
     let mut current_unify_start_idx: usize = 0;
     let mut current_unification_groups: Vec<UnificationGroup> = Vec::new();
+    // ID, name and tags inherited from the enclosing <rulegroup>
+    let mut current_group_id = String::new();
+    let mut current_group_name = String::new();
+    let mut group_rule_count = 0;
+    let mut current_group_tags: Vec<String> = Vec::new();
+    // Issue type and default-off flag inherited from <category> and <rulegroup>
+    let mut current_category_type: Option<String> = None;
+    let mut current_category_off = false;
+    let mut current_group_type: Option<String> = None;
+    let mut current_group_off = false;
+    // `<url>` of the enclosing <rulegroup>, inherited by its rules
+    let mut current_group_url: Option<String> = None;
+    let mut in_url = false;
+    let mut in_rule = false;
 
     loop {
         match reader.read_event_into(&mut buf) {
@@ -6564,25 +6704,56 @@ fn extract_complex_rules(path: &Path) -> Result<Vec<ComplexRule>, Box<dyn std::e
                 match name.as_str() {
                     "category" => {
                         current_category = get_attr(e, "name").unwrap_or_default();
+                        current_category_type = get_attr(e, "type");
+                        current_category_off = is_default_off(get_attr(e, "default").as_deref());
                     }
                     "rulegroup" | "rule" => {
-                        if name == "rule" || (name == "rulegroup" && current_rule.is_none()) {
-                            let id = get_attr(e, "id").unwrap_or_default();
-                            let rule_name = get_attr(e, "name").unwrap_or_default();
-                            if !id.is_empty() {
-                                current_rule = Some(ComplexRule {
-                                    id,
-                                    name: rule_name,
-                                    category: current_category.clone(),
-                                    pattern: Vec::new(),
-                                    antipatterns: Vec::new(),
-                                    message: String::new(),
-                                    suggestions: Vec::new(),
-                                    dynamic_suggestions: Vec::new(),
-                                    unification_groups: Vec::new(),
-                                    examples: Vec::new(),
-                                });
+                        let own_tags = parse_rule_tags(get_attr(e, "tags").as_deref());
+                        let own_type = get_attr(e, "type");
+                        let own_off = is_default_off(get_attr(e, "default").as_deref());
+                        if name == "rulegroup" {
+                            current_group_id = get_attr(e, "id").unwrap_or_default();
+                            current_group_name = get_attr(e, "name").unwrap_or_default();
+                            group_rule_count = 0;
+                            current_group_url = None;
+                            current_group_tags = own_tags;
+                            current_group_type = own_type.or_else(|| current_category_type.clone());
+                            current_group_off = own_off || current_category_off;
+                        } else {
+                            in_rule = true;
+                            // Each sub-rule of a group is exported as `GROUP[n]` (LanguageTool's
+                            // subId) with its own level: group values flow down, never back up
+                            let mut id = get_attr(e, "id").unwrap_or_default();
+                            if id.is_empty() && !current_group_id.is_empty() {
+                                group_rule_count += 1;
+                                id = format!("{}[{}]", current_group_id, group_rule_count);
                             }
+                            let rule_name = get_attr(e, "name").unwrap_or_else(|| current_group_name.clone());
+                            let mut tags = current_group_tags.clone();
+                            for tag in own_tags {
+                                if !tags.contains(&tag) {
+                                    tags.push(tag);
+                                }
+                            }
+                            // Rulegroup values already include the category's
+                            let inherited_type = current_group_type.clone().or_else(|| current_category_type.clone());
+                            let inherited_off = current_group_off || current_category_off;
+                            current_rule = (!id.is_empty()).then(|| ComplexRule {
+                                id,
+                                name: rule_name,
+                                category: current_category.clone(),
+                                pattern: Vec::new(),
+                                antipatterns: Vec::new(),
+                                message: String::new(),
+                                suggestions: Vec::new(),
+                                dynamic_suggestions: Vec::new(),
+                                unification_groups: Vec::new(),
+                                examples: Vec::new(),
+                                tags,
+                                issue_type: own_type.or(inherited_type),
+                                default_off: own_off || inherited_off,
+                                url: current_group_url.clone(),
+                            });
                         }
                         current_pattern.clear();
                         current_antipatterns.clear();
@@ -6630,6 +6801,10 @@ fn extract_complex_rules(path: &Path) -> Result<Vec<ComplexRule>, Box<dyn std::e
                         in_message = true;
                         text_buffer.clear();
                     }
+                    "url" => {
+                        in_url = true;
+                        text_buffer.clear();
+                    }
                     "suggestion" => {
                         in_suggestion = true;
                         text_buffer.clear();
@@ -6666,8 +6841,14 @@ fn extract_complex_rules(path: &Path) -> Result<Vec<ComplexRule>, Box<dyn std::e
                                 rules.push(rule);
                             }
                         }
+                        in_rule = false;
                         if name == "rulegroup" {
-                            current_rule = None;
+                            current_group_id.clear();
+                            current_group_name.clear();
+                            current_group_url = None;
+                            current_group_tags.clear();
+                            current_group_type = None;
+                            current_group_off = false;
                         }
                     }
                     "antipattern" => {
@@ -6718,6 +6899,21 @@ fn extract_complex_rules(path: &Path) -> Result<Vec<ComplexRule>, Box<dyn std::e
                         }
                         in_message = false;
                     }
+                    "url" => {
+                        let url = text_buffer.trim().to_string();
+                        if !url.is_empty() {
+                            if !in_rule {
+                                // <rulegroup><url>: shared by the rules of the group
+                                current_group_url = Some(url.clone());
+                            }
+                            if let Some(ref mut rule) = current_rule {
+                                if in_rule || rule.url.is_none() {
+                                    rule.url = Some(url);
+                                }
+                            }
+                        }
+                        in_url = false;
+                    }
                     "suggestion" => {
                         if let Some(ref mut rule) = current_rule {
                             let trimmed = text_buffer.trim().to_string();
@@ -6772,7 +6968,7 @@ fn extract_complex_rules(path: &Path) -> Result<Vec<ComplexRule>, Box<dyn std::e
             }
             Ok(Event::Text(e)) => {
                 let text = e.unescape().unwrap_or_default().to_string();
-                if in_token || in_message || in_suggestion || in_example {
+                if in_token || in_message || in_suggestion || in_example || in_url {
                     text_buffer.push_str(&text);
                 }
             }
@@ -6850,6 +7046,17 @@ fn extract_complex_rules(path: &Path) -> Result<Vec<ComplexRule>, Box<dyn std::e
     Ok(rules)
 }
 
+/// Parse a LanguageTool `tags` attribute (space-separated, e.g. "picky")
+fn parse_rule_tags(tags: Option<&str>) -> Vec<String> {
+    tags.map(|t| t.split_whitespace().map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+/// LanguageTool `default` attribute: "off" and "temp_off" disable the rule
+fn is_default_off(default: Option<&str>) -> bool {
+    matches!(default, Some("off") | Some("temp_off"))
+}
+
 /// Parse suggestion text and convert \N references to MatchRef parts
 /// Returns a vector of SuggestionParts (Literal and MatchRef)
 fn parse_suggestion_text(text: &str) -> Vec<SuggestionPart> {
@@ -6963,3 +7170,78 @@ fn is_complex_rule(rule: &ComplexRule) -> bool {
 
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disambiguation_exceptions_skip_and_subrules() {
+        let xml = r#"<rules>
+            <rulegroup id="SAW">
+                <rule>
+                    <pattern>
+                        <token skip="2">I</token>
+                        <marker><token>saw<exception>sawn</exception></token></marker>
+                    </pattern>
+                    <disambig action="replace"><wd lemma="see" pos="VBD"/></disambig>
+                </rule>
+                <rule>
+                    <pattern>
+                        <token skip="-1">the</token>
+                        <marker><token>saw</token></marker>
+                    </pattern>
+                    <disambig action="replace"><wd lemma="saw" pos="NN"/></disambig>
+                </rule>
+            </rulegroup>
+        </rules>"#;
+        let path = std::env::temp_dir().join(format!("sync-lt-disambig-{}.xml", std::process::id()));
+        fs::write(&path, xml).unwrap();
+        let rules = parse_disambiguation_xml(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].id, "SAW[1]");
+        assert_eq!(rules[0].pattern[1].text.as_deref(), Some("saw"));
+        assert!(rules[0].has_exceptions);
+        assert_eq!(rules[0].pattern[0].skip, Some(2));
+
+        // Only the rule without exceptions reaches the runtime disambiguator
+        let json = extract_disambiguation_rules(&rules);
+        assert_eq!(json.len(), 1);
+        assert_eq!(json[0].id, "SAW[2]");
+        assert_eq!(json[0].pattern[0].skip, Some(-1));
+    }
+
+    #[test]
+    fn test_complex_subrules_get_their_own_ids() {
+        let xml = r#"<rules>
+            <category name="Grammar">
+                <rulegroup id="GROUP" name="Group" type="grammar">
+                    <rule default="off" tags="picky">
+                        <pattern><token regexp="yes">a|an</token><token>foo</token></pattern>
+                        <message>Off</message>
+                    </rule>
+                    <rule>
+                        <pattern><token regexp="yes">the|a</token><token>bar</token></pattern>
+                        <message>Default</message>
+                    </rule>
+                </rulegroup>
+            </category>
+        </rules>"#;
+        let path = std::env::temp_dir().join(format!("sync-lt-complex-{}.xml", std::process::id()));
+        fs::write(&path, xml).unwrap();
+        let rules = extract_complex_rules(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        // Sub-rules are numbered like LanguageTool's subId; the group values flow down
+        let ids: Vec<&str> = rules.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["GROUP[1]", "GROUP[2]"]);
+        assert!(rules.iter().all(|r| r.name == "Group"));
+        assert!(rules[0].default_off);
+        assert_eq!(rules[0].tags, vec!["picky"]);
+        assert!(!rules[1].default_off);
+        assert!(rules[1].tags.is_empty());
+        assert_eq!(rules[1].issue_type.as_deref(), Some("grammar"));
+    }
+}
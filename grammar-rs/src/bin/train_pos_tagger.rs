@@ -0,0 +1,86 @@
+//! Train the averaged-perceptron POS tagger on a tagged corpus
+//!
+//! Usage: cargo run --release --bin train-pos-tagger -- <train.txt> <output.bin> [--iterations N] [--heldout test.txt]
+//!
+//! The corpus is CoNLL (one `word<TAB>tag` per line, blank line between
+//! sentences; CoNLL-U is read from FORM/XPOS) or Penn-style (`word/TAG`,
+//! one sentence per line). Put the model at `data/models/en_pos.bin` to
+//! use it in the English pipeline.
+
+use grammar_rs::analyzer::{parse_tagged_corpus, PerceptronTagger, TaggedSentence};
+use std::env;
+use std::path::Path;
+use std::time::Instant;
+
+const DEFAULT_ITERATIONS: usize = 5;
+
+fn usage(program: &str) -> ! {
+    eprintln!("Usage: {} <train.txt> <output.bin> [--iterations N] [--heldout test.txt]", program);
+    eprintln!("  train.txt    - Tagged corpus (CoNLL or word/TAG per line)");
+    eprintln!("  output.bin   - Output model file");
+    eprintln!("  --iterations - Training passes over the corpus (default {})", DEFAULT_ITERATIONS);
+    eprintln!("  --heldout    - Tagged corpus to report the accuracy on");
+    std::process::exit(1);
+}
+
+fn load_corpus(path: &str) -> Vec<TaggedSentence> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => parse_tagged_corpus(&text),
+        Err(e) => {
+            eprintln!("Error reading {}: {}", path, e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let mut positional = Vec::new();
+    let mut iterations = DEFAULT_ITERATIONS;
+    let mut heldout_path = None;
+
+    let mut rest = args.iter().skip(1);
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--iterations" => {
+                iterations = match rest.next().and_then(|n| n.parse().ok()) {
+                    Some(n) => n,
+                    None => usage(&args[0]),
+                };
+            }
+            "--heldout" => heldout_path = Some(rest.next().unwrap_or_else(|| usage(&args[0]))),
+            _ => positional.push(arg),
+        }
+    }
+    let [train_path, output_path] = positional[..] else {
+        usage(&args[0]);
+    };
+
+    let train = load_corpus(train_path);
+    let words: usize = train.iter().map(Vec::len).sum();
+    println!("Loaded {} sentences ({} words) from: {}", train.len(), words, train_path);
+
+    let start = Instant::now();
+    let tagger = PerceptronTagger::train(&train, iterations);
+    println!(
+        "Trained {} iterations in {:.2?} ({} tags, {} features)",
+        iterations,
+        start.elapsed(),
+        tagger.classes().len(),
+        tagger.feature_count()
+    );
+    println!("Training accuracy: {:.2}%", tagger.accuracy(&train) * 100.0);
+
+    if let Some(path) = heldout_path {
+        let heldout = load_corpus(path);
+        let words: usize = heldout.iter().map(Vec::len).sum();
+        println!("Held-out accuracy: {:.2}% ({} words)", tagger.accuracy(&heldout) * 100.0, words);
+    }
+
+    if let Err(e) = tagger.to_file(Path::new(output_path)) {
+        eprintln!("Error saving model: {}", e);
+        std::process::exit(1);
+    }
+    let size = std::fs::metadata(output_path).map(|m| m.len()).unwrap_or(0);
+    println!("Saved model to: {} ({} KB)", output_path, size / 1024);
+}
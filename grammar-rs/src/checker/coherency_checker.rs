@@ -6,9 +6,10 @@
 
 use std::collections::HashMap;
 
-use crate::checker::data::en_coherency::{get_en_coherency_pair, get_en_coherency_variants};
+use crate::checker::data::en_coherency::{get_en_coherency_pair, get_en_coherency_variants, EN_COHERENCY_PAIRS};
+use crate::core::registry::{RuleCategory, RuleMetadata};
 use crate::core::traits::Checker;
-use crate::core::{AnalyzedToken, CheckResult, Match, Severity, TokenKind};
+use crate::core::{AnalyzedToken, CheckResult, IssueType, Match, Severity, TokenKind};
 
 /// Coherency checker that detects inconsistent spelling variants.
 ///
@@ -66,6 +67,7 @@ impl CoherencyChecker {
                 ),
                 suggestions: vec![suggestion],
                 severity: Severity::Hint,
+                issue_type: Some(IssueType::Inconsistency),
             });
         }
 
@@ -119,6 +121,7 @@ impl Checker for CoherencyChecker {
                         ),
                         suggestions: vec![first_variant.clone()],
                         severity: Severity::Hint,
+                        issue_type: Some(IssueType::Inconsistency),
                     });
                 } else {
                     // First time seeing this pair - record the variant used
@@ -129,6 +132,23 @@ impl Checker for CoherencyChecker {
 
         CheckResult { matches }
     }
+
+    fn is_document_level(&self) -> bool {
+        true
+    }
+
+    fn rules(&self) -> Vec<RuleMetadata> {
+        EN_COHERENCY_PAIRS
+            .iter()
+            .map(|pair| {
+                RuleMetadata::new(
+                    format!("COHERENCY_{}", pair.id),
+                    format!("Inconsistent spelling: {}", pair.variants.join(" / ")),
+                    RuleCategory::Consistency,
+                )
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
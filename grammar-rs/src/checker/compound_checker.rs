@@ -6,6 +6,7 @@
 //! - Hyphenated words that should be joined: "air-plane" → "airplane"
 
 use crate::core::{AnalyzedToken, CheckResult, Match, Severity, TokenKind};
+use crate::core::registry::{RuleCategory, RuleMetadata};
 use crate::core::traits::Checker;
 use crate::checker::data::en_compounds::{EN_COMPOUND_RULES, CompoundRule as EnCompoundRule, get_en_compound};
 use crate::checker::data::fr_compounds::{FR_COMPOUND_RULES, CompoundRule as FrCompoundRule, get_fr_compound};
@@ -172,6 +173,7 @@ impl Checker for CompoundWordChecker {
                             rule_id: "COMPOUND_SPACE".to_string(),
                             suggestions: vec![suggestion],
                             severity: Severity::Hint,
+                            issue_type: None,
                         });
                         skip_next = true;
                     }
@@ -181,6 +183,10 @@ impl Checker for CompoundWordChecker {
 
         result
     }
+
+    fn rules(&self) -> Vec<RuleMetadata> {
+        vec![RuleMetadata::new("COMPOUND_SPACE", "Compound word written with a space", RuleCategory::Typos)]
+    }
 }
 
 #[cfg(test)]
@@ -4,6 +4,7 @@
 //! These rules detect homophones and other commonly confused word pairs
 //! like "affect/effect", "their/there/they're", etc.
 
+use crate::core::registry::RuleCategory;
 use crate::core::{AnalyzedToken, Match, Severity, TokenKind};
 use super::rules::Rule;
 use super::data::{get_en_confusions, get_fr_confusions};
@@ -552,6 +553,14 @@ impl Rule for EnglishConfusionRule {
         "EN_CONFUSION"
     }
 
+    fn description(&self) -> &str {
+        "Commonly confused words"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::ConfusedWords
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         for (idx, token) in tokens.iter().enumerate() {
             if token.token.kind != TokenKind::Word {
@@ -573,6 +582,7 @@ impl Rule for EnglishConfusionRule {
                             rule_id: self.id().to_string(),
                             suggestions: vec![suggested.to_string()],
                             severity: Severity::Warning,
+                            issue_type: None,
                         });
                     }
                 }
@@ -1349,6 +1359,14 @@ impl Rule for FrenchConfusionRule {
         "FR_CONFUSION"
     }
 
+    fn description(&self) -> &str {
+        "Mots souvent confondus"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::ConfusedWords
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         for (idx, token) in tokens.iter().enumerate() {
             if token.token.kind != TokenKind::Word {
@@ -1369,6 +1387,7 @@ impl Rule for FrenchConfusionRule {
                             rule_id: self.id().to_string(),
                             suggestions: vec![suggested.to_string()],
                             severity: Severity::Warning,
+                            issue_type: None,
                         });
                     }
                 }
@@ -5,6 +5,7 @@
 //! words like "medication" (prescribe) vs "theft" (proscribe).
 
 use crate::checker::data::en_context_words::EN_CONTEXT_RULES;
+use crate::core::registry::{RuleCategory, RuleMetadata};
 use crate::core::traits::Checker;
 use crate::core::{AnalyzedToken, CheckResult, Match, Severity, TokenKind};
 use regex::Regex;
@@ -201,6 +202,7 @@ impl Checker for ContextChecker {
                             ),
                             suggestions: vec![suggestion],
                             severity: Severity::Warning,
+                            issue_type: None,
                         });
                     }
                     // If we used word2 but context suggests word1
@@ -217,6 +219,7 @@ impl Checker for ContextChecker {
                             ),
                             suggestions: vec![suggestion],
                             severity: Severity::Warning,
+                            issue_type: None,
                         });
                     }
                 }
@@ -225,6 +228,10 @@ impl Checker for ContextChecker {
 
         CheckResult { matches }
     }
+
+    fn rules(&self) -> Vec<RuleMetadata> {
+        vec![RuleMetadata::new("CONTEXT_WORD", "Word that does not fit its context", RuleCategory::ConfusedWords)]
+    }
 }
 
 #[cfg(test)]
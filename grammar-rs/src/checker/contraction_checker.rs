@@ -4,6 +4,7 @@
 //! and suggests the proper contracted form, e.g., "dont" -> "don't".
 
 use crate::checker::data::en_contractions::{get_en_contraction, EN_CONTRACTION_RULES};
+use crate::core::registry::{RuleCategory, RuleMetadata};
 use crate::core::traits::Checker;
 use crate::core::{AnalyzedToken, CheckResult, Match, Severity, TokenKind};
 
@@ -71,12 +72,17 @@ impl Checker for ContractionChecker {
                     message: format!("Did you mean '{}'? This word is usually written with an apostrophe.", primary),
                     suggestions,
                     severity: Severity::Warning,
+                    issue_type: None,
                 });
             }
         }
 
         CheckResult { matches }
     }
+
+    fn rules(&self) -> Vec<RuleMetadata> {
+        vec![RuleMetadata::new("CONTRACTION", "Contraction missing an apostrophe", RuleCategory::Typos)]
+    }
 }
 
 #[cfg(test)]
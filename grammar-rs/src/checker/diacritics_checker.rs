@@ -4,6 +4,7 @@
 //! such as "cafe" -> "café" or "naive" -> "naïve".
 
 use crate::checker::data::en_diacritics::{get_en_diacritics, EN_DIACRITICS_RULES};
+use crate::core::registry::{RuleCategory, RuleMetadata};
 use crate::core::traits::Checker;
 use crate::core::{AnalyzedToken, CheckResult, Match, Severity, TokenKind};
 
@@ -85,12 +86,17 @@ impl Checker for DiacriticsChecker {
                     ),
                     suggestions: vec![suggestion],
                     severity: Severity::Hint,
+                    issue_type: None,
                 });
             }
         }
 
         CheckResult { matches }
     }
+
+    fn rules(&self) -> Vec<RuleMetadata> {
+        vec![RuleMetadata::new("DIACRITICS", "Missing diacritics in loanwords", RuleCategory::Typography)]
+    }
 }
 
 #[cfg(test)]
@@ -8,12 +8,17 @@
 //! - Skip gaps (match with N tokens between)
 //! - Antipatterns (exceptions to rules)
 
+use super::variant_checker::preserve_case;
+use crate::core::profile::RuleTiming;
+use crate::core::registry::{RuleCategory, RuleExample, RuleMetadata};
 use crate::core::traits::Checker;
-use crate::core::{AnalyzedToken, CheckResult, Match, Severity, TokenKind};
-use crate::morphology::{FrenchMorphology, transform_pos};
+use crate::core::{AnalyzedToken, CheckLevel, CheckResult, IssueType, Match, Severity, TokenKind};
+use crate::lang_detect::Language;
+use crate::morphology::{EnglishMorphology, FrenchMorphology, transform_pos};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::sync::OnceLock;
+use std::time::Instant;
 
 // ═══════════════════════════════════════════════════════════════════════════════
 // JSON Structures (matching sync-lt output)
@@ -107,6 +112,34 @@ pub struct ComplexRule {
     pub unification_groups: Vec<UnificationGroup>,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub examples: Vec<ComplexExample>,
+    /// LanguageTool rule tags (e.g. "picky")
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub tags: Vec<String>,
+    /// LanguageTool issue type (`type="misspelling"`, "grammar", "style", ...)
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none", default)]
+    pub issue_type: Option<String>,
+    /// Rule is disabled unless explicitly enabled (`default="off"`)
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    pub default_off: bool,
+    /// Page explaining the rule (`<url>` of the rule or its rulegroup)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub url: Option<String>,
+}
+
+impl ComplexRule {
+    /// Strictness tier derived from the LanguageTool `tags` attribute
+    pub fn level(&self) -> CheckLevel {
+        if self.tags.iter().any(|t| t == "picky") {
+            CheckLevel::Picky
+        } else {
+            CheckLevel::Default
+        }
+    }
+
+    /// Parsed issue type (unknown values are ignored)
+    pub fn issue_type(&self) -> Option<IssueType> {
+        self.issue_type.as_deref().and_then(|t| t.parse().ok())
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -114,20 +147,24 @@ pub struct ComplexRule {
 // ═══════════════════════════════════════════════════════════════════════════════
 
 /// Compiled token pattern for efficient matching
-struct CompiledToken {
+pub(crate) struct CompiledToken {
     text: Option<String>,
     text_regex: Option<Regex>,
     postag: Option<String>,
     postag_regex: Option<Regex>,
     case_sensitive: bool,
+    /// `inflected="yes"`: text/regexp match the lemmas instead of the form
+    inflected: bool,
     negation: bool,
     min: u32,
     max: u32,
     skip: Option<i32>,
+    /// `<token postag="SENT_START"/>`: zero-width anchor at sentence start
+    sentence_start: bool,
 }
 
 impl CompiledToken {
-    fn from_json(token: &ComplexPatternToken) -> Option<Self> {
+    pub(crate) fn from_json(token: &ComplexPatternToken) -> Option<Self> {
         // Compile text regex if present
         let text_regex = if let Some(ref regexp) = token.regexp {
             // Make pattern case-insensitive by default unless case_sensitive
@@ -152,47 +189,59 @@ impl CompiledToken {
             None
         };
 
+        let sentence_start = token.postag.as_deref() == Some("SENT_START")
+            && !token.postag_regexp
+            && token.text.is_none()
+            && token.regexp.is_none()
+            && !token.negation;
+
         Some(CompiledToken {
             text: token.text.clone(),
             text_regex,
             postag: if token.postag_regexp { None } else { token.postag.clone() },
             postag_regex,
             case_sensitive: token.case_sensitive,
+            inflected: token.inflected,
             negation: token.negation,
             min: token.min,
             max: token.max,
             skip: token.skip,
+            sentence_start,
         })
     }
 
     /// Check if this compiled token matches the given analyzed token
-    fn matches(&self, token: &AnalyzedToken) -> bool {
+    pub(crate) fn matches(&self, token: &AnalyzedToken) -> bool {
         // Text matching
         let text_match = if let Some(ref text) = self.text {
-            if self.case_sensitive {
-                token.token.text == text
-            } else {
-                token.token.text.eq_ignore_ascii_case(text)
-            }
+            self.text_matches(token, |candidate| {
+                if self.case_sensitive {
+                    candidate == text
+                } else {
+                    candidate.eq_ignore_ascii_case(text)
+                }
+            })
         } else if let Some(ref regex) = self.text_regex {
-            regex.is_match(token.token.text)
+            self.text_matches(token, |candidate| regex.is_match(candidate))
         } else {
             true // No text constraint
         };
 
-        // POS tag matching
+        // POS tag matching (any reading of an ambiguous token)
         let pos_match = if let Some(ref postag) = self.postag {
-            // Exact or prefix match
-            token.pos.as_ref().map_or(false, |pos| {
-                let pos_str = pos.as_str();
-                pos_str == postag
-                    || pos_str.starts_with(postag)
-                    || pos.matches_french_pattern(postag)
-            })
+            match postag.as_str() {
+                "SENT_START" => token.is_sentence_start(),
+                "SENT_END" => token.is_sentence_end(),
+                // Exact or prefix match
+                _ => token.has_pos(|pos| {
+                    let pos_str = pos.as_str();
+                    pos_str == postag
+                        || pos_str.starts_with(postag.as_str())
+                        || pos.matches_french_pattern(postag)
+                }),
+            }
         } else if let Some(ref regex) = self.postag_regex {
-            token.pos.as_ref().map_or(false, |pos| {
-                regex.is_match(pos.as_str())
-            })
+            token.has_pos(|pos| regex.is_match(pos.as_str()))
         } else {
             true // No POS constraint
         };
@@ -200,6 +249,114 @@ impl CompiledToken {
         let result = text_match && pos_match;
         if self.negation { !result } else { result }
     }
+
+    /// Match the token text, or its lemmas for `inflected="yes"` (the form
+    /// itself when the analyzer gave no lemma)
+    fn text_matches(&self, token: &AnalyzedToken, matches: impl Fn(&str) -> bool) -> bool {
+        if !self.inflected {
+            return matches(token.token.text);
+        }
+        let mut lemmas = token.lemma.iter()
+            .chain(token.readings.iter().filter_map(|r| r.lemma.as_ref()))
+            .peekable();
+        if lemmas.peek().is_none() {
+            matches(token.token.text)
+        } else {
+            lemmas.any(|lemma| matches(lemma))
+        }
+    }
+}
+
+/// Match `pattern` at position `start` of the non-whitespace tokens
+///
+/// Returns the end index if matched. `on_token(pattern_idx, token_idx)` is
+/// called for every token consumed by a pattern element (the disambiguator
+/// uses it to find the tokens inside `<marker>`).
+pub(crate) fn match_pattern(
+    word_tokens: &[(usize, &AnalyzedToken)],
+    start: usize,
+    pattern: &[CompiledToken],
+    mut on_token: impl FnMut(usize, usize),
+) -> Option<usize> {
+    let mut token_idx = start;
+    let mut pattern_idx = 0;
+
+    while pattern_idx < pattern.len() {
+        let pat_token = &pattern[pattern_idx];
+
+        // SENT_START: zero-width anchor, the next token must start a sentence
+        if pat_token.sentence_start {
+            if token_idx >= word_tokens.len() || !word_tokens[token_idx].1.is_sentence_start() {
+                return None;
+            }
+            pattern_idx += 1;
+            continue;
+        }
+
+        // Handle optional tokens (min=0)
+        if pat_token.min == 0 {
+            // Try matching with and without this token
+            if token_idx < word_tokens.len() && pat_token.matches(word_tokens[token_idx].1) {
+                // Token matched, continue
+                on_token(pattern_idx, token_idx);
+                token_idx += 1;
+            }
+            // Move to next pattern element regardless (it's optional)
+            pattern_idx += 1;
+            continue;
+        }
+
+        // Handle skip (gap matching)
+        if let Some(skip) = pat_token.skip {
+            // Match current token
+            if token_idx >= word_tokens.len() || !pat_token.matches(word_tokens[token_idx].1) {
+                return None;
+            }
+            on_token(pattern_idx, token_idx);
+            token_idx += 1;
+
+            // Skip up to N tokens before matching next pattern element
+            if pattern_idx + 1 < pattern.len() {
+                let next_pat = &pattern[pattern_idx + 1];
+                let max_skip = if skip < 0 {
+                    word_tokens.len() - token_idx
+                } else {
+                    skip as usize
+                };
+
+                let mut found = false;
+                for offset in 0..=max_skip {
+                    if token_idx + offset < word_tokens.len()
+                        && next_pat.matches(word_tokens[token_idx + offset].1)
+                    {
+                        token_idx += offset;
+                        found = true;
+                        break;
+                    }
+                }
+                if !found {
+                    return None;
+                }
+            }
+            pattern_idx += 1;
+            continue;
+        }
+
+        // Regular matching (min=1, max=1)
+        if token_idx >= word_tokens.len() {
+            return None;
+        }
+
+        if !pat_token.matches(word_tokens[token_idx].1) {
+            return None;
+        }
+
+        on_token(pattern_idx, token_idx);
+        token_idx += 1;
+        pattern_idx += 1;
+    }
+
+    Some(token_idx)
 }
 
 /// Compiled antipattern for efficient matching
@@ -229,6 +386,15 @@ struct CompiledRule {
     dynamic_suggestions: Vec<ComplexSuggestion>,
     /// Unification groups for gender/number agreement
     unification_groups: Vec<UnificationGroup>,
+    /// Strictness tier (picky rules only run when requested)
+    level: CheckLevel,
+    /// Rule metadata kept for `Checker::rules()`
+    name: String,
+    category: RuleCategory,
+    examples: Vec<RuleExample>,
+    issue_type: Option<IssueType>,
+    default_off: bool,
+    url: Option<String>,
 }
 
 impl CompiledRule {
@@ -253,6 +419,21 @@ impl CompiledRule {
             suggestions: rule.suggestions.clone(),
             dynamic_suggestions: rule.dynamic_suggestions.clone(),
             unification_groups: rule.unification_groups.clone(),
+            level: rule.level(),
+            name: rule.name.clone(),
+            category: RuleCategory::from_lt_name(&rule.category),
+            examples: rule
+                .examples
+                .iter()
+                .map(|e| RuleExample {
+                    text: e.text.clone(),
+                    is_correct: e.is_correct,
+                    correction: e.correction.clone(),
+                })
+                .collect(),
+            issue_type: rule.issue_type(),
+            default_off: rule.default_off,
+            url: rule.url.clone(),
         })
     }
 }
@@ -375,12 +556,20 @@ fn check_unification(
 /// Checker for complex grammar rules loaded at runtime
 pub struct DynamicPatternChecker {
     rules: Vec<CompiledRule>,
+    /// Language of the rules: picks the morphology used for `postag_replace`
+    language: Language,
 }
 
 impl DynamicPatternChecker {
     /// Create an empty checker
     pub fn new() -> Self {
-        Self { rules: Vec::new() }
+        Self { rules: Vec::new(), language: Language::French }
+    }
+
+    /// Set the language of the rules (French morphology by default)
+    pub fn with_language(mut self, language: Language) -> Self {
+        self.language = language;
+        self
     }
 
     /// Load rules from JSON string
@@ -391,7 +580,7 @@ impl DynamicPatternChecker {
             .filter_map(CompiledRule::from_json)
             .collect();
 
-        Ok(Self { rules })
+        Ok(Self { rules, language: Language::French })
     }
 
     /// Load rules from a slice of ComplexRule
@@ -401,7 +590,7 @@ impl DynamicPatternChecker {
             .filter_map(CompiledRule::from_json)
             .collect();
 
-        Self { rules }
+        Self { rules, language: Language::French }
     }
 
     /// Number of loaded rules
@@ -417,73 +606,7 @@ impl DynamicPatternChecker {
         start: usize,
         pattern: &[CompiledToken],
     ) -> Option<usize> {
-        let mut token_idx = start;
-        let mut pattern_idx = 0;
-
-        while pattern_idx < pattern.len() {
-            let pat_token = &pattern[pattern_idx];
-
-            // Handle optional tokens (min=0)
-            if pat_token.min == 0 {
-                // Try matching with and without this token
-                if token_idx < word_tokens.len() && pat_token.matches(word_tokens[token_idx].1) {
-                    // Token matched, continue
-                    token_idx += 1;
-                }
-                // Move to next pattern element regardless (it's optional)
-                pattern_idx += 1;
-                continue;
-            }
-
-            // Handle skip (gap matching)
-            if let Some(skip) = pat_token.skip {
-                // Match current token
-                if token_idx >= word_tokens.len() || !pat_token.matches(word_tokens[token_idx].1) {
-                    return None;
-                }
-                token_idx += 1;
-
-                // Skip up to N tokens before matching next pattern element
-                if pattern_idx + 1 < pattern.len() {
-                    let next_pat = &pattern[pattern_idx + 1];
-                    let max_skip = if skip < 0 {
-                        word_tokens.len() - token_idx
-                    } else {
-                        skip as usize
-                    };
-
-                    let mut found = false;
-                    for offset in 0..=max_skip {
-                        if token_idx + offset < word_tokens.len()
-                            && next_pat.matches(word_tokens[token_idx + offset].1)
-                        {
-                            token_idx += offset;
-                            found = true;
-                            break;
-                        }
-                    }
-                    if !found {
-                        return None;
-                    }
-                }
-                pattern_idx += 1;
-                continue;
-            }
-
-            // Regular matching (min=1, max=1)
-            if token_idx >= word_tokens.len() {
-                return None;
-            }
-
-            if !pat_token.matches(word_tokens[token_idx].1) {
-                return None;
-            }
-
-            token_idx += 1;
-            pattern_idx += 1;
-        }
-
-        Some(token_idx)
+        match_pattern(word_tokens, start, pattern, |_, _| {})
     }
 
     /// Check if any antipattern matches the given token range
@@ -511,6 +634,7 @@ impl DynamicPatternChecker {
         &self,
         matched_tokens: &[&AnalyzedToken],
         dynamic_suggestions: &[ComplexSuggestion],
+        anchors: usize,
     ) -> Vec<String> {
         let mut result = Vec::new();
 
@@ -530,43 +654,23 @@ impl DynamicPatternChecker {
                         postag_replace,
                         case_conversion,
                     } => {
-                        // index is 1-based in LanguageTool
-                        if *index > 0 && *index <= matched_tokens.len() {
-                            let token = matched_tokens[*index - 1];
+                        // index is 1-based in LanguageTool and counts SENT_START anchors
+                        if *index > anchors && *index - anchors <= matched_tokens.len() {
+                            let token = matched_tokens[*index - anchors - 1];
                             let mut text = token.token.text.to_string();
 
                             // Apply POS-based morphological transformation if present
-                            if let (Some(pos_pattern), Some(pos_replace)) = (postag, postag_replace) {
-                                if let Some(ref current_pos) = token.pos {
-                                    // Get the current POS as string
-                                    let pos_str = current_pos.as_str();
-
-                                    // Transform the POS tag using the pattern/replace
-                                    if let Some(target_tags) = transform_pos(pos_str, pos_pattern, pos_replace) {
-                                        // Get the lemma from morphology or token
-                                        let morph = FrenchMorphology::load();
-                                        let lemma = token.lemma.as_deref()
-                                            .or_else(|| morph.get_lemma(&text))
-                                            .unwrap_or(&text);
-
-                                        // Try to synthesize the new form
-                                        for target_tag in &target_tags {
-                                            // First try exact match
-                                            let forms = morph.synthesize(lemma, target_tag);
-                                            if !forms.is_empty() {
-                                                text = forms[0].to_string();
-                                                break;
-                                            }
-
-                                            // Try regex match if exact doesn't work
-                                            let regex_forms = morph.synthesize_regex(lemma, target_tag);
-                                            if !regex_forms.is_empty() {
-                                                text = regex_forms[0].to_string();
-                                                break;
-                                            }
-                                        }
-                                    }
-                                }
+                            let target_tags = match (postag, postag_replace) {
+                                // Transform the POS tag using the pattern/replace
+                                (Some(pos_pattern), Some(pos_replace)) => token.pos.as_ref()
+                                    .and_then(|pos| transform_pos(pos.as_str(), pos_pattern, pos_replace)),
+                                // postag alone is the target tag
+                                (Some(pos_pattern), None) => Some(vec![pos_pattern.clone()]),
+                                _ => None,
+                            };
+                            // Lexicon forms are lowercase: keep the token's capitalization
+                            if let Some(form) = target_tags.and_then(|tags| self.synthesize(token, &text, &tags)) {
+                                text = preserve_case(&text, &form);
                             }
 
                             // Apply regex replacement if present
@@ -618,73 +722,157 @@ impl DynamicPatternChecker {
 
         result
     }
-}
 
-impl Default for DynamicPatternChecker {
-    fn default() -> Self {
-        Self::new()
+    /// First form of the token lemma for one of the target tags
+    fn synthesize(&self, token: &AnalyzedToken, text: &str, target_tags: &[String]) -> Option<String> {
+        match self.language {
+            Language::English => {
+                let morph = EnglishMorphology::load();
+                let lemma = token.lemma.clone().unwrap_or_else(|| {
+                    morph.lemmatize(text, token.pos.as_ref().map_or("", |pos| pos.as_str()))
+                });
+                target_tags.iter()
+                    .find_map(|tag| morph.synthesize_regex(&lemma, tag).into_iter().next())
+            }
+            _ => {
+                // Get the lemma from morphology or token
+                let morph = FrenchMorphology::load();
+                let lemma = token.lemma.as_deref()
+                    .or_else(|| morph.get_lemma(text))
+                    .unwrap_or(text);
+
+                // Exact match first, then regex match
+                target_tags.iter().find_map(|tag| {
+                    let forms = morph.synthesize(lemma, tag);
+                    let forms = if forms.is_empty() { morph.synthesize_regex(lemma, tag) } else { forms };
+                    forms.first().map(|form| form.to_string())
+                })
+            }
+        }
     }
 }
 
-impl Checker for DynamicPatternChecker {
-    fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> CheckResult {
-        let mut matches = Vec::new();
-
-        // Filter out whitespace tokens for pattern matching
-        let word_tokens: Vec<(usize, &AnalyzedToken)> = tokens
-            .iter()
-            .enumerate()
-            .filter(|(_, t)| t.token.kind != TokenKind::Whitespace)
-            .collect();
+/// Tokens used for pattern matching (everything but whitespace), with their index
+fn word_tokens<'a, 't>(tokens: &'a [AnalyzedToken<'t>]) -> Vec<(usize, &'a AnalyzedToken<'t>)> {
+    tokens
+        .iter()
+        .enumerate()
+        .filter(|(_, t)| t.token.kind != TokenKind::Whitespace)
+        .collect()
+}
 
-        for rule in &self.rules {
-            // Slide pattern across all word positions
-            for start in 0..word_tokens.len() {
-                if let Some(end) = self.try_match_pattern(&word_tokens, start, &rule.pattern) {
-                    // Check if any antipattern matches (exception)
-                    if self.matches_antipattern(&word_tokens, start, end, &rule.antipatterns) {
-                        continue;
-                    }
+impl DynamicPatternChecker {
+    /// Add the matches of one rule, sliding its pattern over every word position
+    fn check_rule(&self, rule: &CompiledRule, word_tokens: &[(usize, &AnalyzedToken)], matches: &mut Vec<Match>) {
+        // Slide pattern across all word positions
+        for start in 0..word_tokens.len() {
+            if let Some(end) = self.try_match_pattern(word_tokens, start, &rule.pattern) {
+                // Pattern made of anchors only: nothing to highlight
+                if end == start {
+                    continue;
+                }
 
-                    // Collect matched tokens
-                    let matched_tokens: Vec<&AnalyzedToken> = (start..end)
-                        .map(|i| word_tokens[i].1)
-                        .collect();
+                // Check if any antipattern matches (exception)
+                if self.matches_antipattern(word_tokens, start, end, &rule.antipatterns) {
+                    continue;
+                }
 
-                    // Check unification constraints if present
-                    if !rule.unification_groups.is_empty() {
-                        if !check_unification(&matched_tokens, &rule.unification_groups) {
-                            continue; // Unification constraints not satisfied
-                        }
-                    }
+                // Collect matched tokens
+                let matched_tokens: Vec<&AnalyzedToken> = (start..end)
+                    .map(|i| word_tokens[i].1)
+                    .collect();
 
-                    // Calculate span from first to last matched token
-                    let span_start = word_tokens[start].1.token.span.start;
-                    let span_end = word_tokens[end - 1].1.token.span.end;
-
-                    // Generate suggestions: static first, then dynamic
-                    let mut suggestions = rule.suggestions.clone();
-                    if !rule.dynamic_suggestions.is_empty() {
-                        let dynamic = self.generate_dynamic_suggestions(
-                            &matched_tokens,
-                            &rule.dynamic_suggestions,
-                        );
-                        suggestions.extend(dynamic);
+                // Check unification constraints if present
+                if !rule.unification_groups.is_empty() {
+                    if !check_unification(&matched_tokens, &rule.unification_groups) {
+                        continue; // Unification constraints not satisfied
                     }
+                }
 
-                    matches.push(Match {
-                        span: span_start..span_end,
-                        message: rule.message.clone(),
-                        rule_id: rule.id.clone(),
-                        suggestions,
-                        severity: Severity::Warning,
-                    });
+                // Calculate span from first to last matched token
+                let span_start = word_tokens[start].1.token.span.start;
+                let span_end = word_tokens[end - 1].1.token.span.end;
+
+                // Generate suggestions: static first, then dynamic
+                let mut suggestions = rule.suggestions.clone();
+                if !rule.dynamic_suggestions.is_empty() {
+                    let dynamic = self.generate_dynamic_suggestions(
+                        &matched_tokens,
+                        &rule.dynamic_suggestions,
+                        rule.pattern.iter().take_while(|t| t.sentence_start).count(),
+                    );
+                    suggestions.extend(dynamic);
                 }
+
+                matches.push(Match {
+                    span: span_start..span_end,
+                    message: rule.message.clone(),
+                    rule_id: rule.id.clone(),
+                    suggestions,
+                    severity: rule.issue_type.map_or(Severity::Warning, |t| t.severity()),
+                    issue_type: rule.issue_type,
+                });
             }
         }
+    }
+}
 
+impl Default for DynamicPatternChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Checker for DynamicPatternChecker {
+    fn check(&self, text: &str, tokens: &[AnalyzedToken]) -> CheckResult {
+        self.check_with_level(text, tokens, CheckLevel::Picky)
+    }
+
+    fn check_with_level(&self, _text: &str, tokens: &[AnalyzedToken], level: CheckLevel) -> CheckResult {
+        let mut matches = Vec::new();
+        let word_tokens = word_tokens(tokens);
+        for rule in self.rules.iter().filter(|r| r.level <= level) {
+            self.check_rule(rule, &word_tokens, &mut matches);
+        }
         CheckResult { matches }
     }
+
+    fn check_profiled(&self, _text: &str, tokens: &[AnalyzedToken], level: CheckLevel) -> (CheckResult, Vec<RuleTiming>) {
+        let mut matches = Vec::new();
+        let word_tokens = word_tokens(tokens);
+        let timings = self
+            .rules
+            .iter()
+            .filter(|r| r.level <= level)
+            .map(|rule| {
+                let start = Instant::now();
+                let before = matches.len();
+                self.check_rule(rule, &word_tokens, &mut matches);
+                RuleTiming { rule_id: rule.id.clone(), elapsed: start.elapsed(), matches: matches.len() - before }
+            })
+            .collect();
+        (CheckResult { matches }, timings)
+    }
+
+    fn rules(&self) -> Vec<RuleMetadata> {
+        self.rules
+            .iter()
+            .map(|rule| {
+                let description = if rule.name.is_empty() { &rule.message } else { &rule.name };
+                let mut metadata = RuleMetadata::new(rule.id.clone(), description.clone(), rule.category)
+                    .with_level(rule.level)
+                    .with_default_on(!rule.default_off)
+                    .with_examples(rule.examples.clone());
+                if let Some(issue_type) = rule.issue_type {
+                    metadata = metadata.with_issue_type(issue_type);
+                }
+                if let Some(url) = &rule.url {
+                    metadata = metadata.with_url(url.clone());
+                }
+                metadata
+            })
+            .collect()
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -713,10 +901,7 @@ pub fn get_fr_dynamic_checker() -> &'static DynamicPatternChecker {
 
 /// Get the English dynamic pattern checker (lazily compiled)
 pub fn get_en_dynamic_checker() -> &'static DynamicPatternChecker {
-    EN_DYNAMIC_CHECKER.get_or_init(|| {
-        DynamicPatternChecker::from_json(EN_COMPLEX_PATTERNS_JSON)
-            .expect("Failed to parse en_complex_patterns.json")
-    })
+    EN_DYNAMIC_CHECKER.get_or_init(create_en_dynamic_checker)
 }
 
 /// Create a new French dynamic pattern checker
@@ -729,6 +914,7 @@ pub fn create_fr_dynamic_checker() -> DynamicPatternChecker {
 pub fn create_en_dynamic_checker() -> DynamicPatternChecker {
     DynamicPatternChecker::from_json(EN_COMPLEX_PATTERNS_JSON)
         .expect("Failed to parse en_complex_patterns.json")
+        .with_language(Language::English)
 }
 
 #[cfg(test)]
@@ -737,15 +923,15 @@ mod tests {
     use crate::core::{PosTag, Token};
 
     fn make_token<'a>(text: &'a str, pos: Option<PosTag>, start: usize) -> AnalyzedToken<'a> {
-        AnalyzedToken {
-            token: Token {
+        AnalyzedToken::new(
+            Token {
                 text,
                 span: start..start + text.len(),
                 kind: TokenKind::Word,
             },
-            lemma: None,
+            None,
             pos,
-        }
+        )
     }
 
     #[test]
@@ -786,6 +972,27 @@ mod tests {
         assert_eq!(result.matches[0].rule_id, "TEST_RULE");
     }
 
+    #[test]
+    fn test_check_profiled_times_each_rule() {
+        let token = |text: &str| format!(
+            r#"{{"text": "{}", "regexp": null, "postag": null, "postag_regexp": false, "inflected": false,
+                "case_sensitive": false, "negation": false, "min": 1, "max": 1, "skip": null}}"#,
+            text
+        );
+        let rule = |id: &str, text: &str| format!(
+            r#"{{"id": "{}", "name": "", "category": "Test", "pattern": [{}], "antipatterns": [], "message": "m", "suggestions": []}}"#,
+            id, token(text)
+        );
+        let json = format!("[{}, {}]", rule("RULE_A", "test"), rule("RULE_B", "other"));
+        let checker = DynamicPatternChecker::from_json(&json).unwrap();
+
+        let tokens = vec![make_token("test", None, 0)];
+        let (result, timings) = checker.check_profiled("test", &tokens, CheckLevel::Picky);
+        assert_eq!(result.matches.len(), checker.check("test", &tokens).matches.len());
+        let counts: Vec<_> = timings.iter().map(|t| (t.rule_id.as_str(), t.matches)).collect();
+        assert_eq!(counts, vec![("RULE_A", 1), ("RULE_B", 0)]);
+    }
+
     #[test]
     fn test_regex_match() {
         let json = r#"[{
@@ -816,6 +1023,88 @@ mod tests {
         assert_eq!(result3.matches.len(), 0);
     }
 
+    #[test]
+    fn test_picky_rule_level() {
+        let json = r#"[{
+            "id": "PICKY_RULE",
+            "name": "Picky Test",
+            "category": "Style",
+            "pattern": [
+                {"text": null, "regexp": "very", "postag": null, "postag_regexp": false,
+                 "inflected": false, "case_sensitive": false, "negation": false, "min": 1, "max": 1, "skip": null}
+            ],
+            "antipatterns": [],
+            "message": "Consider removing 'very'",
+            "suggestions": [],
+            "tags": ["picky"]
+        }]"#;
+
+        let checker = DynamicPatternChecker::from_json(json).unwrap();
+        let tokens = vec![make_token("very", None, 0)];
+
+        assert_eq!(checker.check_with_level("very", &tokens, CheckLevel::Picky).matches.len(), 1);
+        assert!(checker.check_with_level("very", &tokens, CheckLevel::Default).matches.is_empty());
+    }
+
+    #[test]
+    fn test_rule_metadata() {
+        let json = r#"[{
+            "id": "META_RULE",
+            "name": "Metadata Test",
+            "category": "Possible Typo",
+            "pattern": [
+                {"text": "teh", "regexp": null, "postag": null, "postag_regexp": false,
+                 "inflected": false, "case_sensitive": false, "negation": false, "min": 1, "max": 1, "skip": null}
+            ],
+            "antipatterns": [],
+            "message": "Did you mean 'the'?",
+            "suggestions": ["the"],
+            "examples": [{"text": "I saw teh cat.", "is_correct": false, "correction": "the"}]
+        }]"#;
+
+        let checker = DynamicPatternChecker::from_json(json).unwrap();
+        let rules = checker.rules();
+
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].id, "META_RULE");
+        assert_eq!(rules[0].description, "Metadata Test");
+        assert_eq!(rules[0].category, RuleCategory::Typos);
+        assert_eq!(rules[0].examples.len(), 1);
+        assert!(!rules[0].examples[0].is_correct);
+    }
+
+    #[test]
+    fn test_issue_type_and_default_off() {
+        let json = r#"[{
+            "id": "TYPED_RULE",
+            "name": "Typed Test",
+            "category": "Grammar",
+            "pattern": [
+                {"text": "teh", "regexp": null, "postag": null, "postag_regexp": false,
+                 "inflected": false, "case_sensitive": false, "negation": false, "min": 1, "max": 1, "skip": null}
+            ],
+            "antipatterns": [],
+            "message": "Did you mean 'the'?",
+            "suggestions": ["the"],
+            "type": "misspelling",
+            "default_off": true,
+            "url": "https://example.com/teh"
+        }]"#;
+
+        let checker = DynamicPatternChecker::from_json(json).unwrap();
+        let tokens = vec![make_token("teh", None, 0)];
+        let result = checker.check("teh", &tokens);
+
+        assert_eq!(result.matches.len(), 1);
+        assert_eq!(result.matches[0].issue_type, Some(IssueType::Misspelling));
+        assert_eq!(result.matches[0].severity, Severity::Error);
+
+        let rules = checker.rules();
+        assert!(!rules[0].default_on);
+        assert_eq!(rules[0].issue_type, IssueType::Misspelling);
+        assert_eq!(rules[0].url.as_deref(), Some("https://example.com/teh"));
+    }
+
     #[test]
     fn test_optional_token() {
         let json = r#"[{
@@ -985,4 +1274,130 @@ mod tests {
         assert!(!m.suggestions.is_empty(), "Should have a suggestion");
         assert_eq!(m.suggestions[0], "the Test", "Case conversion should work");
     }
+
+    #[test]
+    fn test_postag_matches_any_reading() {
+        use crate::core::Reading;
+
+        let json = r#"[{
+            "id": "DT_NOUN_TEST",
+            "name": "Determiner noun test",
+            "category": "Test",
+            "pattern": [
+                {"text": "the", "regexp": null, "postag": null, "postag_regexp": false,
+                 "inflected": false, "case_sensitive": false, "negation": false, "min": 1, "max": 1, "skip": null},
+                {"text": null, "regexp": null, "postag": "NN.*", "postag_regexp": true,
+                 "inflected": false, "case_sensitive": false, "negation": false, "min": 1, "max": 1, "skip": null}
+            ],
+            "antipatterns": [],
+            "message": "Determiner + noun",
+            "suggestions": []
+        }]"#;
+        let checker = DynamicPatternChecker::from_json(json).unwrap();
+
+        let saw = |pos: PosTag| Reading { lemma: None, pos };
+        let tokens = vec![
+            make_token("the", Some(PosTag::DT), 0),
+            AnalyzedToken::with_readings(
+                Token { text: "saw", span: 4..7, kind: TokenKind::Word },
+                vec![saw(PosTag::VBD), saw(PosTag::NN)],
+            ),
+        ];
+        assert_eq!(checker.check("the saw", &tokens).matches.len(), 1);
+
+        let tokens = vec![make_token("the", Some(PosTag::DT), 0), make_token("saw", Some(PosTag::VBD), 4)];
+        assert!(checker.check("the saw", &tokens).matches.is_empty());
+    }
+
+    #[test]
+    fn test_english_postag_replace_and_inflected() {
+        use crate::analyzer::PosTagger;
+        use crate::core::traits::{Analyzer, Tokenizer};
+        use crate::tokenizer::SimpleTokenizer;
+
+        // "he have went" → "he has gone": <token inflected="yes">have</token>
+        // + <match no="2" postag="VBZ"/> and <match no="3" postag="VBD" postag_replace="VBN"/>
+        let json = r#"[{
+            "id": "HAVE_WENT",
+            "name": "have went",
+            "category": "Grammar",
+            "pattern": [
+                {"text": "he", "regexp": null, "postag": null, "postag_regexp": false,
+                 "inflected": false, "case_sensitive": false, "negation": false, "min": 1, "max": 1, "skip": null},
+                {"text": "have", "regexp": null, "postag": null, "postag_regexp": false,
+                 "inflected": true, "case_sensitive": false, "negation": false, "min": 1, "max": 1, "skip": null},
+                {"text": null, "regexp": null, "postag": "VBD", "postag_regexp": false,
+                 "inflected": false, "case_sensitive": false, "negation": false, "min": 1, "max": 1, "skip": null}
+            ],
+            "antipatterns": [],
+            "message": "Use the past participle",
+            "suggestions": [],
+            "dynamic_suggestions": [{"parts": [
+                {"type": "MatchRef", "index": 1},
+                {"type": "Literal", "text": " "},
+                {"type": "MatchRef", "index": 2, "postag": "VBZ"},
+                {"type": "Literal", "text": " "},
+                {"type": "MatchRef", "index": 3, "postag": "VBD", "postag_replace": "VBN"}
+            ]}]
+        }]"#;
+        let checker = DynamicPatternChecker::from_json(json).unwrap().with_language(Language::English);
+        let tagger = PosTagger::new().with_morphology(EnglishMorphology::load());
+
+        // Synthesized forms take the capitalization of the matched token
+        for (text, expected) in [
+            ("he have went", "he has gone"),
+            ("he had went", "he has gone"),
+            ("He Had Went", "He Has Gone"),
+            ("HE HAVE WENT", "HE HAS GONE"),
+        ] {
+            let tokens = tagger.analyze(SimpleTokenizer::new().tokenize(text));
+            let result = checker.check(text, &tokens);
+            assert_eq!(result.matches.len(), 1, "{text}");
+            assert_eq!(result.matches[0].suggestions, vec![expected]);
+        }
+
+        // The lemma, not the form, is matched
+        let tokens = tagger.analyze(SimpleTokenizer::new().tokenize("he saw went"));
+        assert!(checker.check("he saw went", &tokens).matches.is_empty());
+    }
+
+    #[test]
+    fn test_sentence_anchors() {
+        let json = r#"[{
+            "id": "SENT_ANCHOR_TEST",
+            "name": "Sentence anchor test",
+            "category": "Test",
+            "pattern": [
+                {"text": null, "regexp": null, "postag": "SENT_START", "postag_regexp": false,
+                 "inflected": false, "case_sensitive": false, "negation": false, "min": 1, "max": 1, "skip": null},
+                {"text": "and", "regexp": null, "postag": null, "postag_regexp": false,
+                 "inflected": false, "case_sensitive": false, "negation": false, "min": 1, "max": 1, "skip": null}
+            ],
+            "antipatterns": [],
+            "message": "Sentence starts with a conjunction",
+            "suggestions": [],
+            "dynamic_suggestions": [
+                {"parts": [{"type": "MatchRef", "index": 2, "case_conversion": "alllower"}]}
+            ]
+        }]"#;
+        let checker = DynamicPatternChecker::from_json(json).unwrap();
+
+        let text = "Tea and cake. And coffee.";
+        let mut tokens = vec![
+            make_token("Tea", None, 0),
+            make_token("and", None, 4),
+            make_token("cake", None, 8),
+            make_token(".", None, 12),
+            make_token("And", None, 14),
+            make_token("coffee", None, 18),
+            make_token(".", None, 24),
+        ];
+        crate::core::assign_sentences(&mut tokens, &[0..13, 14..25]);
+
+        let result = checker.check(text, &tokens);
+        assert_eq!(result.matches.len(), 1);
+        assert_eq!(result.matches[0].span, 14..17);
+        assert_eq!(result.matches[0].suggestions, vec!["and"]);
+        assert!(tokens[3].is_sentence_end() && !tokens[2].is_sentence_end());
+    }
 }
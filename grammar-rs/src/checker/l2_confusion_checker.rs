@@ -12,6 +12,7 @@
 
 use crate::checker::data::en_confusion_l2_fr::get_en_l2_fr_confusion;
 use crate::core::{AnalyzedToken, CheckResult, Match, Severity, TokenKind};
+use crate::core::registry::{RuleCategory, RuleMetadata};
 use crate::core::traits::Checker;
 
 /// L2 Confusion Checker for French speakers learning English
@@ -113,12 +114,17 @@ impl Checker for L2ConfusionChecker {
                     message: self.generate_message(word, &suggestion),
                     suggestions: vec![suggestion],
                     severity: Severity::Hint,
+                    issue_type: None,
                 });
             }
         }
 
         CheckResult { matches }
     }
+
+    fn rules(&self) -> Vec<RuleMetadata> {
+        vec![RuleMetadata::new("EN_L2_FR_CONFUSION", "False friends for French speakers", RuleCategory::ConfusedWords)]
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,6 @@
 mod spell;
+mod spell_ranking;
+mod user_dictionary;
 mod rules;
 pub mod data;
 mod confusion;
@@ -16,8 +18,11 @@ mod prohibit_checker;
 mod l2_confusion_checker;
 mod ngram_confusion_checker;
 mod dynamic_pattern_checker;
+mod variant_checker;
 
 pub use spell::SpellChecker;
+pub use spell_ranking::{phonetic_key, KeyboardLayout, SuggestionRanker, WordFrequency};
+pub use user_dictionary::UserDictionary;
 pub use rules::{
     RuleChecker, Rule,
     // Universal rules
@@ -33,7 +38,7 @@ pub use rules::{
     // Advanced style rules (Phase 6)
     SentenceLengthRule, ClicheRule, RedundancyRule,
     // French rules
-    FrenchPunctuationRule, FrenchAAccentRule, FrenchOuAccentRule, FrenchCeSeRule,
+    FrenchPunctuationRule, CanadianFrenchPunctuationRule, FrenchAAccentRule, FrenchOuAccentRule, FrenchCeSeRule,
     FrenchSubjectVerbRule, FrenchAdjectiveNounRule,
     // Advanced French rules (Phase 5)
     FrenchConditionnelSiRule, FrenchToutAccordRule,
@@ -116,6 +121,10 @@ pub use data::{
 pub use data::{
     DisambigPosEntry, EN_DISAMBIG_POS, FR_DISAMBIG_POS,
 };
+// US/GB spelling variants
+pub use data::{
+    UsGbMapping, EN_US_GB_MAPPINGS, us_to_gb, gb_to_us, is_us_spelling, is_gb_spelling,
+};
 // N-gram confusion words (for filtering N-gram data)
 pub use data::{
     EN_NGRAM_WORDS, is_en_ngram_word,
@@ -133,8 +142,10 @@ pub use compound_checker::CompoundWordChecker;
 pub use prohibit_checker::ProhibitChecker;
 pub use l2_confusion_checker::L2ConfusionChecker;
 pub use ngram_confusion_checker::NgramConfusionChecker;
+pub use variant_checker::{EnglishVariant, VariantSpellingChecker};
 pub use dynamic_pattern_checker::{
     DynamicPatternChecker, ComplexRule, ComplexPatternToken, ComplexAntipattern, ComplexExample,
     get_fr_dynamic_checker, get_en_dynamic_checker,
     create_fr_dynamic_checker, create_en_dynamic_checker,
 };
+pub(crate) use dynamic_pattern_checker::{CompiledToken, match_pattern};
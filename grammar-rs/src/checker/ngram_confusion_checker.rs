@@ -11,9 +11,13 @@ use std::path::Path;
 use std::sync::Arc;
 
 use crate::core::{AnalyzedToken, Match, Severity, TokenKind, CheckResult};
+use crate::core::registry::{RuleCategory, RuleMetadata};
 use crate::core::traits::Checker;
 use crate::language_model::{CompactNgramModel, Probability};
 
+/// Directories searched for the N-gram data, relative to the working directory
+const DEFAULT_DATA_DIRS: [&str; 3] = ["data/ngrams", "../data/ngrams", "grammar-rs/data/ngrams"];
+
 /// Confusion pair with calibrated factor
 #[derive(Debug, Clone)]
 pub struct ConfusionPair {
@@ -66,56 +70,12 @@ impl NgramConfusionChecker {
     /// environment variable is set (with `ngram-download` feature enabled),
     /// the data will be automatically downloaded from R2.
     pub fn try_load_en() -> Option<Self> {
-        use crate::language_model::downloader;
-
-        // Preferred data directories to check
-        let data_dirs = [
-            Path::new("data/ngrams"),
-            Path::new("../data/ngrams"),
-            Path::new("grammar-rs/data/ngrams"),
-        ];
-
-        // First, check if file exists in any of the paths
-        for data_dir in &data_dirs {
-            let bin_path = data_dir.join("en_ngrams.bin");
-            if bin_path.exists() {
-                match Self::from_file(&bin_path) {
-                    Ok(checker) => {
-                        tracing::info!("Loaded EN N-gram model from {:?}", bin_path);
-                        return Some(checker.with_en_confusion_pairs().with_en_confusion_extended());
-                    }
-                    Err(e) => {
-                        tracing::warn!("Failed to load EN N-gram model from {:?}: {}", bin_path, e);
-                    }
-                }
-            }
-        }
-
-        // File not found - try auto-download to first writable directory
-        let primary_dir = data_dirs[0];
-        match downloader::ensure_ngram_data("en", primary_dir) {
-            Ok(true) => {
-                // Successfully downloaded, try to load
-                let bin_path = primary_dir.join("en_ngrams.bin");
-                match Self::from_file(&bin_path) {
-                    Ok(checker) => {
-                        tracing::info!("Loaded EN N-gram model after download");
-                        return Some(checker.with_en_confusion_pairs().with_en_confusion_extended());
-                    }
-                    Err(e) => {
-                        tracing::warn!("Failed to load downloaded EN N-gram model: {}", e);
-                    }
-                }
-            }
-            Ok(false) => {
-                // Auto-download disabled, nothing to do
-            }
-            Err(e) => {
-                tracing::warn!("Failed to download EN N-gram data: {}", e);
-            }
-        }
+        Self::try_load("en", &DEFAULT_DATA_DIRS.map(Path::new))
+    }
 
-        None
+    /// Same as `try_load_en`, with `en_ngrams.bin` in the given directory only
+    pub fn try_load_en_in(data_dir: &Path) -> Option<Self> {
+        Self::try_load("en", &[data_dir])
     }
 
     /// Try to load from the default data path for French
@@ -124,44 +84,54 @@ impl NgramConfusionChecker {
     /// environment variable is set (with `ngram-download` feature enabled),
     /// the data will be automatically downloaded from R2.
     pub fn try_load_fr() -> Option<Self> {
+        Self::try_load("fr", &DEFAULT_DATA_DIRS.map(Path::new))
+    }
+
+    /// Same as `try_load_fr`, with `fr_ngrams.bin` in the given directory only
+    pub fn try_load_fr_in(data_dir: &Path) -> Option<Self> {
+        Self::try_load("fr", &[data_dir])
+    }
+
+    /// Load `<lang>_ngrams.bin` from the first directory that has it,
+    /// downloading it into the first directory when enabled
+    fn try_load(lang: &str, data_dirs: &[&Path]) -> Option<Self> {
         use crate::language_model::downloader;
 
-        // Preferred data directories to check
-        let data_dirs = [
-            Path::new("data/ngrams"),
-            Path::new("../data/ngrams"),
-            Path::new("grammar-rs/data/ngrams"),
-        ];
+        let file_name = format!("{}_ngrams.bin", lang);
+        let with_pairs = |checker: Self| match lang {
+            "fr" => checker.with_fr_confusion_pairs(),
+            _ => checker.with_en_confusion_pairs().with_en_confusion_extended(),
+        };
 
         // First, check if file exists in any of the paths
-        for data_dir in &data_dirs {
-            let bin_path = data_dir.join("fr_ngrams.bin");
+        for data_dir in data_dirs {
+            let bin_path = data_dir.join(&file_name);
             if bin_path.exists() {
                 match Self::from_file(&bin_path) {
                     Ok(checker) => {
-                        tracing::info!("Loaded FR N-gram model from {:?}", bin_path);
-                        return Some(checker.with_fr_confusion_pairs());
+                        tracing::info!("Loaded {} N-gram model from {:?}", lang.to_uppercase(), bin_path);
+                        return Some(with_pairs(checker));
                     }
                     Err(e) => {
-                        tracing::warn!("Failed to load FR N-gram model from {:?}: {}", bin_path, e);
+                        tracing::warn!("Failed to load {} N-gram model from {:?}: {}", lang.to_uppercase(), bin_path, e);
                     }
                 }
             }
         }
 
         // File not found - try auto-download to first writable directory
-        let primary_dir = data_dirs[0];
-        match downloader::ensure_ngram_data("fr", primary_dir) {
+        let primary_dir = data_dirs.first()?;
+        match downloader::ensure_ngram_data(lang, primary_dir) {
             Ok(true) => {
                 // Successfully downloaded, try to load
-                let bin_path = primary_dir.join("fr_ngrams.bin");
+                let bin_path = primary_dir.join(&file_name);
                 match Self::from_file(&bin_path) {
                     Ok(checker) => {
-                        tracing::info!("Loaded FR N-gram model after download");
-                        return Some(checker.with_fr_confusion_pairs());
+                        tracing::info!("Loaded {} N-gram model after download", lang.to_uppercase());
+                        return Some(with_pairs(checker));
                     }
                     Err(e) => {
-                        tracing::warn!("Failed to load downloaded FR N-gram model: {}", e);
+                        tracing::warn!("Failed to load downloaded {} N-gram model: {}", lang.to_uppercase(), e);
                     }
                 }
             }
@@ -169,7 +139,7 @@ impl NgramConfusionChecker {
                 // Auto-download disabled, nothing to do
             }
             Err(e) => {
-                tracing::warn!("Failed to download FR N-gram data: {}", e);
+                tracing::warn!("Failed to download {} N-gram data: {}", lang.to_uppercase(), e);
             }
         }
 
@@ -363,6 +333,7 @@ impl NgramConfusionChecker {
                     rule_id: "NGRAM_CONFUSION".to_string(),
                     suggestions: vec![entry.alternative.clone()],
                     severity: Severity::Warning,
+                    issue_type: None,
                 });
             }
         }
@@ -383,6 +354,10 @@ impl Checker for NgramConfusionChecker {
 
         CheckResult { matches }
     }
+
+    fn rules(&self) -> Vec<RuleMetadata> {
+        vec![RuleMetadata::new("NGRAM_CONFUSION", "Commonly confused words (n-gram statistics)", RuleCategory::ConfusedWords)]
+    }
 }
 
 #[cfg(test)]
@@ -17,9 +17,11 @@ use regex::Regex;
 use std::collections::HashMap;
 use std::sync::LazyLock;
 
+use crate::core::registry::{RuleCategory, RuleExample, RuleMetadata};
 use crate::core::traits::Checker;
 use crate::core::{AnalyzedToken, CheckResult, Match, Severity, TokenKind};
 
+use crate::checker::data::{get_en_examples_for_rule, get_fr_examples_for_rule};
 use crate::checker::data::{Antipattern, AntipatternToken};
 
 /// A pattern rule imported from LanguageTool
@@ -31,6 +33,37 @@ pub struct PatternRule {
     pub message: &'static str,
 }
 
+/// Example sentences from LanguageTool's `<example>` tags for a rule ID
+pub(crate) fn lt_rule_examples(rule_id: &str) -> Vec<RuleExample> {
+    let en = get_en_examples_for_rule(rule_id)
+        .into_iter()
+        .map(|e| (e.text, e.is_correct, e.correction));
+    let fr = get_fr_examples_for_rule(rule_id)
+        .into_iter()
+        .map(|e| (e.text, e.is_correct, e.correction));
+
+    en.chain(fr)
+        .map(|(text, is_correct, correction)| RuleExample {
+            text: text.to_string(),
+            is_correct,
+            correction: correction.map(|c| c.to_string()),
+        })
+        .collect()
+}
+
+/// Describe pattern rules (rules sharing an ID in a rulegroup are listed once)
+fn pattern_rules_metadata(rules: &[PatternRule]) -> Vec<RuleMetadata> {
+    let mut seen = std::collections::HashSet::new();
+    rules
+        .iter()
+        .filter(|rule| seen.insert(rule.id))
+        .map(|rule| {
+            RuleMetadata::new(rule.id, rule.message, RuleCategory::guess(rule.id, Severity::Warning))
+                .with_examples(lt_rule_examples(rule.id))
+        })
+        .collect()
+}
+
 /// Checker that matches multi-word patterns from LanguageTool grammar.xml
 pub struct PatternRuleChecker {
     rules: &'static [PatternRule],
@@ -125,6 +158,7 @@ impl Checker for PatternRuleChecker {
                             vec![suggestion]
                         },
                         severity: Severity::Warning,
+                        issue_type: None,
                     });
                 }
             }
@@ -132,6 +166,10 @@ impl Checker for PatternRuleChecker {
 
         CheckResult { matches }
     }
+
+    fn rules(&self) -> Vec<RuleMetadata> {
+        pattern_rules_metadata(self.rules)
+    }
 }
 
 /// Optimized pattern rule checker using Aho-Corasick algorithm.
@@ -418,6 +456,7 @@ impl Checker for AhoPatternRuleChecker {
                                 vec![suggestion]
                             },
                             severity: Severity::Warning,
+                            issue_type: None,
                         });
                     }
                 }
@@ -426,6 +465,10 @@ impl Checker for AhoPatternRuleChecker {
 
         CheckResult { matches }
     }
+
+    fn rules(&self) -> Vec<RuleMetadata> {
+        pattern_rules_metadata(self.rules)
+    }
 }
 
 #[cfg(test)]
@@ -435,15 +478,15 @@ mod tests {
     use std::ops::Range;
 
     fn make_token<'a>(text: &'a str, span: Range<usize>) -> AnalyzedToken<'a> {
-        AnalyzedToken {
-            token: Token {
+        AnalyzedToken::new(
+            Token {
                 text,
                 span,
                 kind: TokenKind::Word,
             },
-            lemma: None,
-            pos: None,
-        }
+            None,
+            None,
+        )
     }
 
     #[test]
@@ -8,6 +8,8 @@
 //!
 //! This unlocks ~5000+ additional rules from LanguageTool that require POS tagging.
 
+use crate::checker::pattern_rules::lt_rule_examples;
+use crate::core::registry::{RuleCategory, RuleMetadata};
 use crate::core::traits::Checker;
 use crate::core::{AnalyzedToken, CheckResult, Match, PosTag, Severity};
 
@@ -29,8 +31,9 @@ impl PosPatternElement {
             token.token.text.eq_ignore_ascii_case(t)
         });
 
+        // Any reading of an ambiguous token can match
         let pos_match = self.pos_pattern.map_or(true, |pattern| {
-            token.pos.as_ref().map_or(false, |pos| pos.matches_pattern(pattern))
+            token.has_pos(|pos| pos.matches_pattern(pattern))
         });
 
         let result = text_match && pos_match;
@@ -135,6 +138,7 @@ impl Checker for PosPatternChecker {
                         rule_id: rule.id.to_string(),
                         suggestions: rule.suggestions.iter().map(|s| s.to_string()).collect(),
                         severity: Severity::Warning,
+                        issue_type: None,
                     });
                 }
             }
@@ -142,6 +146,18 @@ impl Checker for PosPatternChecker {
 
         CheckResult { matches }
     }
+
+    fn rules(&self) -> Vec<RuleMetadata> {
+        let mut seen = std::collections::HashSet::new();
+        self.rules
+            .iter()
+            .filter(|rule| seen.insert(rule.id))
+            .map(|rule| {
+                RuleMetadata::new(rule.id, rule.message, RuleCategory::Grammar)
+                    .with_examples(lt_rule_examples(rule.id))
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -150,15 +166,15 @@ mod tests {
     use crate::core::{Token, TokenKind};
 
     fn make_analyzed_token(text: &str, pos: Option<PosTag>) -> AnalyzedToken {
-        AnalyzedToken {
-            token: Token {
+        AnalyzedToken::new(
+            Token {
                 text,
                 span: 0..text.len(),
                 kind: TokenKind::Word,
             },
-            lemma: None,
+            None,
             pos,
-        }
+        )
     }
 
     #[test]
@@ -209,6 +225,31 @@ mod tests {
         assert!(!element.matches(&token_vb)); // Is a verb, so doesn't match
     }
 
+    #[test]
+    fn test_pos_pattern_element_matches_any_reading() {
+        use crate::core::Reading;
+
+        let element = PosPatternElement {
+            text: None,
+            pos_pattern: Some("NN"),
+            negation: false,
+        };
+
+        // "saw": VBD selected, NN still possible
+        let token = AnalyzedToken::with_readings(
+            Token { text: "saw", span: 0..3, kind: TokenKind::Word },
+            vec![
+                Reading { lemma: Some("see".to_string()), pos: PosTag::VBD },
+                Reading { lemma: Some("saw".to_string()), pos: PosTag::NN },
+            ],
+        );
+        assert_eq!(token.pos, Some(PosTag::VBD));
+        assert!(element.matches(&token));
+
+        let negated = PosPatternElement { negation: true, ..element };
+        assert!(!negated.matches(&token));
+    }
+
     // Static test rule definitions for testing
     static TEST_DT_NN_PATTERN: &[PosPatternElement] = &[
         PosPatternElement { text: None, pos_pattern: Some("DT"), negation: false },
@@ -244,21 +285,9 @@ mod tests {
 
         // Create tokens: "the dog" with whitespace between
         let tokens = vec![
-            AnalyzedToken {
-                token: Token { text: "the", span: 0..3, kind: TokenKind::Word },
-                lemma: None,
-                pos: Some(PosTag::DT),
-            },
-            AnalyzedToken {
-                token: Token { text: " ", span: 3..4, kind: TokenKind::Whitespace },
-                lemma: None,
-                pos: None,
-            },
-            AnalyzedToken {
-                token: Token { text: "dog", span: 4..7, kind: TokenKind::Word },
-                lemma: None,
-                pos: Some(PosTag::NN),
-            },
+            AnalyzedToken::new(Token { text: "the", span: 0..3, kind: TokenKind::Word }, None, Some(PosTag::DT)),
+            AnalyzedToken::new(Token { text: " ", span: 3..4, kind: TokenKind::Whitespace }, None, None),
+            AnalyzedToken::new(Token { text: "dog", span: 4..7, kind: TokenKind::Word }, None, Some(PosTag::NN)),
         ];
 
         let result = checker.check("the dog", &tokens);
@@ -275,21 +304,9 @@ mod tests {
 
         // Create tokens: "the dog" - should NOT match DT_VB pattern
         let tokens = vec![
-            AnalyzedToken {
-                token: Token { text: "the", span: 0..3, kind: TokenKind::Word },
-                lemma: None,
-                pos: Some(PosTag::DT),
-            },
-            AnalyzedToken {
-                token: Token { text: " ", span: 3..4, kind: TokenKind::Whitespace },
-                lemma: None,
-                pos: None,
-            },
-            AnalyzedToken {
-                token: Token { text: "dog", span: 4..7, kind: TokenKind::Word },
-                lemma: None,
-                pos: Some(PosTag::NN),
-            },
+            AnalyzedToken::new(Token { text: "the", span: 0..3, kind: TokenKind::Word }, None, Some(PosTag::DT)),
+            AnalyzedToken::new(Token { text: " ", span: 3..4, kind: TokenKind::Whitespace }, None, None),
+            AnalyzedToken::new(Token { text: "dog", span: 4..7, kind: TokenKind::Word }, None, Some(PosTag::NN)),
         ];
 
         let result = checker.check("the dog", &tokens);
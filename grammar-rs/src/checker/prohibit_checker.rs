@@ -3,7 +3,8 @@
 //! Flags words/phrases that are always wrong and should be replaced.
 //! Examples: "1-moth" → "1-month", "GDPR-complaint" → "GDPR-compliant"
 
-use crate::core::{AnalyzedToken, CheckResult, Match, Severity, TokenKind};
+use crate::core::{AnalyzedToken, CheckResult, IssueType, Match, Severity, TokenKind};
+use crate::core::registry::{RuleCategory, RuleMetadata};
 use crate::core::traits::Checker;
 use crate::checker::data::en_prohibit::{EN_PROHIBIT, is_en_prohibit};
 use std::collections::HashMap;
@@ -94,12 +95,17 @@ impl Checker for ProhibitChecker {
                     rule_id: "PROHIBIT".to_string(),
                     suggestions,
                     severity: Severity::Error,
+                    issue_type: Some(IssueType::Misspelling),
                 });
             }
         }
 
         result
     }
+
+    fn rules(&self) -> Vec<RuleMetadata> {
+        vec![RuleMetadata::new("PROHIBIT", "Prohibited or misspelled word", RuleCategory::Typos)]
+    }
 }
 
 #[cfg(test)]
@@ -4,6 +4,7 @@
 //! in a replacement table. Rules are imported from LanguageTool's
 //! replace.txt via the sync-lt tool.
 
+use crate::core::registry::{RuleCategory, RuleMetadata};
 use crate::core::traits::Checker;
 use crate::core::{AnalyzedToken, CheckResult, Match, Severity, TokenKind};
 
@@ -79,12 +80,26 @@ impl Checker for ReplaceRuleChecker {
                     message: format!("'{}' might be incorrect. Did you mean '{}'?", token.token.text, suggestion),
                     suggestions: vec![suggestion],
                     severity: Severity::Warning,
+                    issue_type: None,
                 });
             }
         }
 
         CheckResult { matches }
     }
+
+    fn rules(&self) -> Vec<RuleMetadata> {
+        self.replacements
+            .iter()
+            .map(|(wrong, correct)| {
+                RuleMetadata::new(
+                    format!("{}_{}", self.rule_prefix, wrong.to_uppercase()),
+                    format!("'{}' instead of '{}'", wrong, correct),
+                    RuleCategory::Typos,
+                )
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -94,15 +109,15 @@ mod tests {
     use std::ops::Range;
 
     fn make_token<'a>(text: &'a str, span: Range<usize>) -> AnalyzedToken<'a> {
-        AnalyzedToken {
-            token: Token {
+        AnalyzedToken::new(
+            Token {
                 text,
                 span,
                 kind: TokenKind::Word,
             },
-            lemma: None,
-            pos: None,
-        }
+            None,
+            None,
+        )
     }
 
     static TEST_REPLACEMENTS: &[(&str, &str)] = &[
@@ -4,13 +4,30 @@
 //! Version 2: Règles chargées depuis un fichier (JSON/TOML)
 //! Version 3: DSL compilé ou pattern matching avancé
 
-use crate::core::{AnalyzedToken, CheckResult, Match, Severity, TokenKind};
-use crate::core::traits::Checker;
+use crate::core::{sentences, AnalyzedToken, CheckLevel, CheckResult, Match, Severity, TokenKind};
+use crate::core::registry::{RuleCategory, RuleMetadata};
+use crate::core::traits::{Checker, SentenceSplitter};
+use crate::tokenizer::SimpleSentenceSplitter;
 
 /// Une règle de grammaire
 pub trait Rule: Send + Sync {
     fn id(&self) -> &str;
     fn check(&self, text: &str, tokens: &[AnalyzedToken]) -> Option<Match>;
+
+    /// Niveau de la règle (les règles de style strictes sont `Picky`)
+    fn level(&self) -> CheckLevel {
+        CheckLevel::Default
+    }
+
+    /// Description courte de la règle (par défaut : son ID)
+    fn description(&self) -> &str {
+        self.id()
+    }
+
+    /// Catégorie LanguageTool de la règle
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Grammar
+    }
 }
 
 /// Checker qui applique une liste de règles
@@ -35,6 +52,13 @@ impl RuleChecker {
             .with_rule(FrenchPunctuationRule)
     }
 
+    /// Charge les règles du français canadien (typographie québécoise)
+    pub fn with_canadian_french_rules(self) -> Self {
+        self.with_rule(DoubleSpaceRule)
+            .with_rule(RepeatedWordRule)
+            .with_rule(CanadianFrenchPunctuationRule)
+    }
+
     /// Charge les règles anglaises par défaut
     pub fn with_english_rules(self) -> Self {
         self.with_rule(DoubleSpaceRule)
@@ -64,9 +88,24 @@ impl Default for RuleChecker {
 
 impl Checker for RuleChecker {
     fn check(&self, text: &str, tokens: &[AnalyzedToken]) -> CheckResult {
+        self.check_with_level(text, tokens, CheckLevel::Picky)
+    }
+
+    fn check_with_level(&self, text: &str, tokens: &[AnalyzedToken], level: CheckLevel) -> CheckResult {
         let mut result = CheckResult::new();
 
-        for rule in &self.rules {
+        // Tokens qui ne sortent pas d'un pipeline : phrases découpées ici
+        let segmented;
+        let tokens = if !tokens.is_empty() && tokens.iter().all(|t| t.sentence.is_empty()) {
+            let mut owned = tokens.to_vec();
+            SimpleSentenceSplitter::new().segment(text, &mut owned);
+            segmented = owned;
+            &segmented[..]
+        } else {
+            tokens
+        };
+
+        for rule in self.rules.iter().filter(|r| r.level() <= level) {
             if let Some(m) = rule.check(text, tokens) {
                 result.matches.push(m);
             }
@@ -74,6 +113,13 @@ impl Checker for RuleChecker {
 
         result
     }
+
+    fn rules(&self) -> Vec<RuleMetadata> {
+        self.rules
+            .iter()
+            .map(|r| RuleMetadata::new(r.id(), r.description(), r.category()).with_level(r.level()))
+            .collect()
+    }
 }
 
 // --- Règles concrètes ---
@@ -86,6 +132,14 @@ impl Rule for DoubleSpaceRule {
         "DOUBLE_SPACE"
     }
 
+    fn description(&self) -> &str {
+        "Two consecutive spaces"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Typography
+    }
+
     fn check(&self, text: &str, _tokens: &[AnalyzedToken]) -> Option<Match> {
         if let Some(pos) = text.find("  ") {
             Some(Match {
@@ -94,6 +148,7 @@ impl Rule for DoubleSpaceRule {
                 rule_id: self.id().to_string(),
                 suggestions: vec![" ".to_string()],
                 severity: Severity::Warning,
+                issue_type: None,
             })
         } else {
             None
@@ -109,6 +164,14 @@ impl Rule for RepeatedWordRule {
         "REPEATED_WORD"
     }
 
+    fn description(&self) -> &str {
+        "Word repeated twice in a row"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Duplication
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -126,6 +189,7 @@ impl Rule for RepeatedWordRule {
                     rule_id: self.id().to_string(),
                     suggestions: vec![a.token.text.to_string()],
                     severity: Severity::Warning,
+                    issue_type: None,
                 });
             }
         }
@@ -142,6 +206,14 @@ impl Rule for FrenchPunctuationRule {
         "FR_PUNCT_SPACE"
     }
 
+    fn description(&self) -> &str {
+        "Space before high punctuation in French"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Typography
+    }
+
     fn check(&self, text: &str, _tokens: &[AnalyzedToken]) -> Option<Match> {
         let chars: Vec<char> = text.chars().collect();
 
@@ -164,6 +236,7 @@ impl Rule for FrenchPunctuationRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec![format!(" {}", c)],
                         severity: Severity::Warning,
+                        issue_type: None,
                     });
                 }
             }
@@ -173,6 +246,46 @@ impl Rule for FrenchPunctuationRule {
     }
 }
 
+/// Espacement de la ponctuation en français canadien
+///
+/// La typographie québécoise ne met d'espace que devant le deux-points ;
+/// aucun espace devant `;`, `!` et `?`.
+pub struct CanadianFrenchPunctuationRule;
+
+impl Rule for CanadianFrenchPunctuationRule {
+    fn id(&self) -> &str {
+        "FR_CA_PUNCT_SPACE"
+    }
+
+    fn description(&self) -> &str {
+        "Space before colon in Canadian French"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Typography
+    }
+
+    fn check(&self, text: &str, _tokens: &[AnalyzedToken]) -> Option<Match> {
+        let mut prev: Option<char> = None;
+
+        for (byte_pos, c) in text.char_indices() {
+            if c == ':' && prev.is_some_and(|p| !p.is_whitespace()) {
+                return Some(Match {
+                    span: byte_pos..byte_pos + c.len_utf8(),
+                    message: "En français, un espace est requis avant ':'".to_string(),
+                    rule_id: self.id().to_string(),
+                    suggestions: vec![" :".to_string()],
+                    severity: Severity::Warning,
+                    issue_type: None,
+                });
+            }
+            prev = Some(c);
+        }
+
+        None
+    }
+}
+
 /// Règle a/an en anglais
 pub struct AAnRule;
 
@@ -181,6 +294,14 @@ impl Rule for AAnRule {
         "EN_A_AN"
     }
 
+    fn description(&self) -> &str {
+        "Use of 'a' vs. 'an'"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Grammar
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -211,6 +332,7 @@ impl Rule for AAnRule {
                     rule_id: self.id().to_string(),
                     suggestions: vec!["an".to_string()],
                     severity: Severity::Error,
+                    issue_type: None,
                 });
             }
 
@@ -225,6 +347,7 @@ impl Rule for AAnRule {
                     rule_id: self.id().to_string(),
                     suggestions: vec!["a".to_string()],
                     severity: Severity::Error,
+                    issue_type: None,
                 });
             }
         }
@@ -241,74 +364,45 @@ impl Rule for UppercaseSentenceStartRule {
         "UPPERCASE_SENTENCE_START"
     }
 
-    fn check(&self, text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
-        // Find first word token
-        let first_word = tokens
-            .iter()
-            .find(|t| t.token.kind == TokenKind::Word)?;
-
-        let first_char = first_word.token.text.chars().next()?;
-
-        // Check if first character is lowercase
-        if first_char.is_lowercase() {
-            let corrected: String = first_word
-                .token
-                .text
-                .chars()
-                .enumerate()
-                .map(|(i, c)| if i == 0 { c.to_uppercase().next().unwrap_or(c) } else { c })
-                .collect();
-
-            return Some(Match {
-                span: first_word.token.span.clone(),
-                message: "La phrase doit commencer par une majuscule".to_string(),
-                rule_id: self.id().to_string(),
-                suggestions: vec![corrected],
-                severity: Severity::Error,
-            });
-        }
+    fn description(&self) -> &str {
+        "Sentence should start with an uppercase letter"
+    }
 
-        // Check after sentence-ending punctuation
-        let sentence_enders = ['.', '!', '?'];
-        let chars: Vec<char> = text.chars().collect();
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Casing
+    }
 
-        for (i, &c) in chars.iter().enumerate() {
-            if sentence_enders.contains(&c) {
-                // Find next word after this punctuation
-                let byte_pos = text.char_indices().nth(i).map(|(p, _)| p).unwrap_or(0);
+    fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
+        for sentence in sentences(tokens) {
+            // Premier mot, après guillemets, tirets et parenthèses ouvrants
+            let Some(first_word) = sentence
+                .iter()
+                .find(|t| !matches!(t.token.kind, TokenKind::Whitespace | TokenKind::Punctuation))
+            else {
+                continue;
+            };
+            if first_word.token.kind != TokenKind::Word {
+                continue;
+            }
 
-                for token in tokens {
-                    if token.token.kind == TokenKind::Word && token.token.span.start > byte_pos {
-                        let first_char = token.token.text.chars().next();
-                        if let Some(fc) = first_char {
-                            if fc.is_lowercase() {
-                                let corrected: String = token
-                                    .token
-                                    .text
-                                    .chars()
-                                    .enumerate()
-                                    .map(|(i, c)| {
-                                        if i == 0 {
-                                            c.to_uppercase().next().unwrap_or(c)
-                                        } else {
-                                            c
-                                        }
-                                    })
-                                    .collect();
+            let first_char = first_word.token.text.chars().next()?;
+            if first_char.is_lowercase() {
+                let corrected: String = first_word
+                    .token
+                    .text
+                    .chars()
+                    .enumerate()
+                    .map(|(i, c)| if i == 0 { c.to_uppercase().next().unwrap_or(c) } else { c })
+                    .collect();
 
-                                return Some(Match {
-                                    span: token.token.span.clone(),
-                                    message: "La phrase doit commencer par une majuscule"
-                                        .to_string(),
-                                    rule_id: self.id().to_string(),
-                                    suggestions: vec![corrected],
-                                    severity: Severity::Error,
-                                });
-                            }
-                        }
-                        break;
-                    }
-                }
+                return Some(Match {
+                    span: first_word.token.span.clone(),
+                    message: "La phrase doit commencer par une majuscule".to_string(),
+                    rule_id: self.id().to_string(),
+                    suggestions: vec![corrected],
+                    severity: Severity::Error,
+                    issue_type: None,
+                });
             }
         }
 
@@ -324,6 +418,14 @@ impl Rule for RepeatedPunctuationRule {
         "REPEATED_PUNCTUATION"
     }
 
+    fn description(&self) -> &str {
+        "Repeated punctuation marks"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Punctuation
+    }
+
     fn check(&self, text: &str, _tokens: &[AnalyzedToken]) -> Option<Match> {
         let chars: Vec<char> = text.chars().collect();
         let punctuation = ['.', '!', '?', ',', ';', ':'];
@@ -356,6 +458,7 @@ impl Rule for RepeatedPunctuationRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec![c.to_string()],
                         severity: Severity::Warning,
+                        issue_type: None,
                     });
                 }
             }
@@ -374,6 +477,14 @@ impl Rule for MissingSpaceAfterPunctRule {
         "MISSING_SPACE_AFTER_PUNCT"
     }
 
+    fn description(&self) -> &str {
+        "Missing space after punctuation"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Typography
+    }
+
     fn check(&self, text: &str, _tokens: &[AnalyzedToken]) -> Option<Match> {
         let chars: Vec<char> = text.chars().collect();
         let punctuation = ['.', '!', '?', ',', ';', ':'];
@@ -461,6 +572,7 @@ impl Rule for MissingSpaceAfterPunctRule {
                     rule_id: self.id().to_string(),
                     suggestions: vec![format!("{} ", c)],
                     severity: Severity::Warning,
+                    issue_type: None,
                 });
             }
         }
@@ -592,6 +704,14 @@ impl Rule for SubjectVerbAgreementRule {
         "SUBJECT_VERB_AGREEMENT"
     }
 
+    fn description(&self) -> &str {
+        "Subject-verb agreement"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Grammar
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -617,6 +737,7 @@ impl Rule for SubjectVerbAgreementRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec![correct_form.to_string()],
                         severity: Severity::Error,
+                        issue_type: None,
                     });
                 }
             }
@@ -633,6 +754,7 @@ impl Rule for SubjectVerbAgreementRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec![correct_form.to_string()],
                         severity: Severity::Error,
+                        issue_type: None,
                     });
                 }
             }
@@ -682,6 +804,14 @@ impl Rule for ItsItsRule {
         "EN_ITS_ITS"
     }
 
+    fn description(&self) -> &str {
+        "Confusion of 'its' and 'it's'"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::ConfusedWords
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -707,6 +837,7 @@ impl Rule for ItsItsRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec!["it's".to_string()],
                         severity: Severity::Error,
+                        issue_type: None,
                     });
                 }
             }
@@ -723,6 +854,7 @@ impl Rule for ItsItsRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec!["its".to_string()],
                         severity: Severity::Error,
+                        issue_type: None,
                     });
                 }
             }
@@ -773,6 +905,14 @@ impl Rule for YourYoureRule {
         "EN_YOUR_YOURE"
     }
 
+    fn description(&self) -> &str {
+        "Confusion of 'your' and 'you're'"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::ConfusedWords
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -798,6 +938,7 @@ impl Rule for YourYoureRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec!["you're".to_string()],
                         severity: Severity::Error,
+                        issue_type: None,
                     });
                 }
             }
@@ -814,6 +955,7 @@ impl Rule for YourYoureRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec!["your".to_string()],
                         severity: Severity::Error,
+                        issue_type: None,
                     });
                 }
             }
@@ -874,6 +1016,14 @@ impl Rule for TheirTheyreThereRule {
         "EN_THEIR_THEYRE_THERE"
     }
 
+    fn description(&self) -> &str {
+        "Confusion of 'their', 'they're' and 'there'"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::ConfusedWords
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -899,6 +1049,7 @@ impl Rule for TheirTheyreThereRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec!["they're".to_string()],
                         severity: Severity::Error,
+                        issue_type: None,
                     });
                 }
             }
@@ -915,6 +1066,7 @@ impl Rule for TheirTheyreThereRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec!["their".to_string()],
                         severity: Severity::Error,
+                        issue_type: None,
                     });
                 }
                 // "there" in contraction context -> should be "they're"
@@ -928,6 +1080,7 @@ impl Rule for TheirTheyreThereRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec!["they're".to_string()],
                         severity: Severity::Error,
+                        issue_type: None,
                     });
                 }
             }
@@ -944,6 +1097,7 @@ impl Rule for TheirTheyreThereRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec!["their".to_string()],
                         severity: Severity::Error,
+                        issue_type: None,
                     });
                 }
             }
@@ -1019,7 +1173,26 @@ impl Rule for CommaSpliceRule {
         "COMMA_SPLICE"
     }
 
+    fn description(&self) -> &str {
+        "Comma splice between independent clauses"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Punctuation
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
+        for sentence in sentences(tokens) {
+            if let Some(m) = self.check_sentence(sentence) {
+                return Some(m);
+            }
+        }
+        None
+    }
+}
+
+impl CommaSpliceRule {
+    fn check_sentence(&self, tokens: &[AnalyzedToken]) -> Option<Match> {
         // Find commas
         for (i, token) in tokens.iter().enumerate() {
             if token.token.kind == TokenKind::Punctuation && token.token.text == "," {
@@ -1087,6 +1260,7 @@ impl Rule for CommaSpliceRule {
                                                 ", but".to_string(),
                                             ],
                                             severity: Severity::Warning,
+                                            issue_type: None,
                                         });
                                     }
                                 }
@@ -1170,6 +1344,14 @@ impl Rule for ImprovedAAnRule {
         "EN_A_AN_IMPROVED"
     }
 
+    fn description(&self) -> &str {
+        "Use of 'a' vs. 'an' (pronunciation-based)"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Grammar
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -1194,6 +1376,7 @@ impl Rule for ImprovedAAnRule {
                     rule_id: self.id().to_string(),
                     suggestions: vec!["an".to_string()],
                     severity: Severity::Error,
+                    issue_type: None,
                 });
             }
 
@@ -1208,6 +1391,7 @@ impl Rule for ImprovedAAnRule {
                     rule_id: self.id().to_string(),
                     suggestions: vec!["a".to_string()],
                     severity: Severity::Error,
+                    issue_type: None,
                 });
             }
         }
@@ -1248,6 +1432,14 @@ impl Rule for FrenchAAccentRule {
         "FR_A_ACCENT"
     }
 
+    fn description(&self) -> &str {
+        "Confusion de « a » et « à »"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::ConfusedWords
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -1275,6 +1467,7 @@ impl Rule for FrenchAAccentRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec!["à".to_string()],
                         severity: Severity::Error,
+                        issue_type: None,
                     });
                 }
             }
@@ -1306,6 +1499,14 @@ impl Rule for FrenchOuAccentRule {
         "FR_OU_ACCENT"
     }
 
+    fn description(&self) -> &str {
+        "Confusion de « ou » et « où »"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::ConfusedWords
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -1327,6 +1528,7 @@ impl Rule for FrenchOuAccentRule {
                             rule_id: self.id().to_string(),
                             suggestions: vec!["où".to_string()],
                             severity: Severity::Error,
+                            issue_type: None,
                         });
                     }
                 }
@@ -1341,6 +1543,7 @@ impl Rule for FrenchOuAccentRule {
                             rule_id: self.id().to_string(),
                             suggestions: vec!["où".to_string()],
                             severity: Severity::Error,
+                            issue_type: None,
                         });
                     }
                 }
@@ -1383,6 +1586,14 @@ impl Rule for FrenchCeSeRule {
         "FR_CE_SE"
     }
 
+    fn description(&self) -> &str {
+        "Confusion de « ce » et « se »"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::ConfusedWords
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -1408,6 +1619,7 @@ impl Rule for FrenchCeSeRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec!["se".to_string()],
                         severity: Severity::Error,
+                        issue_type: None,
                     });
                 }
             }
@@ -1424,6 +1636,7 @@ impl Rule for FrenchCeSeRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec!["ce".to_string()],
                         severity: Severity::Error,
+                        issue_type: None,
                     });
                 }
             }
@@ -1506,6 +1719,14 @@ impl Rule for FrenchSubjectVerbRule {
         "FR_SUBJECT_VERB"
     }
 
+    fn description(&self) -> &str {
+        "Accord sujet-verbe"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Grammar
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -1538,6 +1759,7 @@ impl Rule for FrenchSubjectVerbRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec![sing.to_string()],
                         severity: Severity::Error,
+                        issue_type: None,
                     });
                 }
                 if is_plural && verb_lower == *sing {
@@ -1550,6 +1772,7 @@ impl Rule for FrenchSubjectVerbRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec![plur.to_string()],
                         severity: Severity::Error,
+                        issue_type: None,
                     });
                 }
             }
@@ -1569,6 +1792,7 @@ impl Rule for FrenchSubjectVerbRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec![sing_form],
                         severity: Severity::Error,
+                        issue_type: None,
                     });
                 }
                 if is_plural && verb_lower == sing_form {
@@ -1581,6 +1805,7 @@ impl Rule for FrenchSubjectVerbRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec![plur_form],
                         severity: Severity::Error,
+                        issue_type: None,
                     });
                 }
             }
@@ -1653,6 +1878,14 @@ impl Rule for FrenchAdjectiveNounRule {
         "FR_ADJ_NOUN"
     }
 
+    fn description(&self) -> &str {
+        "Accord adjectif-nom"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Grammar
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -1696,6 +1929,7 @@ impl Rule for FrenchAdjectiveNounRule {
                             rule_id: self.id().to_string(),
                             suggestions: vec![fem.to_string()],
                             severity: Severity::Error,
+                            issue_type: None,
                         });
                     }
                 }
@@ -1713,6 +1947,7 @@ impl Rule for FrenchAdjectiveNounRule {
                             rule_id: self.id().to_string(),
                             suggestions: vec![masc.to_string()],
                             severity: Severity::Error,
+                            issue_type: None,
                         });
                     }
                 }
@@ -1750,6 +1985,7 @@ impl Rule for FrenchAdjectiveNounRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec![masc.to_string()],
                         severity: Severity::Error,
+                        issue_type: None,
                     });
                 }
 
@@ -1764,6 +2000,7 @@ impl Rule for FrenchAdjectiveNounRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec![fem.to_string()],
                         severity: Severity::Error,
+                        issue_type: None,
                     });
                 }
             }
@@ -1787,6 +2024,18 @@ impl Rule for TypographicQuotesRule {
         "TYPOGRAPHIC_QUOTES"
     }
 
+    fn description(&self) -> &str {
+        "Straight quotes instead of typographic quotes"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Typography
+    }
+
+    fn level(&self) -> CheckLevel {
+        CheckLevel::Picky
+    }
+
     fn check(&self, text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         // Look for straight double quotes
         for token in tokens {
@@ -1802,6 +2051,7 @@ impl Rule for TypographicQuotesRule {
                         "\u{00BB}".to_string(),  // » right guillemet
                     ],
                     severity: Severity::Hint,
+                    issue_type: None,
                 });
             }
         }
@@ -1821,6 +2071,7 @@ impl Rule for TypographicQuotesRule {
                         "\u{00BB}".to_string(),  // » right guillemet
                     ],
                     severity: Severity::Hint,
+                    issue_type: None,
                 });
             }
         }
@@ -1891,6 +2142,18 @@ impl Rule for PassiveVoiceRule {
         "PASSIVE_VOICE"
     }
 
+    fn description(&self) -> &str {
+        "Passive voice"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Style
+    }
+
+    fn level(&self) -> CheckLevel {
+        CheckLevel::Picky
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -1915,6 +2178,7 @@ impl Rule for PassiveVoiceRule {
                     rule_id: self.id().to_string(),
                     suggestions: vec![],
                     severity: Severity::Hint,
+                    issue_type: None,
                 });
             }
         }
@@ -1942,6 +2206,7 @@ impl Rule for PassiveVoiceRule {
                     rule_id: self.id().to_string(),
                     suggestions: vec![],
                     severity: Severity::Hint,
+                    issue_type: None,
                 });
             }
         }
@@ -1989,6 +2254,18 @@ impl Rule for WordinessRule {
         "WORDINESS"
     }
 
+    fn description(&self) -> &str {
+        "Wordy phrases"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Style
+    }
+
+    fn level(&self) -> CheckLevel {
+        CheckLevel::Picky
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -2027,6 +2304,7 @@ impl Rule for WordinessRule {
                             rule_id: self.id().to_string(),
                             suggestions: vec![replacement.to_string()],
                             severity: Severity::Hint,
+                            issue_type: None,
                         });
                     }
                 }
@@ -2061,6 +2339,14 @@ impl Rule for SentenceFragmentRule {
         "SENTENCE_FRAGMENT"
     }
 
+    fn description(&self) -> &str {
+        "Sentence fragment"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Grammar
+    }
+
     fn check(&self, text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -2091,6 +2377,7 @@ impl Rule for SentenceFragmentRule {
                     rule_id: self.id().to_string(),
                     suggestions: vec![],
                     severity: Severity::Warning,
+                    issue_type: None,
                 });
             }
         }
@@ -2128,6 +2415,14 @@ impl Rule for LessFewerRule {
         "LESS_FEWER"
     }
 
+    fn description(&self) -> &str {
+        "Use of 'less' vs. 'fewer'"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::ConfusedWords
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -2149,6 +2444,7 @@ impl Rule for LessFewerRule {
                             rule_id: self.id().to_string(),
                             suggestions: vec!["fewer".to_string()],
                             severity: Severity::Warning,
+                            issue_type: None,
                         });
                     }
                 }
@@ -2176,6 +2472,14 @@ impl Rule for WhoWhomRule {
         "WHO_WHOM"
     }
 
+    fn description(&self) -> &str {
+        "Use of 'who' vs. 'whom'"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Grammar
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -2201,6 +2505,7 @@ impl Rule for WhoWhomRule {
                                         rule_id: self.id().to_string(),
                                         suggestions: vec!["Whom".to_string(), "whom".to_string()],
                                         severity: Severity::Hint,
+                                        issue_type: None,
                                     });
                                 }
                             }
@@ -2219,6 +2524,7 @@ impl Rule for WhoWhomRule {
                                 rule_id: self.id().to_string(),
                                 suggestions: vec!["whom".to_string()],
                                 severity: Severity::Warning,
+                                issue_type: None,
                             });
                         }
                     }
@@ -2255,6 +2561,14 @@ impl Rule for GoodWellRule {
         "GOOD_WELL"
     }
 
+    fn description(&self) -> &str {
+        "Use of 'good' vs. 'well'"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Grammar
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -2272,6 +2586,7 @@ impl Rule for GoodWellRule {
                             rule_id: self.id().to_string(),
                             suggestions: vec!["well".to_string()],
                             severity: Severity::Warning,
+                            issue_type: None,
                         });
                     }
                 }
@@ -2302,6 +2617,14 @@ impl Rule for DoubleNegativeRule {
         "DOUBLE_NEGATIVE"
     }
 
+    fn description(&self) -> &str {
+        "Double negative"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Grammar
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -2323,6 +2646,7 @@ impl Rule for DoubleNegativeRule {
                         rule_id: self.id().to_string(),
                         suggestions: vec![],
                         severity: Severity::Warning,
+                        issue_type: None,
                     });
                 }
                 if first_neg_span.is_none() {
@@ -2373,6 +2697,14 @@ impl Rule for FrenchConditionnelSiRule {
         "FR_CONDITIONNEL_SI"
     }
 
+    fn description(&self) -> &str {
+        "Conditionnel après « si »"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Grammar
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -2407,6 +2739,7 @@ impl Rule for FrenchConditionnelSiRule {
                                 rule_id: self.id().to_string(),
                                 suggestions: correction.map(|c| c.to_string()).into_iter().collect(),
                                 severity: Severity::Error,
+                                issue_type: None,
                             });
                         }
                         break; // Only check first non-pronoun word
@@ -2439,6 +2772,14 @@ impl Rule for FrenchToutAccordRule {
         "FR_TOUT_ACCORD"
     }
 
+    fn description(&self) -> &str {
+        "Accord de « tout »"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Grammar
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -2464,6 +2805,7 @@ impl Rule for FrenchToutAccordRule {
                                     rule_id: self.id().to_string(),
                                     suggestions: vec!["toutes".to_string()],
                                     severity: Severity::Warning,
+                                    issue_type: None,
                                 });
                             }
                         }
@@ -2474,6 +2816,7 @@ impl Rule for FrenchToutAccordRule {
                             rule_id: self.id().to_string(),
                             suggestions: vec!["tous".to_string(), "toutes".to_string()],
                             severity: Severity::Warning,
+                            issue_type: None,
                         });
                     }
                 }
@@ -2513,15 +2856,33 @@ impl Rule for SentenceLengthRule {
         "SENTENCE_LENGTH"
     }
 
-    fn check(&self, text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
-        let word_count = tokens
-            .iter()
-            .filter(|t| t.token.kind == TokenKind::Word)
-            .count();
+    fn description(&self) -> &str {
+        "Sentence is too long"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Style
+    }
+
+    fn level(&self) -> CheckLevel {
+        CheckLevel::Picky
+    }
 
-        if word_count > self.max_words {
+    fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
+        for sentence in sentences(tokens) {
+            let word_count = sentence
+                .iter()
+                .filter(|t| t.token.kind == TokenKind::Word)
+                .count();
+            if word_count <= self.max_words {
+                continue;
+            }
+
+            let mut content = sentence.iter().filter(|t| t.token.kind != TokenKind::Whitespace);
+            let first = content.next()?;
+            let last = content.next_back().unwrap_or(first);
             return Some(Match {
-                span: 0..text.len().min(50),
+                span: first.token.span.start..last.token.span.end,
                 message: format!(
                     "Long sentence ({} words). Consider breaking it into shorter sentences for readability.",
                     word_count
@@ -2529,6 +2890,7 @@ impl Rule for SentenceLengthRule {
                 rule_id: self.id().to_string(),
                 suggestions: vec![],
                 severity: Severity::Hint,
+                issue_type: None,
             });
         }
         None
@@ -2569,6 +2931,18 @@ impl Rule for ClicheRule {
         "CLICHE"
     }
 
+    fn description(&self) -> &str {
+        "Clichés and overused phrases"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Style
+    }
+
+    fn level(&self) -> CheckLevel {
+        CheckLevel::Picky
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -2603,6 +2977,7 @@ impl Rule for ClicheRule {
                             rule_id: self.id().to_string(),
                             suggestions: vec![],
                             severity: Severity::Hint,
+                            issue_type: None,
                         });
                     }
                 }
@@ -2664,6 +3039,18 @@ impl Rule for RedundancyRule {
         "REDUNDANCY"
     }
 
+    fn description(&self) -> &str {
+        "Redundant phrases"
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Redundancy
+    }
+
+    fn level(&self) -> CheckLevel {
+        CheckLevel::Picky
+    }
+
     fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> Option<Match> {
         let words: Vec<_> = tokens
             .iter()
@@ -2697,6 +3084,7 @@ impl Rule for RedundancyRule {
                             rule_id: self.id().to_string(),
                             suggestions: vec![suggestion.to_string()],
                             severity: Severity::Hint,
+                            issue_type: None,
                         });
                     }
                 }
@@ -2750,6 +3138,32 @@ mod tests {
         assert!(result.matches[0].message.contains("espace"));
     }
 
+    #[test]
+    fn test_picky_rules_skipped_at_default_level() {
+        let checker = RuleChecker::new()
+            .with_rule(RepeatedWordRule)
+            .with_rule(PassiveVoiceRule);
+        let text = "The the cake was eaten by the dog.";
+        let analyzed = PassthroughAnalyzer::new().analyze(SimpleTokenizer::new().tokenize(text));
+
+        let picky = checker.check_with_level(text, &analyzed, CheckLevel::Picky);
+        assert!(picky.matches.iter().any(|m| m.rule_id == "PASSIVE_VOICE"));
+
+        let default = checker.check_with_level(text, &analyzed, CheckLevel::Default);
+        assert!(default.matches.iter().any(|m| m.rule_id == "REPEATED_WORD"));
+        assert!(!default.matches.iter().any(|m| m.rule_id == "PASSIVE_VOICE"));
+    }
+
+    #[test]
+    fn test_canadian_french_punctuation() {
+        let checker = RuleChecker::new().with_rule(CanadianFrenchPunctuationRule);
+        assert!(check_text("Comment ça va? Très bien!", &checker).matches.is_empty());
+
+        let result = check_text("Voici la liste: pommes", &checker);
+        assert_eq!(result.matches.len(), 1);
+        assert_eq!(result.matches[0].rule_id, "FR_CA_PUNCT_SPACE");
+    }
+
     #[test]
     fn test_uppercase_sentence_start() {
         let checker = RuleChecker::new().with_rule(UppercaseSentenceStartRule);
@@ -2768,6 +3182,26 @@ mod tests {
         let result = check_text("Hello. world", &checker);
         assert_eq!(result.matches.len(), 1);
         assert_eq!(result.matches[0].suggestions, vec!["World"]);
+
+        // Abbreviations, decimals and URLs don't end the sentence
+        let result = check_text("Use fruit, e.g. apples. It costs 3.50 at example.com today.", &checker);
+        assert_eq!(result.matches.len(), 0);
+    }
+
+    #[test]
+    fn test_sentence_rules_use_sentence_spans() {
+        let checker = RuleChecker::new()
+            .with_rule(SentenceLengthRule::with_max_words(5))
+            .with_rule(CommaSpliceRule);
+
+        let text = "Short one. This second sentence has quite a few words in it.";
+        let result = check_text(text, &checker);
+        assert_eq!(result.matches.len(), 1);
+        assert_eq!(&text[result.matches[0].span.clone()], "This second sentence has quite a few words in it.");
+
+        // The subordinator belongs to the previous sentence
+        let result = check_text("Stay if you want. Then, I went home, I was tired.", &checker);
+        assert!(result.matches.iter().any(|m| m.rule_id == "COMMA_SPLICE"));
     }
 
     #[test]
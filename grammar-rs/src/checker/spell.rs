@@ -3,12 +3,17 @@
 //! Supports two dictionary backends:
 //! - HashSet: Simple, good for small dictionaries
 //! - FstDictionary: Memory-efficient, fast, good for large dictionaries
+//!
+//! Suggestions come from a Levenshtein automaton walked over an FST (the
+//! HashSet backend builds one on first use), ranked by `SuggestionRanker`.
 
-use crate::core::{AnalyzedToken, CheckResult, Match, Severity, TokenKind};
+use crate::core::{AnalyzedToken, CheckResult, IssueType, Match, Severity, TokenKind};
+use crate::core::registry::{RuleCategory, RuleMetadata};
 use crate::core::traits::{Checker, Suggester};
 use crate::dictionary::FstDictionary;
+use super::spell_ranking::SuggestionRanker;
 use std::collections::HashSet;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 /// Dictionary backend for spell checking
 enum DictionaryBackend {
@@ -20,34 +25,41 @@ pub struct SpellChecker {
     backend: DictionaryBackend,
     max_edit_distance: usize,
     skip_words: HashSet<String>,
+    ranker: SuggestionRanker,
+    /// FST built from the HashSet backend for suggestions (lazy)
+    suggestion_index: OnceLock<FstDictionary>,
 }
 
 impl SpellChecker {
-    /// Create a new spell checker with empty HashSet backend
-    pub fn new() -> Self {
+    fn with_backend(backend: DictionaryBackend) -> Self {
         Self {
-            backend: DictionaryBackend::HashSet(HashSet::new()),
+            backend,
             max_edit_distance: 2,
             skip_words: HashSet::new(),
+            ranker: SuggestionRanker::new(),
+            suggestion_index: OnceLock::new(),
         }
     }
 
+    /// Create a new spell checker with empty HashSet backend
+    pub fn new() -> Self {
+        Self::with_backend(DictionaryBackend::HashSet(HashSet::new()))
+    }
+
     /// Create a spell checker with an FST dictionary
     pub fn with_fst_dictionary(dict: FstDictionary) -> Self {
-        Self {
-            backend: DictionaryBackend::Fst(Arc::new(dict)),
-            max_edit_distance: 2,
-            skip_words: HashSet::new(),
-        }
+        Self::with_backend(DictionaryBackend::Fst(Arc::new(dict)))
     }
 
     /// Create a spell checker with a shared FST dictionary
     pub fn with_shared_fst(dict: Arc<FstDictionary>) -> Self {
-        Self {
-            backend: DictionaryBackend::Fst(dict),
-            max_edit_distance: 2,
-            skip_words: HashSet::new(),
-        }
+        Self::with_backend(DictionaryBackend::Fst(dict))
+    }
+
+    /// Set how suggestions are ranked (keyboard layout, word frequencies)
+    pub fn with_ranker(mut self, ranker: SuggestionRanker) -> Self {
+        self.ranker = ranker;
+        self
     }
 
     /// Add words to skip during spell checking (proper nouns, acronyms, etc.)
@@ -73,6 +85,7 @@ impl SpellChecker {
         I: IntoIterator<Item = S>,
         S: AsRef<str>,
     {
+        self.suggestion_index = OnceLock::new();
         match &mut self.backend {
             DictionaryBackend::HashSet(set) => {
                 for word in words {
@@ -165,111 +178,50 @@ impl Checker for SpellChecker {
                     rule_id: "SPELL".to_string(),
                     suggestions,
                     severity: Severity::Error,
+                    issue_type: Some(IssueType::Misspelling),
                 });
             }
         }
 
         result
     }
-}
 
-impl Suggester for SpellChecker {
-    fn suggest(&self, word: &str, max: usize) -> Vec<String> {
-        let lower = word.to_lowercase();
+    fn rules(&self) -> Vec<RuleMetadata> {
+        vec![RuleMetadata::new("SPELL", "Possible spelling mistake", RuleCategory::Typos)]
+    }
+}
 
+impl SpellChecker {
+    /// FST used to generate suggestion candidates
+    fn suggestion_dictionary(&self) -> &FstDictionary {
         match &self.backend {
-            DictionaryBackend::HashSet(set) => {
-                let mut candidates: Vec<(String, usize)> = Vec::new();
-
-                for dict_word in set {
-                    let dist = levenshtein(&lower, dict_word);
-                    if dist <= self.max_edit_distance {
-                        candidates.push((dict_word.clone(), dist));
-                    }
-                }
-
-                // Trier par distance puis alphabétiquement
-                candidates.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
-
-                candidates
-                    .into_iter()
-                    .take(max)
-                    .map(|(w, _)| w)
-                    .collect()
-            }
-            DictionaryBackend::Fst(dict) => {
-                // For FST, we use prefix-based suggestions + edit distance
-                // This is a simplified version - a full implementation would use SymSpell
-                let mut candidates: Vec<(String, usize)> = Vec::new();
-
-                // Try prefix matches first (fast)
-                if lower.len() >= 2 {
-                    let prefix = &lower[..2.min(lower.len())];
-                    for dict_word in dict.words_with_prefix(prefix) {
-                        let dist = levenshtein(&lower, &dict_word);
-                        if dist <= self.max_edit_distance {
-                            candidates.push((dict_word, dist));
-                        }
-                    }
-                }
-
-                // Also try with first char only for more coverage
-                if candidates.len() < max && !lower.is_empty() {
-                    let prefix = &lower[..1];
-                    for dict_word in dict.words_with_prefix(prefix) {
-                        let dist = levenshtein(&lower, &dict_word);
-                        if dist <= self.max_edit_distance {
-                            if !candidates.iter().any(|(w, _)| w == &dict_word) {
-                                candidates.push((dict_word, dist));
-                            }
-                        }
-                    }
-                }
-
-                candidates.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
-
-                candidates
-                    .into_iter()
-                    .take(max)
-                    .map(|(w, _)| w)
-                    .collect()
-            }
+            DictionaryBackend::Fst(dict) => dict,
+            DictionaryBackend::HashSet(set) => self.suggestion_index.get_or_init(|| {
+                FstDictionary::from_iter(set.iter()).unwrap_or_default()
+            }),
         }
     }
 }
 
-/// Distance de Levenshtein simple
-/// Version 2: utiliser triple_accel ou strsim crate
-fn levenshtein(a: &str, b: &str) -> usize {
-    let a: Vec<char> = a.chars().collect();
-    let b: Vec<char> = b.chars().collect();
-
-    if a.is_empty() {
-        return b.len();
-    }
-    if b.is_empty() {
-        return a.len();
-    }
-
-    let mut matrix = vec![vec![0usize; b.len() + 1]; a.len() + 1];
-
-    for i in 0..=a.len() {
-        matrix[i][0] = i;
-    }
-    for j in 0..=b.len() {
-        matrix[0][j] = j;
-    }
+impl Suggester for SpellChecker {
+    fn suggest(&self, word: &str, max: usize) -> Vec<String> {
+        let lower = word.to_lowercase();
 
-    for i in 1..=a.len() {
-        for j in 1..=b.len() {
-            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
-            matrix[i][j] = (matrix[i - 1][j] + 1)
-                .min(matrix[i][j - 1] + 1)
-                .min(matrix[i - 1][j - 1] + cost);
-        }
+        // Levenshtein automaton: only visits FST branches within the distance.
+        // Every candidate up to `max_edit_distance` is kept: the ranker weighs
+        // the edit cost against sound and frequency (`ocured` → `occurred`
+        // rather than `cured`).
+        let candidates: Vec<String> = self.suggestion_dictionary()
+            .fuzzy(&lower, self.max_edit_distance)
+            .into_iter()
+            .filter(|(candidate, _)| *candidate != lower)
+            .map(|(candidate, _)| candidate)
+            .collect();
+
+        let mut ranked = self.ranker.rank(&lower, candidates);
+        ranked.truncate(max);
+        ranked
     }
-
-    matrix[a.len()][b.len()]
 }
 
 #[cfg(test)]
@@ -297,10 +249,34 @@ mod tests {
     }
 
     #[test]
-    fn test_levenshtein() {
-        assert_eq!(levenshtein("hello", "hello"), 0);
-        assert_eq!(levenshtein("hello", "helo"), 1);
-        assert_eq!(levenshtein("hello", "world"), 4);
+    fn test_fst_suggestions_with_accented_words() {
+        let dict = FstDictionary::from_iter(["été", "étés", "mangé", "manger", "mangeons"]).unwrap();
+        let checker = SpellChecker::with_fst_dictionary(dict);
+
+        assert!(checker.is_valid("mangeons"));
+        assert_eq!(checker.suggest("éte", 3).first().map(String::as_str), Some("été"));
+        assert!(checker.suggest("mangeon", 3).contains(&"mangeons".to_string()));
+    }
+
+    #[test]
+    fn test_suggestions_are_ranked() {
+        let checker = SpellChecker::new().with_words(["test", "tent", "text", "best", "for", "por"]);
+        // Transposition first; adjacent-key substitution before a distant one
+        assert_eq!(checker.suggest("tset", 1), vec!["test"]);
+        assert_eq!(checker.suggest("gor", 2), vec!["for", "por"]);
+
+        // The index is rebuilt when words are added
+        let mut checker = checker;
+        checker.load_words(["tsetse"]);
+        assert_eq!(checker.suggest("tsets", 1), vec!["tsetse"]);
+    }
+
+    #[test]
+    fn test_suggestions_span_edit_distances() {
+        // "cured" is one edit away, but "occurred" sounds like the typo
+        let checker = SpellChecker::new().with_words(["cured", "occurred"]);
+        assert_eq!(checker.suggest("ocured", 2), vec!["occurred", "cured"]);
+        assert_eq!(checker.with_max_edit_distance(1).suggest("ocured", 2), vec!["cured"]);
     }
 
     #[test]
@@ -0,0 +1,249 @@
+//! Ranking of spelling suggestions
+//!
+//! Candidates come from the Levenshtein automaton; they are ordered by a
+//! score where lower is better:
+//! - edit cost weighted by keyboard adjacency (`gor` → `for` beats `por`),
+//!   accents (`ete` → `été`) and doubled letters (`ocured` → `occurred`)
+//! - minus a bonus when both words sound alike (same phonetic key)
+//! - minus a bonus for frequent words (common word list or n-gram unigrams)
+
+use std::sync::Arc;
+
+use crate::language_model::CompactNgramModel;
+
+/// Keyboard layout used for substitution costs
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum KeyboardLayout {
+    #[default]
+    Qwerty,
+    Azerty,
+}
+
+impl KeyboardLayout {
+    fn rows(self) -> [&'static str; 3] {
+        match self {
+            KeyboardLayout::Qwerty => ["qwertyuiop", "asdfghjkl", "zxcvbnm"],
+            KeyboardLayout::Azerty => ["azertyuiop", "qsdfghjklm", "wxcvbn"],
+        }
+    }
+
+    /// (row, horizontal position) of a key, rows being staggered by half a key
+    fn position(self, c: char) -> Option<(usize, f32)> {
+        self.rows().iter().enumerate().find_map(|(row, keys)| {
+            keys.chars().position(|k| k == c).map(|col| (row, col as f32 + row as f32 * 0.5))
+        })
+    }
+
+    /// True when both letters are neighbouring keys
+    pub fn adjacent(self, a: char, b: char) -> bool {
+        match (self.position(a), self.position(b)) {
+            (Some((ra, xa)), Some((rb, xb))) => a != b && ra.abs_diff(rb) <= 1 && (xa - xb).abs() <= 1.0,
+            _ => false,
+        }
+    }
+}
+
+/// Source of word frequencies for ranking
+#[derive(Clone, Default)]
+pub enum WordFrequency {
+    #[default]
+    None,
+    /// Sorted list of common words (`EN_COMMON_WORDS`, `FR_COMMON_WORDS`)
+    CommonWords(&'static [&'static str]),
+    /// Unigram counts of an n-gram model
+    Ngrams(Arc<CompactNgramModel>),
+}
+
+impl WordFrequency {
+    /// Ranking bonus for a word, between 0.0 and 0.6
+    fn bonus(&self, word: &str) -> f64 {
+        match self {
+            WordFrequency::None => 0.0,
+            WordFrequency::CommonWords(words) => {
+                if words.binary_search(&word).is_ok() { 0.3 } else { 0.0 }
+            }
+            WordFrequency::Ngrams(model) => model.get_unigram(word)
+                .map_or(0.0, |count| (0.1 * (count as f64 + 1.0).log10()).min(0.6)),
+        }
+    }
+}
+
+/// Lowercase letter without its accent (`é` → `e`, `ç` → `c`)
+fn base_letter(c: char) -> char {
+    match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+        'ç' => 'c',
+        'è' | 'é' | 'ê' | 'ë' => 'e',
+        'ì' | 'í' | 'î' | 'ï' => 'i',
+        'ñ' => 'n',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' => 'o',
+        'ù' | 'ú' | 'û' | 'ü' => 'u',
+        'ý' | 'ÿ' => 'y',
+        _ => c,
+    }
+}
+
+/// Simplified phonetic key: consonant skeleton after common spelling merges
+///
+/// `fonetik` and `phonetic` share the key `FNTK`.
+pub fn phonetic_key(word: &str) -> String {
+    let chars: Vec<char> = word.to_lowercase().chars().map(base_letter).collect();
+    let mut key = String::new();
+
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let (sound, skip) = match (c, next) {
+            ('p', Some('h')) => ("F", 2),
+            ('c', Some('k')) | ('q', Some('u')) => ("K", 2),
+            ('c', Some('e' | 'i' | 'y')) => ("S", 1),
+            ('c' | 'k' | 'q', _) => ("K", 1),
+            ('x', _) => ("KS", 1),
+            ('s' | 'z', _) => ("S", 1),
+            ('g', Some('h')) => ("", 2),
+            ('a' | 'e' | 'i' | 'o' | 'u' | 'y', _) if i == 0 => ("A", 1),
+            ('a' | 'e' | 'i' | 'o' | 'u' | 'y' | 'h' | 'w' | '\'' | '-', _) => ("", 1),
+            _ => {
+                let upper = c.to_ascii_uppercase();
+                if !key.ends_with(upper) {
+                    key.push(upper);
+                }
+                i += 1;
+                continue;
+            }
+        };
+        if !sound.is_empty() && !key.ends_with(sound) {
+            key.push_str(sound);
+        }
+        i += skip;
+    }
+    key
+}
+
+/// Ranks spelling candidates for a misspelled word
+#[derive(Clone, Default)]
+pub struct SuggestionRanker {
+    layout: KeyboardLayout,
+    frequency: WordFrequency,
+}
+
+impl SuggestionRanker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_layout(mut self, layout: KeyboardLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    pub fn with_frequency(mut self, frequency: WordFrequency) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    fn substitution_cost(&self, a: char, b: char) -> f64 {
+        if a == b {
+            0.0
+        } else if base_letter(a) == base_letter(b) {
+            0.25
+        } else if self.layout.adjacent(base_letter(a), base_letter(b)) {
+            0.5
+        } else {
+            1.0
+        }
+    }
+
+    /// Damerau-Levenshtein distance with weighted edits
+    pub fn edit_cost(&self, word: &str, candidate: &str) -> f64 {
+        let a: Vec<char> = word.chars().collect();
+        let b: Vec<char> = candidate.chars().collect();
+        // Adding or dropping a doubled letter is a cheap, frequent typo
+        let indel = |s: &[char], i: usize| if i > 1 && s[i - 1] == s[i - 2] { 0.5 } else { 1.0 };
+
+        let mut d = vec![vec![0.0_f64; b.len() + 1]; a.len() + 1];
+        for i in 1..=a.len() {
+            d[i][0] = d[i - 1][0] + indel(&a, i);
+        }
+        for j in 1..=b.len() {
+            d[0][j] = d[0][j - 1] + indel(&b, j);
+        }
+        for i in 1..=a.len() {
+            for j in 1..=b.len() {
+                let mut cost = (d[i - 1][j] + indel(&a, i))
+                    .min(d[i][j - 1] + indel(&b, j))
+                    .min(d[i - 1][j - 1] + self.substitution_cost(a[i - 1], b[j - 1]));
+                if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                    cost = cost.min(d[i - 2][j - 2] + 0.75);
+                }
+                d[i][j] = cost;
+            }
+        }
+        d[a.len()][b.len()]
+    }
+
+    /// Score of a candidate (lower is better)
+    pub fn score(&self, word: &str, candidate: &str) -> f64 {
+        let phonetic = if phonetic_key(word) == phonetic_key(candidate) { 0.4 } else { 0.0 };
+        self.edit_cost(word, candidate) - phonetic - self.frequency.bonus(candidate)
+    }
+
+    /// Sort candidates best first (ties broken alphabetically)
+    pub fn rank(&self, word: &str, candidates: Vec<String>) -> Vec<String> {
+        let mut scored: Vec<(f64, String)> = candidates.into_iter()
+            .map(|c| (self.score(word, &c), c))
+            .collect();
+        scored.sort_by(|a, b| a.0.total_cmp(&b.0).then_with(|| a.1.cmp(&b.1)));
+        scored.into_iter().map(|(_, c)| c).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rank(ranker: &SuggestionRanker, word: &str, candidates: &[&str]) -> Vec<String> {
+        ranker.rank(word, candidates.iter().map(|c| c.to_string()).collect())
+    }
+
+    #[test]
+    fn test_keyboard_adjacency() {
+        assert!(KeyboardLayout::Qwerty.adjacent('s', 'd'));
+        assert!(KeyboardLayout::Qwerty.adjacent('e', 'd'));
+        assert!(!KeyboardLayout::Qwerty.adjacent('a', 'p'));
+        assert!(KeyboardLayout::Azerty.adjacent('a', 'z'));
+        assert!(KeyboardLayout::Azerty.adjacent('q', 's'));
+        assert!(!KeyboardLayout::Qwerty.adjacent('a', 'm'));
+    }
+
+    #[test]
+    fn test_adjacent_key_beats_distant_key() {
+        // "gor": f is next to g, p is not
+        let ranker = SuggestionRanker::new();
+        assert_eq!(rank(&ranker, "gor", &["por", "for"])[0], "for");
+    }
+
+    #[test]
+    fn test_accent_and_doubled_letter_are_cheap() {
+        let ranker = SuggestionRanker::new().with_layout(KeyboardLayout::Azerty);
+        assert_eq!(rank(&ranker, "ete", &["pete", "été", "etc"])[0], "été");
+        assert_eq!(rank(&ranker, "ocurred", &["ocurrent", "occurred"])[0], "occurred");
+    }
+
+    #[test]
+    fn test_phonetic_key() {
+        assert_eq!(phonetic_key("fonetik"), phonetic_key("phonetic"));
+        assert_eq!(phonetic_key("nite"), "NT");
+        assert_ne!(phonetic_key("cat"), phonetic_key("bat"));
+    }
+
+    #[test]
+    fn test_frequency_breaks_ties() {
+        const COMMON: &[&str] = &["thin"];
+        let plain = SuggestionRanker::new();
+        let ranked = SuggestionRanker::new().with_frequency(WordFrequency::CommonWords(COMMON));
+        assert_eq!(rank(&plain, "thn", &["thin", "than"])[0], "than");
+        assert_eq!(rank(&ranked, "thn", &["thin", "than"])[0], "thin");
+    }
+}
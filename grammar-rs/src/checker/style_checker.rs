@@ -8,8 +8,9 @@ use aho_corasick::AhoCorasick;
 
 use crate::checker::data::en_style::{StyleCategory, StyleRule, EN_STYLE_RULES};
 use crate::checker::data::fr_style::FR_STYLE_RULES;
+use crate::core::registry::{RuleCategory, RuleMetadata};
 use crate::core::traits::Checker;
-use crate::core::{AnalyzedToken, CheckResult, Match, Severity};
+use crate::core::{AnalyzedToken, CheckLevel, CheckResult, IssueType, Match, Severity};
 
 /// Style checker using Aho-Corasick for efficient phrase matching.
 ///
@@ -20,6 +21,8 @@ pub struct StyleChecker {
     ac: AhoCorasick,
     /// Reference to the style rules (indexed by AC pattern ID)
     rules: &'static [StyleRule],
+    /// Strictness tier (picky by default: wordiness is not an error)
+    level: CheckLevel,
 }
 
 impl StyleChecker {
@@ -42,7 +45,13 @@ impl StyleChecker {
             .build(&phrases)
             .expect("Failed to build Aho-Corasick automaton");
 
-        Self { ac, rules }
+        Self { ac, rules, level: CheckLevel::Picky }
+    }
+
+    /// Set the strictness tier of this checker (default: `CheckLevel::Picky`)
+    pub fn with_level(mut self, level: CheckLevel) -> Self {
+        self.level = level;
+        self
     }
 
     /// Get the rule ID based on category and phrase
@@ -142,11 +151,30 @@ impl Checker for StyleChecker {
                 message: self.get_message(rule),
                 suggestions,
                 severity: Severity::Hint, // Style issues are hints
+                issue_type: Some(IssueType::Style),
             });
         }
 
         CheckResult { matches }
     }
+
+    fn level(&self) -> CheckLevel {
+        self.level
+    }
+
+    fn rules(&self) -> Vec<RuleMetadata> {
+        self.rules
+            .iter()
+            .map(|rule| {
+                let category = match rule.category {
+                    StyleCategory::Wordiness => RuleCategory::Style,
+                    StyleCategory::Redundancy => RuleCategory::Redundancy,
+                };
+                RuleMetadata::new(self.get_rule_id(rule), self.get_message(rule), category)
+                    .with_level(self.level)
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
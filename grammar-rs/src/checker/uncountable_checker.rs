@@ -6,6 +6,7 @@
 //! - "furnitures" → "furniture"
 
 use crate::core::{AnalyzedToken, CheckResult, Match, Severity, TokenKind};
+use crate::core::registry::{RuleCategory, RuleMetadata};
 use crate::core::traits::Checker;
 use crate::checker::data::en_uncountable::is_en_uncountable;
 use std::collections::HashSet;
@@ -135,12 +136,17 @@ impl Checker for UncountableNounChecker {
                     rule_id: "UNCOUNTABLE_PLURAL".to_string(),
                     suggestions: vec![singular],
                     severity: Severity::Warning,
+                    issue_type: None,
                 });
             }
         }
 
         result
     }
+
+    fn rules(&self) -> Vec<RuleMetadata> {
+        vec![RuleMetadata::new("UNCOUNTABLE_PLURAL", "Plural of an uncountable noun", RuleCategory::Grammar)]
+    }
 }
 
 #[cfg(test)]
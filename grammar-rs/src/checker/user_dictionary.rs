@@ -0,0 +1,161 @@
+//! Personal dictionary (LanguageTool `/v2/words`)
+//!
+//! Words a user or a team added at runtime ("Kubernetes", product names).
+//! The dictionary is applied per request to the `SPELL` matches of a
+//! `SpellChecker`, so the shared pipeline and its caches stay untouched:
+//! - known words are no longer reported
+//! - close words are offered first among the suggestions (`Kuberentes`)
+//!
+//! Case: a lowercase entry accepts every casing (`foo`, `Foo`, `FOO`),
+//! an entry with capitals only itself and its all-caps form.
+
+use crate::core::Match;
+use crate::dictionary::FstDictionary;
+use std::collections::{HashMap, HashSet};
+
+/// Rule ID of `SpellChecker` matches
+const SPELL_RULE_ID: &str = "SPELL";
+
+/// Maximum edit distance of suggestions taken from the personal dictionary
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// Words accepted by the spell checker for one user
+#[derive(Default)]
+pub struct UserDictionary {
+    words: HashSet<String>,
+    /// Lowercase form → words as added, for case-insensitive lookups
+    by_lowercase: HashMap<String, Vec<String>>,
+    /// Lowercase words, for suggestions
+    index: FstDictionary,
+}
+
+impl UserDictionary {
+    pub fn new<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let words: HashSet<String> = words
+            .into_iter()
+            .map(|w| w.as_ref().trim().to_string())
+            .filter(|w| !w.is_empty())
+            .collect();
+
+        let mut by_lowercase: HashMap<String, Vec<String>> = HashMap::new();
+        for word in &words {
+            by_lowercase.entry(word.to_lowercase()).or_default().push(word.clone());
+        }
+        let index = FstDictionary::from_iter(by_lowercase.keys()).unwrap_or_default();
+
+        Self { words, by_lowercase, index }
+    }
+
+    /// Number of words
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// True if the spell checker must accept `word`
+    pub fn contains(&self, word: &str) -> bool {
+        if self.words.contains(word) {
+            return true;
+        }
+        let lower = word.to_lowercase();
+        let all_caps = word.chars().any(char::is_alphabetic) && word.to_uppercase() == word;
+        self.by_lowercase
+            .get(&lower)
+            .is_some_and(|entries| entries.iter().any(|entry| *entry == lower || all_caps))
+    }
+
+    /// Words close to a misspelling, closest first
+    pub fn suggest(&self, word: &str) -> Vec<String> {
+        let mut candidates = self.index.fuzzy(word, MAX_SUGGESTION_DISTANCE);
+        candidates.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+        candidates
+            .into_iter()
+            .filter_map(|(lower, _)| self.by_lowercase.get(&lower))
+            .flatten()
+            .cloned()
+            .collect()
+    }
+
+    /// Apply the dictionary to the matches of a check of `text`
+    ///
+    /// Drops the spelling matches on known words and puts the closest
+    /// personal words first in the suggestions of the others.
+    pub fn apply(&self, text: &str, matches: &mut Vec<Match>) {
+        if self.is_empty() {
+            return;
+        }
+        matches.retain(|m| {
+            m.rule_id != SPELL_RULE_ID || !text.get(m.span.clone()).is_some_and(|word| self.contains(word))
+        });
+
+        for m in matches.iter_mut().filter(|m| m.rule_id == SPELL_RULE_ID) {
+            let Some(word) = text.get(m.span.clone()) else { continue };
+            let mut suggestions = self.suggest(word);
+            if suggestions.is_empty() {
+                continue;
+            }
+            suggestions.extend(m.suggestions.drain(..).filter(|s| !self.words.contains(s)));
+            m.suggestions = suggestions;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{IssueType, Severity};
+
+    fn spell_match(text: &str, word: &str, suggestions: &[&str]) -> Match {
+        let start = text.find(word).unwrap();
+        Match {
+            span: start..start + word.len(),
+            message: format!("Possible spelling mistake: '{}'", word),
+            rule_id: SPELL_RULE_ID.to_string(),
+            suggestions: suggestions.iter().map(|s| s.to_string()).collect(),
+            severity: Severity::Error,
+            issue_type: Some(IssueType::Misspelling),
+        }
+    }
+
+    #[test]
+    fn test_case_handling() {
+        let dict = UserDictionary::new(["Kubernetes", "grafana"]);
+        assert!(dict.contains("Kubernetes"));
+        assert!(dict.contains("KUBERNETES"));
+        assert!(!dict.contains("kubernetes"));
+        assert!(dict.contains("grafana"));
+        assert!(dict.contains("Grafana"));
+        assert!(!dict.contains("grafanas"));
+    }
+
+    #[test]
+    fn test_apply_drops_known_words() {
+        let dict = UserDictionary::new(["Kubernetes"]);
+        let text = "Deploy Kubernetes on teh cluster.";
+        let mut matches = vec![spell_match(text, "Kubernetes", &[]), spell_match(text, "teh", &["the"])];
+        let mut other = spell_match(text, "Kubernetes", &[]);
+        other.rule_id = "OTHER_RULE".to_string();
+        matches.push(other);
+
+        dict.apply(text, &mut matches);
+        let rules: Vec<_> = matches.iter().map(|m| (m.rule_id.as_str(), m.span.start)).collect();
+        assert_eq!(rules, vec![("SPELL", 21), ("OTHER_RULE", 7)]);
+    }
+
+    #[test]
+    fn test_apply_suggests_personal_words() {
+        let dict = UserDictionary::new(["Kubernetes"]);
+        let text = "Deploy Kuberentes.";
+        let mut matches = vec![spell_match(text, "Kuberentes", &["Kubernetes", "Governess"])];
+
+        dict.apply(text, &mut matches);
+        assert_eq!(matches[0].suggestions, vec!["Kubernetes", "Governess"]);
+    }
+}
@@ -0,0 +1,184 @@
+//! Variant spelling checker (en-US vs en-GB)
+//!
+//! Flags words spelled in the "other" English variant using the
+//! LanguageTool en-US-GB mapping table: "colour" in en-US text and
+//! "color" in en-GB text.
+//!
+//! Words that are valid in both variants (e.g. "practice", which is both
+//! the US spelling of the verb and the GB spelling of the noun) are never
+//! flagged.
+
+use crate::checker::data::en_us_gb::{gb_to_us, is_gb_spelling, is_us_spelling, us_to_gb};
+use crate::core::registry::{RuleCategory, RuleMetadata};
+use crate::core::traits::Checker;
+use crate::core::{AnalyzedToken, CheckResult, IssueType, Match, Severity, TokenKind};
+
+/// English language variant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnglishVariant {
+    /// American English (en-US)
+    American,
+    /// British English (en-GB)
+    British,
+}
+
+impl EnglishVariant {
+    /// Get the BCP 47 language code
+    pub fn code(&self) -> &'static str {
+        match self {
+            EnglishVariant::American => "en-US",
+            EnglishVariant::British => "en-GB",
+        }
+    }
+
+    /// Parse a language code ("en-GB", "en-uk", "en-US", ...)
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.to_lowercase().as_str() {
+            "en" | "en-us" => Some(EnglishVariant::American),
+            "en-gb" | "en-uk" => Some(EnglishVariant::British),
+            _ => None,
+        }
+    }
+
+    /// Human-readable name used in messages
+    pub fn name(&self) -> &'static str {
+        match self {
+            EnglishVariant::American => "American English",
+            EnglishVariant::British => "British English",
+        }
+    }
+
+    /// Convert a word to this variant's spelling, if it is a known
+    /// spelling of the other variant only
+    pub fn convert(&self, word: &str) -> Option<&'static str> {
+        let lower = word.to_lowercase();
+        match self {
+            EnglishVariant::American if !is_us_spelling(&lower) => gb_to_us(&lower),
+            EnglishVariant::British if !is_gb_spelling(&lower) => us_to_gb(&lower),
+            _ => None,
+        }
+    }
+}
+
+/// Checker that flags spellings belonging to the other English variant
+pub struct VariantSpellingChecker {
+    variant: EnglishVariant,
+}
+
+impl VariantSpellingChecker {
+    /// Create a checker for the given variant
+    pub fn new(variant: EnglishVariant) -> Self {
+        Self { variant }
+    }
+
+    /// The variant this checker enforces
+    pub fn variant(&self) -> EnglishVariant {
+        self.variant
+    }
+
+    fn rule_id(&self) -> &'static str {
+        match self.variant {
+            EnglishVariant::American => "EN_US_SPELLING",
+            EnglishVariant::British => "EN_GB_SPELLING",
+        }
+    }
+}
+
+impl Default for VariantSpellingChecker {
+    fn default() -> Self {
+        Self::new(EnglishVariant::American)
+    }
+}
+
+impl Checker for VariantSpellingChecker {
+    fn check(&self, _text: &str, tokens: &[AnalyzedToken]) -> CheckResult {
+        let mut matches = Vec::new();
+
+        for token in tokens {
+            if token.token.kind != TokenKind::Word {
+                continue;
+            }
+
+            let word = token.token.text;
+            if let Some(replacement) = self.variant.convert(word) {
+                matches.push(Match {
+                    span: token.token.span.clone(),
+                    message: format!(
+                        "'{}' is not the {} spelling. Use '{}'.",
+                        word,
+                        self.variant.name(),
+                        replacement
+                    ),
+                    rule_id: self.rule_id().to_string(),
+                    suggestions: vec![preserve_case(word, replacement)],
+                    severity: Severity::Warning,
+                    issue_type: Some(IssueType::LocaleViolation),
+                });
+            }
+        }
+
+        CheckResult { matches }
+    }
+
+    fn rules(&self) -> Vec<RuleMetadata> {
+        let description = format!("Spelling not used in {}", self.variant.name());
+        vec![RuleMetadata::new(self.rule_id(), description, RuleCategory::Typos)]
+    }
+}
+
+/// Apply the capitalization of `original` to `replacement`
+pub(crate) fn preserve_case(original: &str, replacement: &str) -> String {
+    if original.len() > 1 && original.chars().all(|c| !c.is_lowercase()) {
+        return replacement.to_uppercase();
+    }
+
+    let mut chars = replacement.chars();
+    match (original.chars().next(), chars.next()) {
+        (Some(first), Some(c)) if first.is_uppercase() => {
+            c.to_uppercase().collect::<String>() + chars.as_str()
+        }
+        _ => replacement.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    fn check(variant: EnglishVariant, text: &str) -> Vec<Match> {
+        let tokens = SimpleTokenizer::new().tokenize(text);
+        let analyzed = PassthroughAnalyzer::new().analyze(tokens);
+        VariantSpellingChecker::new(variant).check(text, &analyzed).matches
+    }
+
+    #[test]
+    fn test_gb_flags_us_spelling() {
+        let matches = check(EnglishVariant::British, "My favorite color is blue.");
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].rule_id, "EN_GB_SPELLING");
+        assert_eq!(matches[0].suggestions, vec!["favourite"]);
+        assert_eq!(matches[1].suggestions, vec!["colour"]);
+    }
+
+    #[test]
+    fn test_us_flags_gb_spelling() {
+        let matches = check(EnglishVariant::American, "Colour me impressed.");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].rule_id, "EN_US_SPELLING");
+        assert_eq!(matches[0].suggestions, vec!["Color"]);
+    }
+
+    #[test]
+    fn test_own_variant_not_flagged() {
+        assert!(check(EnglishVariant::American, "The color of the center.").is_empty());
+        assert!(check(EnglishVariant::British, "The colour of the centre.").is_empty());
+    }
+
+    #[test]
+    fn test_from_code() {
+        assert_eq!(EnglishVariant::from_code("en-GB"), Some(EnglishVariant::British));
+        assert_eq!(EnglishVariant::from_code("EN-us"), Some(EnglishVariant::American));
+        assert_eq!(EnglishVariant::from_code("fr"), None);
+    }
+}
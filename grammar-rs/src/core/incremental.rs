@@ -0,0 +1,364 @@
+//! Vérification incrémentale - ne revérifie que les paragraphes modifiés
+//!
+//! Un éditeur renvoie tout le document à chaque pause de frappe. Le
+//! document est découpé en paragraphes (séparés par une ligne vide) et le
+//! `CheckResult` de chaque paragraphe est mis en cache selon le hash de son
+//! contenu : après une modification, seuls les paragraphes changés passent
+//! dans les checkers, les autres sont repris du cache avec leurs offsets
+//! décalés.
+//!
+//! Les checkers de document (`Checker::is_document_level`, ex: cohérence
+//! US/UK) tournent toujours sur le texte complet, et les zones masquées
+//! (code, citations) sont recalculées sur le texte complet : un bloc de
+//! code clôturé n'est jamais coupé en paragraphes.
+
+use super::pipeline::Pipeline;
+use super::{CheckLevel, CheckResult};
+use rayon::prelude::*;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Nombre de paragraphes gardés en cache par défaut
+const DEFAULT_CAPACITY: usize = 10_000;
+
+/// Résultat d'une vérification incrémentale
+#[derive(Debug, Clone)]
+pub struct IncrementalResult {
+    /// Matches du document entier (offsets relatifs au document)
+    pub result: CheckResult,
+    /// Nombre de paragraphes du document
+    pub paragraphs: usize,
+    /// Nombre de paragraphes absents du cache, vraiment revérifiés
+    pub rechecked: usize,
+}
+
+/// Entrée du cache : le texte est gardé pour écarter les collisions de hash
+struct CachedParagraph {
+    text: String,
+    result: Arc<CheckResult>,
+    last_used: u64,
+}
+
+type ParagraphCache = HashMap<(u64, CheckLevel), CachedParagraph>;
+
+/// Vérificateur incrémental au-dessus d'un `Pipeline`
+///
+/// Partageable entre threads : le cache est commun à tous les documents
+/// vérifiés avec ce pipeline.
+pub struct IncrementalChecker {
+    pipeline: Arc<Pipeline>,
+    cache: Mutex<ParagraphCache>,
+    capacity: usize,
+    clock: AtomicU64,
+}
+
+impl IncrementalChecker {
+    pub fn new(pipeline: Arc<Pipeline>) -> Self {
+        Self {
+            pipeline,
+            cache: Mutex::new(HashMap::new()),
+            capacity: DEFAULT_CAPACITY,
+            clock: AtomicU64::new(0),
+        }
+    }
+
+    /// Nombre maximal de paragraphes en cache (les moins récents sont évincés)
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Pipeline sous-jacent
+    pub fn pipeline(&self) -> &Arc<Pipeline> {
+        &self.pipeline
+    }
+
+    /// Nombre de paragraphes actuellement en cache
+    pub fn cached_paragraphs(&self) -> usize {
+        self.cache.lock().unwrap().len()
+    }
+
+    /// Vide le cache
+    pub fn clear(&self) {
+        self.cache.lock().unwrap().clear();
+    }
+
+    /// Vérifie le document avec toutes les règles (équivalent de `check_text`)
+    pub fn check(&self, text: &str) -> IncrementalResult {
+        self.check_with_level(text, CheckLevel::Picky)
+    }
+
+    /// Vérifie le document en ne revérifiant que les paragraphes modifiés
+    pub fn check_with_level(&self, text: &str, level: CheckLevel) -> IncrementalResult {
+        let paragraphs = split_paragraphs(text);
+        let tick = self.clock.fetch_add(1, Ordering::Relaxed);
+
+        // Étape 1: reprendre ce qui est déjà en cache
+        let mut cached: Vec<Option<Arc<CheckResult>>> = {
+            let mut cache = self.cache.lock().unwrap();
+            paragraphs
+                .iter()
+                .map(|span| lookup(&mut cache, &text[span.clone()], level, tick))
+                .collect()
+        };
+
+        // Étape 2: vérifier les paragraphes manquants (en parallèle)
+        let missing: Vec<usize> = (0..paragraphs.len()).filter(|&i| cached[i].is_none()).collect();
+        let fresh: Vec<(usize, Arc<CheckResult>)> = missing
+            .par_iter()
+            .map(|&i| {
+                let paragraph = &text[paragraphs[i].clone()];
+                (i, Arc::new(self.pipeline.check_paragraph_with_level(paragraph, level)))
+            })
+            .collect();
+
+        if !fresh.is_empty() {
+            let mut cache = self.cache.lock().unwrap();
+            for (i, result) in &fresh {
+                insert(&mut cache, &text[paragraphs[*i].clone()], level, result, tick);
+            }
+            self.evict(&mut cache);
+        }
+
+        let rechecked = fresh.len();
+        for (i, result) in fresh {
+            cached[i] = Some(result);
+        }
+
+        // Étape 3: décaler les offsets vers le document
+        let masks = self.pipeline.find_masks(text);
+        let mut result = CheckResult::new();
+        for (span, paragraph_result) in paragraphs.iter().zip(cached) {
+            let Some(paragraph_result) = paragraph_result else { continue };
+            result.matches.extend(paragraph_result.matches.iter().map(|m| {
+                let mut m = m.clone();
+                m.span = (m.span.start + span.start)..(m.span.end + span.start);
+                m
+            }));
+        }
+
+        // Étape 4: masques et checkers de document sur le texte complet
+        result = result.filter_masked(&masks);
+        if self.pipeline.has_document_checkers() {
+            result.merge(self.pipeline.check_document_with_level(text, level));
+        }
+
+        result.sort_and_dedupe();
+        IncrementalResult {
+            result,
+            paragraphs: paragraphs.len(),
+            rechecked,
+        }
+    }
+
+    /// Vérifie un seul paragraphe en passant par le cache
+    ///
+    /// Les offsets du résultat sont relatifs au paragraphe. Les checkers de
+    /// document ne sont pas exécutés.
+    pub fn check_paragraph(&self, paragraph: &str, level: CheckLevel) -> Arc<CheckResult> {
+        let tick = self.clock.fetch_add(1, Ordering::Relaxed);
+        if let Some(result) = lookup(&mut self.cache.lock().unwrap(), paragraph, level, tick) {
+            return result;
+        }
+
+        let result = Arc::new(self.pipeline.check_paragraph_with_level(paragraph, level));
+        let mut cache = self.cache.lock().unwrap();
+        insert(&mut cache, paragraph, level, &result, tick);
+        self.evict(&mut cache);
+        result
+    }
+
+    /// Évince les paragraphes les moins récemment utilisés au-delà de la capacité
+    fn evict(&self, cache: &mut ParagraphCache) {
+        if cache.len() <= self.capacity {
+            return;
+        }
+
+        // On descend à 3/4 de la capacité pour ne pas trier à chaque appel
+        let target = self.capacity * 3 / 4;
+        let mut ages: Vec<u64> = cache.values().map(|entry| entry.last_used).collect();
+        ages.sort_unstable();
+        let cutoff = ages[cache.len() - target.max(1)];
+        cache.retain(|_, entry| entry.last_used >= cutoff);
+    }
+}
+
+/// Résultat en cache pour ce paragraphe, en le marquant comme utilisé
+fn lookup(
+    cache: &mut ParagraphCache,
+    paragraph: &str,
+    level: CheckLevel,
+    tick: u64,
+) -> Option<Arc<CheckResult>> {
+    let entry = cache.get_mut(&(hash_paragraph(paragraph), level))?;
+    if entry.text != paragraph {
+        return None;
+    }
+    entry.last_used = tick;
+    Some(Arc::clone(&entry.result))
+}
+
+fn insert(
+    cache: &mut ParagraphCache,
+    paragraph: &str,
+    level: CheckLevel,
+    result: &Arc<CheckResult>,
+    tick: u64,
+) {
+    cache.insert(
+        (hash_paragraph(paragraph), level),
+        CachedParagraph {
+            text: paragraph.to_string(),
+            result: Arc::clone(result),
+            last_used: tick,
+        },
+    );
+}
+
+/// Hash du contenu d'un paragraphe (clé du cache)
+fn hash_paragraph(paragraph: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    paragraph.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Découpe le texte en paragraphes séparés par au moins une ligne vide
+///
+/// Les spans excluent les lignes vides et les fins de ligne finales ;
+/// un texte sans contenu ne produit aucun paragraphe. Les lignes vides
+/// d'un bloc de code clôturé (``` ou ~~~) ne coupent pas le paragraphe.
+pub fn split_paragraphs(text: &str) -> Vec<Range<usize>> {
+    let mut paragraphs = Vec::new();
+    let mut current: Option<Range<usize>> = None;
+    let mut offset = 0;
+    let mut in_fence = false;
+
+    for line in text.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        }
+
+        if line.trim().is_empty() && !in_fence {
+            paragraphs.extend(current.take());
+            continue;
+        }
+
+        let end = start + line.trim_end_matches(['\n', '\r']).len();
+        match current {
+            Some(ref mut span) => span.end = end,
+            None => current = Some(start..end),
+        }
+    }
+
+    paragraphs.extend(current);
+    paragraphs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::checker::CoherencyChecker;
+
+    fn checker() -> IncrementalChecker {
+        let pipeline = Pipeline::new(SimpleTokenizer::new(), PassthroughAnalyzer::new())
+            .with_checker(SpellChecker::new().with_words([
+                "hello", "world", "the", "analyse", "analyze", "data",
+            ]))
+            .with_checker(CoherencyChecker::new());
+        IncrementalChecker::new(Arc::new(pipeline))
+    }
+
+    #[test]
+    fn test_split_paragraphs() {
+        let text = "first line\nsecond line\n\n  \nthird\r\n\r\nlast";
+        let spans: Vec<&str> = split_paragraphs(text).into_iter().map(|s| &text[s]).collect();
+        assert_eq!(spans, vec!["first line\nsecond line", "third", "last"]);
+        assert!(split_paragraphs("\n\n  \n").is_empty());
+
+        let fenced = "intro\n\n```\nfn main() {\n\n}\n```\n\nend";
+        let spans: Vec<&str> = split_paragraphs(fenced).into_iter().map(|s| &fenced[s]).collect();
+        assert_eq!(spans, vec!["intro", "```\nfn main() {\n\n}\n```", "end"]);
+    }
+
+    #[test]
+    fn test_only_changed_paragraphs_are_rechecked() {
+        let checker = checker();
+        let first = checker.check("hello world\n\nhelo world\n\nthe world");
+        assert_eq!(first.paragraphs, 3);
+        assert_eq!(first.rechecked, 3);
+
+        let second = checker.check("hello world\n\nhello wrld\n\nthe world");
+        assert_eq!(second.rechecked, 1);
+
+        let third = checker.check("hello world\n\nhello wrld\n\nthe world");
+        assert_eq!(third.rechecked, 0);
+    }
+
+    #[test]
+    fn test_offsets_match_full_check() {
+        let checker = checker();
+        let before = "hello world\n\nhelo world\n\nthe wrld";
+        let after = "hello wrld is here\n\nhelo world\n\nthe wrld";
+        checker.check(before);
+
+        let incremental = checker.check(after);
+        assert_eq!(incremental.rechecked, 1);
+
+        let full = checker.pipeline().check_text(after);
+        let spans = |r: &CheckResult| r.matches.iter().map(|m| (m.span.clone(), m.rule_id.clone())).collect::<Vec<_>>();
+        assert_eq!(spans(&incremental.result), spans(&full));
+        for m in &incremental.result.matches {
+            assert!(!after[m.span.clone()].contains('\n'));
+        }
+    }
+
+    #[test]
+    fn test_masks_cover_the_whole_document() {
+        let pipeline = Pipeline::new(SimpleTokenizer::new(), PassthroughAnalyzer::new())
+            .with_checker(SpellChecker::new().with_words(["hello", "world", "the"]))
+            .with_default_filters();
+        let checker = IncrementalChecker::new(Arc::new(pipeline));
+        let text = "hello wrld\n\n```\nlet helo = 1;\n\nlet wrld = 2;\n```\n\n\"the helo\n\nwrld\" the wrld";
+
+        let incremental = checker.check(text);
+        let full = checker.pipeline().check_text(text);
+        let spans = |r: &CheckResult| r.matches.iter().map(|m| (m.span.clone(), m.rule_id.clone())).collect::<Vec<_>>();
+        assert_eq!(spans(&incremental.result), spans(&full));
+        assert_eq!(incremental.result.matches.len(), 2);
+    }
+
+    #[test]
+    fn test_document_checkers_see_whole_text() {
+        let checker = checker();
+        let result = checker.check("analyse the data\n\nanalyze the data");
+        assert!(result.result.matches.iter().any(|m| m.rule_id.starts_with("COHERENCY_")));
+    }
+
+    #[test]
+    fn test_check_paragraph_shares_cache() {
+        let checker = checker();
+        let single = checker.check_paragraph("helo world", CheckLevel::Picky);
+        assert_eq!(single.matches.len(), 1);
+
+        let document = checker.check("hello world\n\nhelo world");
+        assert_eq!(document.rechecked, 1);
+    }
+
+    #[test]
+    fn test_eviction_respects_capacity() {
+        let checker = checker().with_capacity(4);
+        for i in 0..10 {
+            checker.check(&format!("hello {}\n\nworld {}", i, i));
+        }
+        assert!(checker.cached_paragraphs() <= 4);
+    }
+}
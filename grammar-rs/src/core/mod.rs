@@ -3,6 +3,9 @@
 pub mod traits;
 pub mod pipeline;
 pub mod filter;
+pub mod registry;
+pub mod incremental;
+pub mod profile;
 
 use std::ops::Range;
 
@@ -23,12 +26,96 @@ pub enum TokenKind {
     Unknown,
 }
 
+/// Une lecture possible d'un token : lemme + POS
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reading {
+    pub lemma: Option<String>,
+    pub pos: PosTag,
+}
+
 /// Token enrichi avec analyse linguistique
 #[derive(Debug, Clone)]
 pub struct AnalyzedToken<'a> {
     pub token: Token<'a>,
+    /// Lemme de la lecture retenue
     pub lemma: Option<String>,
+    /// POS de la lecture retenue
     pub pos: Option<PosTag>,
+    /// Toutes les lectures possibles, la lecture retenue en tête
+    /// ("saw" → VBD/see, NN/saw)
+    pub readings: Vec<Reading>,
+    /// Span de la phrase qui contient le token (vide tant que le texte n'a
+    /// pas été découpé, voir `SentenceSplitter`)
+    pub sentence: Range<usize>,
+    /// Protégé par la désambiguïsation (`immunize`) : aucune erreur signalée dessus
+    pub immunized: bool,
+}
+
+impl<'a> AnalyzedToken<'a> {
+    pub fn new(token: Token<'a>, lemma: Option<String>, pos: Option<PosTag>) -> Self {
+        let readings = pos
+            .map(|pos| vec![Reading { lemma: lemma.clone(), pos }])
+            .unwrap_or_default();
+        Self {
+            token,
+            lemma,
+            pos,
+            readings,
+            sentence: 0..0,
+            immunized: false,
+        }
+    }
+
+    /// Token ambigu : la première lecture est retenue
+    pub fn with_readings(token: Token<'a>, readings: Vec<Reading>) -> Self {
+        let mut analyzed = Self::new(token, None, None);
+        analyzed.set_readings(readings);
+        analyzed
+    }
+
+    /// Remplace les lectures et retient la première (aucune : token non étiqueté)
+    pub fn set_readings(&mut self, readings: Vec<Reading>) {
+        self.pos = readings.first().map(|r| r.pos);
+        self.lemma = readings.first().and_then(|r| r.lemma.clone());
+        self.readings = readings;
+    }
+
+    /// Au moins une lecture (ou la lecture retenue) vérifie `f`
+    pub fn has_pos(&self, f: impl Fn(PosTag) -> bool) -> bool {
+        self.pos.is_some_and(&f) || self.readings.iter().any(|r| f(r.pos))
+    }
+
+    /// Premier token de sa phrase (`SENT_START` de LanguageTool)
+    pub fn is_sentence_start(&self) -> bool {
+        self.token.span.start == self.sentence.start
+    }
+
+    /// Dernier token de sa phrase (`SENT_END` de LanguageTool)
+    pub fn is_sentence_end(&self) -> bool {
+        !self.sentence.is_empty() && self.token.span.end == self.sentence.end
+    }
+}
+
+/// Reporte les phrases (spans triés) sur les tokens
+///
+/// Les blancs entre deux phrases appartiennent à la première.
+pub fn assign_sentences(tokens: &mut [AnalyzedToken], sentences: &[Range<usize>]) {
+    let mut current = 0;
+    for token in tokens {
+        while current + 1 < sentences.len() && token.token.span.start >= sentences[current + 1].start {
+            current += 1;
+        }
+        if let Some(sentence) = sentences.get(current) {
+            token.sentence = sentence.clone();
+        }
+    }
+}
+
+/// Tokens regroupés par phrase
+///
+/// Des tokens qui n'ont pas été découpés forment une seule phrase.
+pub fn sentences<'t, 'a>(tokens: &'t [AnalyzedToken<'a>]) -> impl Iterator<Item = &'t [AnalyzedToken<'a>]> {
+    tokens.chunk_by(|a, b| a.sentence == b.sentence)
 }
 
 /// Part-of-speech tags
@@ -590,6 +677,9 @@ pub struct Match {
     pub rule_id: String,
     pub suggestions: Vec<String>,
     pub severity: Severity,
+    /// Type de problème (LanguageTool `issueType`), `None` si le checker
+    /// ne le précise pas
+    pub issue_type: Option<IssueType>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -599,6 +689,112 @@ pub enum Severity {
     Hint,
 }
 
+/// Kind of issue reported by a rule (LanguageTool `type` attribute / `issueType`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IssueType {
+    Misspelling,
+    Grammar,
+    Style,
+    Typographical,
+    Duplication,
+    Whitespace,
+    Inconsistency,
+    LocaleViolation,
+    Register,
+    NonConformance,
+    Uncategorized,
+}
+
+impl IssueType {
+    /// Get the string representation used by the LanguageTool API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IssueType::Misspelling => "misspelling",
+            IssueType::Grammar => "grammar",
+            IssueType::Style => "style",
+            IssueType::Typographical => "typographical",
+            IssueType::Duplication => "duplication",
+            IssueType::Whitespace => "whitespace",
+            IssueType::Inconsistency => "inconsistency",
+            IssueType::LocaleViolation => "locale-violation",
+            IssueType::Register => "register",
+            IssueType::NonConformance => "non-conformance",
+            IssueType::Uncategorized => "uncategorized",
+        }
+    }
+
+    /// Severity a match of this type is reported with
+    pub fn severity(&self) -> Severity {
+        match self {
+            IssueType::Misspelling | IssueType::Grammar => Severity::Error,
+            IssueType::Style | IssueType::Register => Severity::Hint,
+            _ => Severity::Warning,
+        }
+    }
+}
+
+impl std::str::FromStr for IssueType {
+    type Err = String;
+
+    /// Parse a LanguageTool issue type ("misspelling", "locale-violation", "LocaleViolation", ...)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized: String = s
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .collect::<String>()
+            .to_lowercase();
+        match normalized.as_str() {
+            "misspelling" => Ok(IssueType::Misspelling),
+            "grammar" => Ok(IssueType::Grammar),
+            "style" => Ok(IssueType::Style),
+            "typographical" => Ok(IssueType::Typographical),
+            "duplication" => Ok(IssueType::Duplication),
+            "whitespace" => Ok(IssueType::Whitespace),
+            "inconsistency" => Ok(IssueType::Inconsistency),
+            "localeviolation" => Ok(IssueType::LocaleViolation),
+            "register" => Ok(IssueType::Register),
+            "nonconformance" => Ok(IssueType::NonConformance),
+            "uncategorized" | "other" => Ok(IssueType::Uncategorized),
+            _ => Err(format!("unknown issue type '{}'", s)),
+        }
+    }
+}
+
+/// Strictness tier of a rule (LanguageTool `level` parameter)
+///
+/// Tiers are ordered: a check at `Picky` also runs every `Default` rule.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CheckLevel {
+    /// Rules enabled for every check
+    #[default]
+    Default,
+    /// Stricter style rules, only run when the caller asks for them
+    Picky,
+}
+
+impl CheckLevel {
+    /// Get the string representation used by the LanguageTool API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CheckLevel::Default => "default",
+            CheckLevel::Picky => "picky",
+        }
+    }
+}
+
+impl std::str::FromStr for CheckLevel {
+    type Err = String;
+
+    /// Parse a level from a string ("default", "picky")
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "default" => Ok(CheckLevel::Default),
+            "picky" => Ok(CheckLevel::Picky),
+            other => Err(format!("unknown check level '{}'", other)),
+        }
+    }
+}
+
 /// Résultat final de l'analyse
 #[derive(Debug, Clone)]
 pub struct CheckResult {
@@ -4,10 +4,15 @@
 //! implémentation sans changer le reste du code.
 
 use super::filter::FilterChain;
-use super::traits::{Analyzer, Checker, GrammarChecker, Tokenizer};
-use super::CheckResult;
+use super::profile::{slowest_rules, CheckProfile, CheckerProfile, DEFAULT_TOP_RULES};
+use super::registry::RuleRegistry;
+use super::traits::{Analyzer, Checker, Disambiguator, GrammarChecker, SentenceSplitter, Tokenizer};
+use super::{AnalyzedToken, CheckLevel, CheckResult, MaskedRegion};
+use crate::markup::AnnotatedText;
+use crate::tokenizer::SimpleSentenceSplitter;
 use rayon::prelude::*;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
 
 /// Initialize the rayon thread pool with a specific number of threads.
 /// Call this at application startup to limit CPU usage.
@@ -43,12 +48,50 @@ pub fn num_cpus() -> usize {
         .unwrap_or(1)
 }
 
+/// Applique `f` à chaque élément en parallèle, par fenêtres bornées
+///
+/// Au plus `4 × threads rayon` éléments sont traités en même temps : les
+/// tokens et analyses intermédiaires d'un gros lot ne coexistent jamais en
+/// mémoire. L'ordre des résultats suit celui des éléments.
+pub fn par_map_bounded<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let window = rayon::current_num_threads() * 4;
+    let mut results = Vec::with_capacity(items.len());
+    for chunk in items.chunks(window.max(1)) {
+        results.par_extend(chunk.par_iter().map(&f));
+    }
+    results
+}
+
+/// Reçoit la durée et le résultat de chaque checker (métriques)
+///
+/// Appelé depuis les threads rayon, une fois par checker et par
+/// vérification (texte, paragraphe ou document).
+pub trait CheckObserver: Send + Sync {
+    fn checker_done(&self, checker: &'static str, elapsed: Duration, result: &CheckResult);
+}
+
 /// Le pipeline principal - compose les étapes
+///
+/// Les règles `default="off"` tournent comme les autres : le pipeline
+/// renvoie leurs matches et c'est à l'appelant de les écarter tant qu'elles
+/// ne sont pas activées (`RuleRegistry::is_default_on`), comme le font
+/// l'API (`enabledRules` / `enabledCategories`) et la CLI (`--enable`).
 pub struct Pipeline {
     tokenizer: Arc<dyn Tokenizer>,
     analyzer: Arc<dyn Analyzer>,
+    splitter: Arc<dyn SentenceSplitter>,
+    disambiguator: Option<Arc<dyn Disambiguator>>,
     checkers: Vec<Arc<dyn Checker>>,
     filters: Option<FilterChain>,
+    /// Registre des règles, construit à la première demande
+    registry: OnceLock<RuleRegistry>,
+    /// Observateur des checkers (voir `set_observer`)
+    observer: OnceLock<Arc<dyn CheckObserver>>,
 }
 
 impl Pipeline {
@@ -59,20 +102,55 @@ impl Pipeline {
         Self {
             tokenizer: Arc::new(tokenizer),
             analyzer: Arc::new(analyzer),
+            splitter: Arc::new(SimpleSentenceSplitter::new()),
+            disambiguator: None,
+            checkers: Vec::new(),
+            filters: None,
+            registry: OnceLock::new(),
+            observer: OnceLock::new(),
+        }
+    }
+
+    /// Crée un pipeline à partir d'un tokenizer et d'un analyzer partagés
+    ///
+    /// Permet à plusieurs pipelines (ex: variantes en-US / en-GB) de
+    /// réutiliser le même POS tagger sans le reconstruire.
+    pub fn from_shared(tokenizer: Arc<dyn Tokenizer>, analyzer: Arc<dyn Analyzer>) -> Self {
+        Self {
+            tokenizer,
+            analyzer,
+            splitter: Arc::new(SimpleSentenceSplitter::new()),
+            disambiguator: None,
             checkers: Vec::new(),
             filters: None,
+            registry: OnceLock::new(),
+            observer: OnceLock::new(),
         }
     }
 
+    /// Remplace le découpeur de phrases (par défaut `SimpleSentenceSplitter`)
+    pub fn with_sentence_splitter(mut self, splitter: impl SentenceSplitter + 'static) -> Self {
+        self.splitter = Arc::new(splitter);
+        self
+    }
+
+    /// Ajoute une étape de désambiguïsation entre l'analyse et les checkers
+    pub fn with_disambiguator(mut self, disambiguator: impl Disambiguator + 'static) -> Self {
+        self.disambiguator = Some(Arc::new(disambiguator));
+        self
+    }
+
     /// Ajoute un checker au pipeline (builder pattern)
     pub fn with_checker(mut self, checker: impl Checker + 'static) -> Self {
         self.checkers.push(Arc::new(checker));
+        self.registry = OnceLock::new();
         self
     }
 
     /// Ajoute plusieurs checkers
     pub fn with_checkers(mut self, checkers: Vec<Arc<dyn Checker>>) -> Self {
         self.checkers.extend(checkers);
+        self.registry = OnceLock::new();
         self
     }
 
@@ -87,38 +165,272 @@ impl Pipeline {
         self.filters = Some(crate::filter::default_filters());
         self
     }
+
+    /// Métadonnées de toutes les règles des checkers du pipeline
+    ///
+    /// Construit une seule fois ; en cas d'ID dupliqué, le premier checker
+    /// ajouté l'emporte.
+    pub fn rule_registry(&self) -> &RuleRegistry {
+        self.registry.get_or_init(|| {
+            let mut registry = RuleRegistry::new();
+            for checker in &self.checkers {
+                registry.extend(checker.rules());
+            }
+            registry
+        })
+    }
 }
 
-impl GrammarChecker for Pipeline {
-    fn check_text(&self, text: &str) -> CheckResult {
+impl Pipeline {
+    /// Vérifie le texte en n'exécutant que les règles de niveau <= `level`
+    ///
+    /// `CheckLevel::Default` écarte les règles « picky » (style strict),
+    /// comme le paramètre `level` de LanguageTool.
+    pub fn check_text_with_level(&self, text: &str, level: CheckLevel) -> CheckResult {
+        self.run_checkers(text, level, |_| true)
+    }
+
+    /// Vérifie le texte avec toutes les règles en mesurant chaque étape
+    ///
+    /// Plus lent qu'une vérification normale (chaque checker et chaque règle
+    /// détaillée sont chronométrés) : pour le diagnostic uniquement.
+    pub fn check_text_profiled(&self, text: &str) -> (CheckResult, CheckProfile) {
+        self.check_text_profiled_with_level(text, CheckLevel::Picky, DEFAULT_TOP_RULES)
+    }
+
+    /// Comme `check_text_profiled`, au niveau `level`, en gardant les
+    /// `top_rules` règles les plus lentes de chaque checker
+    pub fn check_text_profiled_with_level(
+        &self,
+        text: &str,
+        level: CheckLevel,
+        top_rules: usize,
+    ) -> (CheckResult, CheckProfile) {
+        let (result, profile) = self.run(text, level, |_| true, Some(top_rules));
+        (result, profile.unwrap_or_default())
+    }
+
+    /// Vérifie un texte annoté (Markdown, HTML, LaTeX, paramètre `data`)
+    ///
+    /// Les checkers ne voient que le texte brut ; les spans des matches
+    /// sont ceux du document original.
+    pub fn check_annotated(&self, text: &AnnotatedText) -> CheckResult {
+        self.check_annotated_with_level(text, CheckLevel::Picky)
+    }
+
+    pub fn check_annotated_with_level(&self, text: &AnnotatedText, level: CheckLevel) -> CheckResult {
+        let mut result = self.check_text_with_level(text.plain_text(), level);
+        text.map_matches(&mut result.matches);
+        result
+    }
+
+    /// Comme `check_text_profiled_with_level`, sur un texte annoté
+    pub fn check_annotated_profiled_with_level(
+        &self,
+        text: &AnnotatedText,
+        level: CheckLevel,
+        top_rules: usize,
+    ) -> (CheckResult, CheckProfile) {
+        let (mut result, profile) = self.check_text_profiled_with_level(text.plain_text(), level, top_rules);
+        text.map_matches(&mut result.matches);
+        (result, profile)
+    }
+
+    /// Vérifie plusieurs documents avec toutes les règles
+    pub fn check_batch(&self, texts: &[&str]) -> Vec<CheckResult> {
+        self.check_batch_with_level(texts, CheckLevel::Picky)
+    }
+
+    /// Vérifie plusieurs documents en parallèle (voir `par_map_bounded`)
+    ///
+    /// Les documents sont répartis entre les threads en plus des checkers ;
+    /// `results[i]` correspond à `texts[i]`.
+    pub fn check_batch_with_level(&self, texts: &[&str], level: CheckLevel) -> Vec<CheckResult> {
+        par_map_bounded(texts, |text| self.check_text_with_level(text, level))
+    }
+
+    /// N'exécute que les checkers locaux (tout sauf les checkers de document)
+    ///
+    /// Le résultat d'un paragraphe ne dépend que de son contenu, ce qui
+    /// permet de le mettre en cache (voir `IncrementalChecker`).
+    pub fn check_paragraph_with_level(&self, text: &str, level: CheckLevel) -> CheckResult {
+        self.run_checkers(text, level, |checker| !checker.is_document_level())
+    }
+
+    /// Zones masquées du document entier (vide sans filtres)
+    ///
+    /// Un bloc de code ou une citation peut couvrir plusieurs paragraphes :
+    /// les résultats de `check_paragraph_with_level` doivent être filtrés
+    /// avec les masques du document, pas seulement ceux du paragraphe.
+    pub fn find_masks(&self, text: &str) -> Vec<MaskedRegion> {
+        self.filters.as_ref().map(|f| f.find_all_masks(text)).unwrap_or_default()
+    }
+
+    /// N'exécute que les checkers qui ont besoin du document entier
+    pub fn check_document_with_level(&self, text: &str, level: CheckLevel) -> CheckResult {
+        self.run_checkers(text, level, |checker| checker.is_document_level())
+    }
+
+    /// Nombre de checkers du pipeline
+    pub fn checker_count(&self) -> usize {
+        self.checkers.len()
+    }
+
+    /// Branche un observateur qui reçoit la durée de chaque checker
+    ///
+    /// Possible une seule fois, après la construction (les pipelines sont
+    /// partagés en `Arc`) ; renvoie false si un observateur est déjà posé.
+    /// Sans observateur, les checkers ne sont pas chronométrés.
+    pub fn set_observer(&self, observer: Arc<dyn CheckObserver>) -> bool {
+        self.observer.set(observer).is_ok()
+    }
+
+    /// Vrai si au moins un checker travaille sur le document entier
+    pub fn has_document_checkers(&self) -> bool {
+        self.checkers.iter().any(|checker| checker.is_document_level())
+    }
+
+    fn run_checkers(
+        &self,
+        text: &str,
+        level: CheckLevel,
+        select: impl Fn(&dyn Checker) -> bool + Sync,
+    ) -> CheckResult {
+        self.run(text, level, select, None).0
+    }
+
+    /// Exécute les étapes du pipeline ; avec `top_rules`, chronomètre
+    /// chaque étape et chaque checker
+    fn run(
+        &self,
+        text: &str,
+        level: CheckLevel,
+        select: impl Fn(&dyn Checker) -> bool + Sync,
+        top_rules: Option<usize>,
+    ) -> (CheckResult, Option<CheckProfile>) {
+        let start = Instant::now();
+        let mut profile = top_rules.map(|_| CheckProfile::default());
+        // Temps écoulé depuis le tour précédent (zéro sans profilage)
+        let mut last = start;
+        let timed = profile.is_some();
+        let mut lap = || {
+            if !timed {
+                return Duration::ZERO;
+            }
+            let now = Instant::now();
+            let elapsed = now - last;
+            last = now;
+            elapsed
+        };
+
         // Étape 0: Find masked regions (if filters are configured)
         let masks = self.filters.as_ref().map(|f| f.find_all_masks(text));
+        let mut filter_time = lap();
 
         // Étape 1: Tokenize
         let tokens = self.tokenizer.tokenize(text);
+        let tokenize_time = lap();
 
         // Étape 2: Analyze
-        let analyzed = self.analyzer.analyze(tokens);
+        let mut analyzed = self.analyzer.analyze(tokens);
+
+        // Étape 2 bis: Sentences
+        self.splitter.segment(text, &mut analyzed);
+
+        // Étape 2 ter: Disambiguate
+        if let Some(disambiguator) = &self.disambiguator {
+            disambiguator.disambiguate(&mut analyzed);
+        }
+
+        let analyze_time = lap();
 
         // Étape 3: Check (tous les checkers en parallèle avec rayon)
-        let results: Vec<CheckResult> = self
+        let results: Vec<(CheckResult, Option<CheckerProfile>)> = self
             .checkers
             .par_iter()
-            .map(|checker| checker.check(text, &analyzed))
+            .filter(|checker| select(checker.as_ref()))
+            .map(|checker| self.run_checker(checker.as_ref(), text, &analyzed, level, top_rules))
             .collect();
+        lap();
 
         let mut result = CheckResult::new();
-        for r in results {
+        let mut checker_profiles = Vec::new();
+        for (r, checker_profile) in results {
             result.merge(r);
+            checker_profiles.extend(checker_profile);
+        }
+
+        // Étape 3 bis: Rien à signaler sur les tokens immunisés
+        let immunized: Vec<_> = analyzed
+            .iter()
+            .filter(|t| t.immunized)
+            .map(|t| t.token.span.clone())
+            .collect();
+        if !immunized.is_empty() {
+            result.matches.retain(|m| {
+                !immunized.iter().any(|span| span.start < m.span.end && m.span.start < span.end)
+            });
         }
 
         // Étape 4: Filter out matches in masked regions
+        lap();
         if let Some(ref masks) = masks {
             result = result.filter_masked(masks);
         }
+        filter_time += lap();
 
         // Nettoyer et trier
         result.sort_and_dedupe();
-        result
+
+        if let Some(profile) = profile.as_mut() {
+            *profile = CheckProfile {
+                total: start.elapsed(),
+                filter: filter_time,
+                tokenize: tokenize_time,
+                analyze: analyze_time,
+                checkers: checker_profiles,
+            };
+        }
+        (result, profile)
+    }
+
+    /// Exécute un checker, chronométré s'il y a un observateur ou un profil
+    fn run_checker(
+        &self,
+        checker: &dyn Checker,
+        text: &str,
+        tokens: &[AnalyzedToken],
+        level: CheckLevel,
+        top_rules: Option<usize>,
+    ) -> (CheckResult, Option<CheckerProfile>) {
+        let observer = self.observer.get();
+        if top_rules.is_none() && observer.is_none() {
+            return (checker.check_with_level(text, tokens, level), None);
+        }
+
+        let start = Instant::now();
+        let (result, timings) = match top_rules {
+            Some(_) => checker.check_profiled(text, tokens, level),
+            None => (checker.check_with_level(text, tokens, level), Vec::new()),
+        };
+        let elapsed = start.elapsed();
+        if let Some(observer) = observer {
+            observer.checker_done(checker.name(), elapsed, &result);
+        }
+
+        let profile = top_rules.map(|n| CheckerProfile {
+            name: checker.name(),
+            elapsed,
+            matches: result.matches.len(),
+            slowest_rules: slowest_rules(timings, n),
+        });
+        (result, profile)
+    }
+}
+
+impl GrammarChecker for Pipeline {
+    /// Vérifie le texte avec toutes les règles, y compris les règles « picky »
+    fn check_text(&self, text: &str) -> CheckResult {
+        self.check_text_with_level(text, CheckLevel::Picky)
     }
 }
@@ -0,0 +1,116 @@
+//! Profilage d'une vérification - où part le temps du pipeline
+//!
+//! `Pipeline::check_text_profiled` mesure chaque étape (filtres,
+//! tokenisation, analyse) et chaque checker ; les checkers à nombreuses
+//! règles (`DynamicPatternChecker`) détaillent aussi leurs règles les plus
+//! lentes.
+
+use std::fmt;
+use std::time::Duration;
+
+/// Nombre de règles les plus lentes gardées par checker par défaut
+pub const DEFAULT_TOP_RULES: usize = 10;
+
+/// Temps d'une vérification, étape par étape
+#[derive(Debug, Clone, Default)]
+pub struct CheckProfile {
+    /// Vérification complète
+    pub total: Duration,
+    /// Recherche des zones masquées (URLs, code, ...) et retrait des matches
+    pub filter: Duration,
+    pub tokenize: Duration,
+    /// Analyse (POS), découpage en phrases et désambiguïsation
+    pub analyze: Duration,
+    /// Checkers dans l'ordre du pipeline (exécutés en parallèle : la somme
+    /// dépasse le temps réel de l'étape)
+    pub checkers: Vec<CheckerProfile>,
+}
+
+/// Temps et matches d'un checker
+#[derive(Debug, Clone)]
+pub struct CheckerProfile {
+    /// `Checker::name`
+    pub name: &'static str,
+    /// Temps réel du checker
+    pub elapsed: Duration,
+    /// Matches avant dédoublonnage et filtres
+    pub matches: usize,
+    /// Règles les plus lentes, la plus lente d'abord (vide si le checker
+    /// ne les détaille pas)
+    pub slowest_rules: Vec<RuleTiming>,
+}
+
+/// Temps et matches d'une règle
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleTiming {
+    pub rule_id: String,
+    pub elapsed: Duration,
+    pub matches: usize,
+}
+
+/// Garde les `n` règles les plus lentes, la plus lente d'abord
+pub fn slowest_rules(mut timings: Vec<RuleTiming>, n: usize) -> Vec<RuleTiming> {
+    timings.sort_by(|a, b| b.elapsed.cmp(&a.elapsed).then_with(|| a.rule_id.cmp(&b.rule_id)));
+    timings.truncate(n);
+    timings
+}
+
+impl CheckProfile {
+    /// Checkers du plus lent au plus rapide
+    pub fn checkers_by_time(&self) -> Vec<&CheckerProfile> {
+        let mut checkers: Vec<_> = self.checkers.iter().collect();
+        checkers.sort_by_key(|checker| std::cmp::Reverse(checker.elapsed));
+        checkers
+    }
+}
+
+/// Rapport texte (sortie `--profile` du CLI)
+impl fmt::Display for CheckProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+        writeln!(f, "total      {:>9.3} ms", ms(self.total))?;
+        writeln!(f, "  filter   {:>9.3} ms", ms(self.filter))?;
+        writeln!(f, "  tokenize {:>9.3} ms", ms(self.tokenize))?;
+        writeln!(f, "  analyze  {:>9.3} ms", ms(self.analyze))?;
+        writeln!(f, "checkers (parallel)")?;
+        for checker in self.checkers_by_time() {
+            writeln!(f, "  {:<28} {:>9.3} ms {:>5} matches", checker.name, ms(checker.elapsed), checker.matches)?;
+            for rule in &checker.slowest_rules {
+                writeln!(f, "    {:<26} {:>9.3} ms {:>5} matches", rule.rule_id, ms(rule.elapsed), rule.matches)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(rule_id: &str, micros: u64) -> RuleTiming {
+        RuleTiming { rule_id: rule_id.to_string(), elapsed: Duration::from_micros(micros), matches: 0 }
+    }
+
+    #[test]
+    fn test_slowest_rules() {
+        let timings = vec![timing("A", 5), timing("B", 50), timing("C", 20), timing("D", 50)];
+        let ids: Vec<_> = slowest_rules(timings, 3).into_iter().map(|t| t.rule_id).collect();
+        assert_eq!(ids, vec!["B", "D", "C"]);
+    }
+
+    #[test]
+    fn test_report_lists_checkers_slowest_first() {
+        let checker = |name, micros| CheckerProfile {
+            name,
+            elapsed: Duration::from_micros(micros),
+            matches: 1,
+            slowest_rules: Vec::new(),
+        };
+        let profile = CheckProfile {
+            checkers: vec![checker("SpellChecker", 100), checker("DynamicPatternChecker", 900)],
+            ..Default::default()
+        };
+        let report = profile.to_string();
+        assert!(report.find("DynamicPatternChecker").unwrap() < report.find("SpellChecker").unwrap());
+    }
+}
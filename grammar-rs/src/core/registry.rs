@@ -0,0 +1,332 @@
+//! Registre des règles - métadonnées de toutes les règles d'un pipeline
+//!
+//! Chaque checker décrit les règles qu'il peut émettre (ID, catégorie,
+//! description, activée par défaut, exemples) via `Checker::rules()`.
+//! `Pipeline::rule_registry()` les rassemble dans un `RuleRegistry` pour que
+//! les front-ends puissent lister et catégoriser les règles sans deviner
+//! à partir des préfixes d'ID.
+
+use super::{CheckLevel, IssueType, Severity};
+use std::collections::HashMap;
+
+/// Rule category (LanguageTool category IDs)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RuleCategory {
+    /// Spelling mistakes and typos
+    Typos,
+    /// Grammar errors
+    Grammar,
+    /// Style suggestions
+    Style,
+    /// Typography (quotes, dashes, diacritics)
+    Typography,
+    /// Punctuation and spacing around punctuation
+    Punctuation,
+    /// Capitalization
+    Casing,
+    /// Commonly confused words (their/there, affect/effect)
+    ConfusedWords,
+    /// Redundant phrases
+    Redundancy,
+    /// Repeated words
+    Duplication,
+    /// Consistency within a document (US/UK spelling mix)
+    Consistency,
+    /// Everything else
+    Misc,
+}
+
+impl RuleCategory {
+    /// LanguageTool category ID
+    pub fn id(&self) -> &'static str {
+        match self {
+            RuleCategory::Typos => "TYPOS",
+            RuleCategory::Grammar => "GRAMMAR",
+            RuleCategory::Style => "STYLE",
+            RuleCategory::Typography => "TYPOGRAPHY",
+            RuleCategory::Punctuation => "PUNCTUATION",
+            RuleCategory::Casing => "CASING",
+            RuleCategory::ConfusedWords => "CONFUSED_WORDS",
+            RuleCategory::Redundancy => "REDUNDANCY",
+            RuleCategory::Duplication => "DUPLICATION",
+            RuleCategory::Consistency => "CONSISTENCY",
+            RuleCategory::Misc => "MISC",
+        }
+    }
+
+    /// Human-readable category name
+    pub fn name(&self) -> &'static str {
+        match self {
+            RuleCategory::Typos => "Possible Typo",
+            RuleCategory::Grammar => "Grammar",
+            RuleCategory::Style => "Style",
+            RuleCategory::Typography => "Typography",
+            RuleCategory::Punctuation => "Punctuation",
+            RuleCategory::Casing => "Capitalization",
+            RuleCategory::ConfusedWords => "Commonly Confused Words",
+            RuleCategory::Redundancy => "Redundant Phrases",
+            RuleCategory::Duplication => "Duplication",
+            RuleCategory::Consistency => "Consistency",
+            RuleCategory::Misc => "Miscellaneous",
+        }
+    }
+
+    /// Default issue type of the rules in this category
+    pub fn issue_type(&self) -> IssueType {
+        match self {
+            RuleCategory::Typos => IssueType::Misspelling,
+            RuleCategory::Grammar | RuleCategory::ConfusedWords => IssueType::Grammar,
+            RuleCategory::Style | RuleCategory::Redundancy => IssueType::Style,
+            RuleCategory::Typography | RuleCategory::Punctuation | RuleCategory::Casing => {
+                IssueType::Typographical
+            }
+            RuleCategory::Duplication => IssueType::Duplication,
+            RuleCategory::Consistency => IssueType::Inconsistency,
+            RuleCategory::Misc => IssueType::Uncategorized,
+        }
+    }
+
+    /// Map a LanguageTool `<category name="...">` (English or French) to a category
+    pub fn from_lt_name(name: &str) -> Self {
+        let lower = name.to_lowercase();
+        let has = |words: &[&str]| words.iter().any(|w| lower.contains(w));
+
+        if has(&["typo", "frappe", "spelling", "orthographe"]) {
+            RuleCategory::Typos
+        } else if has(&["confus", "homophone", "paronyme"]) {
+            RuleCategory::ConfusedWords
+        } else if has(&["redundan", "redondan", "pléonasme"]) {
+            RuleCategory::Redundancy
+        } else if has(&["capitali", "majuscule", "casing"]) {
+            RuleCategory::Casing
+        } else if has(&["punctuation", "ponctuation"]) {
+            RuleCategory::Punctuation
+        } else if has(&["typograph"]) {
+            RuleCategory::Typography
+        } else if has(&["style", "plain english", "wikipedia", "nonstandard", "register"]) {
+            RuleCategory::Style
+        } else if has(&["grammar", "grammaire", "accord", "conjugaison", "agreement", "verb"]) {
+            RuleCategory::Grammar
+        } else {
+            RuleCategory::Misc
+        }
+    }
+
+    /// Best-effort category for a rule ID that no checker described
+    pub fn guess(rule_id: &str, severity: Severity) -> Self {
+        match rule_id {
+            "SPELL" | "HUNSPELL" => RuleCategory::Typos,
+            "DOUBLE_SPACE" | "REPEATED_PUNCTUATION" | "MISSING_SPACE_AFTER_PUNCT" | "TYPOGRAPHIC_QUOTES"
+            | "DIACRITICS" | "EN_DIACRITICS" => RuleCategory::Typography,
+            "REPEATED_WORD" => RuleCategory::Duplication,
+            "A_AN" | "IMPROVED_A_AN" | "SUBJECT_VERB_AGREEMENT" | "ITS_ITS" | "YOUR_YOURE"
+            | "THEIR_THEYRE_THERE" | "COMMA_SPLICE" | "LESS_FEWER" | "WHO_WHOM" | "GOOD_WELL"
+            | "DOUBLE_NEGATIVE" | "SENTENCE_FRAGMENT" | "CONTRACTION" | "EN_CONTRACTION" => RuleCategory::Grammar,
+            "EN_CONFUSION" | "FR_CONFUSION" => RuleCategory::ConfusedWords,
+            _ if rule_id.starts_with("FR_") => RuleCategory::Grammar,
+            "PASSIVE_VOICE" | "WORDINESS" | "SENTENCE_LENGTH" | "CLICHE" | "REDUNDANCY" => RuleCategory::Style,
+            _ if rule_id.starts_with("REPLACE_") => RuleCategory::Style,
+            _ if rule_id.starts_with("COHERENCY") || rule_id.starts_with("EN_COHERENCY") => {
+                RuleCategory::Consistency
+            }
+            _ => match severity {
+                Severity::Error => RuleCategory::Grammar,
+                Severity::Warning => RuleCategory::Style,
+                Severity::Hint => RuleCategory::Misc,
+            },
+        }
+    }
+}
+
+/// Split a sub-rule ID `GROUP[n]` into the group ID and LanguageTool's subId
+///
+/// Sub-rules of a `<rulegroup>` are registered under their own ID so that
+/// each keeps its level and default-on state; clients still see the group ID.
+pub fn split_sub_rule_id(id: &str) -> (&str, Option<&str>) {
+    let sub_rule = id
+        .strip_suffix(']')
+        .and_then(|inner| inner.rsplit_once('['))
+        .filter(|(group, sub)| !group.is_empty() && !sub.is_empty() && sub.bytes().all(|b| b.is_ascii_digit()));
+    match sub_rule {
+        Some((group, sub)) => (group, Some(sub)),
+        None => (id, None),
+    }
+}
+
+/// Example sentence attached to a rule
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleExample {
+    pub text: String,
+    /// True if the sentence must NOT trigger the rule
+    pub is_correct: bool,
+    pub correction: Option<String>,
+}
+
+/// Description of a rule a checker may emit
+#[derive(Debug, Clone)]
+pub struct RuleMetadata {
+    pub id: String,
+    pub description: String,
+    pub category: RuleCategory,
+    /// Kind of issue (defaults to the category's issue type)
+    pub issue_type: IssueType,
+    pub level: CheckLevel,
+    /// False for rules that only run when explicitly enabled
+    pub default_on: bool,
+    pub examples: Vec<RuleExample>,
+    /// Page explaining the rule (LanguageTool `<url>`)
+    pub url: Option<String>,
+}
+
+impl RuleMetadata {
+    /// Create metadata for a default-on rule without examples
+    pub fn new(id: impl Into<String>, description: impl Into<String>, category: RuleCategory) -> Self {
+        Self {
+            id: id.into(),
+            description: description.into(),
+            category,
+            issue_type: category.issue_type(),
+            level: CheckLevel::Default,
+            default_on: true,
+            examples: Vec::new(),
+            url: None,
+        }
+    }
+
+    /// Override the issue type derived from the category
+    pub fn with_issue_type(mut self, issue_type: IssueType) -> Self {
+        self.issue_type = issue_type;
+        self
+    }
+
+    /// Set the strictness tier
+    pub fn with_level(mut self, level: CheckLevel) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Set whether the rule runs without being explicitly enabled
+    pub fn with_default_on(mut self, default_on: bool) -> Self {
+        self.default_on = default_on;
+        self
+    }
+
+    /// Attach example sentences
+    pub fn with_examples(mut self, examples: Vec<RuleExample>) -> Self {
+        self.examples = examples;
+        self
+    }
+
+    /// Link to a page explaining the rule
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+}
+
+/// Collection of rule metadata indexed by rule ID
+#[derive(Debug, Clone, Default)]
+pub struct RuleRegistry {
+    rules: Vec<RuleMetadata>,
+    index: HashMap<String, usize>,
+}
+
+impl RuleRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a rule (the first registration of an ID wins)
+    pub fn register(&mut self, rule: RuleMetadata) {
+        if self.index.contains_key(&rule.id) {
+            return;
+        }
+        self.index.insert(rule.id.clone(), self.rules.len());
+        self.rules.push(rule);
+    }
+
+    /// Register several rules
+    pub fn extend(&mut self, rules: impl IntoIterator<Item = RuleMetadata>) {
+        for rule in rules {
+            self.register(rule);
+        }
+    }
+
+    /// Look up a rule by ID
+    pub fn get(&self, id: &str) -> Option<&RuleMetadata> {
+        self.index.get(id).map(|&i| &self.rules[i])
+    }
+
+    /// Whether a rule runs without being explicitly enabled (unknown rules do)
+    pub fn is_default_on(&self, id: &str) -> bool {
+        self.get(id).is_none_or(|rule| rule.default_on)
+    }
+
+    /// Iterate over all rules in registration order
+    pub fn iter(&self) -> impl Iterator<Item = &RuleMetadata> {
+        self.rules.iter()
+    }
+
+    /// Number of registered rules
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Check if the registry is empty
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_registration_wins() {
+        let mut registry = RuleRegistry::new();
+        registry.register(RuleMetadata::new("SPELL", "Spelling", RuleCategory::Typos));
+        registry.register(RuleMetadata::new("SPELL", "Other", RuleCategory::Misc));
+
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.get("SPELL").unwrap().description, "Spelling");
+        assert!(registry.get("UNKNOWN").is_none());
+    }
+
+    #[test]
+    fn test_default_on() {
+        let mut registry = RuleRegistry::new();
+        registry.register(RuleMetadata::new("OFF_RULE", "Off", RuleCategory::Style).with_default_on(false));
+
+        assert!(!registry.is_default_on("OFF_RULE"));
+        assert!(registry.is_default_on("UNKNOWN"));
+        assert_eq!(registry.get("OFF_RULE").unwrap().issue_type, IssueType::Style);
+    }
+
+    #[test]
+    fn test_from_lt_name() {
+        assert_eq!(RuleCategory::from_lt_name("Possible Typo"), RuleCategory::Typos);
+        assert_eq!(RuleCategory::from_lt_name("Commonly Confused Words"), RuleCategory::ConfusedWords);
+        assert_eq!(RuleCategory::from_lt_name("Grammaire"), RuleCategory::Grammar);
+        assert_eq!(RuleCategory::from_lt_name("Redundant Phrases"), RuleCategory::Redundancy);
+        assert_eq!(RuleCategory::from_lt_name("Semantics"), RuleCategory::Misc);
+    }
+
+    #[test]
+    fn test_split_sub_rule_id() {
+        assert_eq!(split_sub_rule_id("GROUP[2]"), ("GROUP", Some("2")));
+        assert_eq!(split_sub_rule_id("GROUP"), ("GROUP", None));
+        assert_eq!(split_sub_rule_id("GROUP[x]"), ("GROUP[x]", None));
+        assert_eq!(split_sub_rule_id("[1]"), ("[1]", None));
+    }
+
+    #[test]
+    fn test_guess() {
+        assert_eq!(RuleCategory::guess("SPELL", Severity::Error), RuleCategory::Typos);
+        assert_eq!(RuleCategory::guess("DOUBLE_SPACE", Severity::Warning), RuleCategory::Typography);
+        assert_eq!(RuleCategory::guess("FR_ACCORD", Severity::Warning), RuleCategory::Grammar);
+        assert_eq!(RuleCategory::guess("EN_COHERENCY_ISE", Severity::Warning), RuleCategory::Consistency);
+        assert_eq!(RuleCategory::guess("UNKNOWN", Severity::Warning), RuleCategory::Style);
+        assert_eq!(RuleCategory::guess("UNKNOWN", Severity::Hint), RuleCategory::Misc);
+    }
+}
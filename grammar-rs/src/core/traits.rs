@@ -4,7 +4,10 @@
 //! Tu peux implémenter ces traits de manière simple au début,
 //! puis les remplacer par des versions plus sophistiquées.
 
-use super::{AnalyzedToken, CheckResult, Token};
+use super::profile::RuleTiming;
+use super::registry::RuleMetadata;
+use super::{AnalyzedToken, CheckLevel, CheckResult, Token};
+use std::ops::Range;
 
 /// Étape 1: Découper le texte en tokens
 pub trait Tokenizer: Send + Sync {
@@ -16,9 +19,79 @@ pub trait Analyzer: Send + Sync {
     fn analyze<'a>(&self, tokens: Vec<Token<'a>>) -> Vec<AnalyzedToken<'a>>;
 }
 
+/// Étape 2 bis: Découper le texte en phrases
+///
+/// Les spans (sans les blancs qui les entourent) sont reportés par le
+/// pipeline sur les tokens analysés (`AnalyzedToken::sentence`).
+pub trait SentenceSplitter: Send + Sync {
+    fn split(&self, text: &str) -> Vec<Range<usize>>;
+
+    /// Découpe `text` et reporte les phrases sur ses tokens
+    fn segment(&self, text: &str, tokens: &mut [AnalyzedToken]) {
+        super::assign_sentences(tokens, &self.split(text));
+    }
+}
+
+/// Étape 2 ter: Lever les ambiguïtés selon le contexte
+///
+/// Corrige les POS tags (et lemmes) posés mot à mot par l'`Analyzer`, ou
+/// immunise des tokens, avant le passage des checkers.
+pub trait Disambiguator: Send + Sync {
+    fn disambiguate(&self, tokens: &mut [AnalyzedToken]);
+}
+
 /// Étape 3: Détecter les erreurs
 pub trait Checker: Send + Sync {
     fn check(&self, text: &str, tokens: &[AnalyzedToken]) -> CheckResult;
+
+    /// Niveau de sévérité du checker dans son ensemble
+    fn level(&self) -> CheckLevel {
+        CheckLevel::Default
+    }
+
+    /// Vérifie en n'appliquant que les règles de niveau <= `level`
+    ///
+    /// Les checkers qui mélangent plusieurs niveaux (RuleChecker,
+    /// DynamicPatternChecker) surchargent cette méthode pour filtrer règle
+    /// par règle.
+    fn check_with_level(&self, text: &str, tokens: &[AnalyzedToken], level: CheckLevel) -> CheckResult {
+        if self.level() <= level {
+            self.check(text, tokens)
+        } else {
+            CheckResult::new()
+        }
+    }
+
+    /// Comme `check_with_level`, avec le temps de chaque règle
+    ///
+    /// Utilisé par `Pipeline::check_text_profiled`. Par défaut les règles
+    /// ne sont pas détaillées ; les checkers à nombreuses règles
+    /// (DynamicPatternChecker) surchargent cette méthode.
+    fn check_profiled(&self, text: &str, tokens: &[AnalyzedToken], level: CheckLevel) -> (CheckResult, Vec<RuleTiming>) {
+        (self.check_with_level(text, tokens, level), Vec::new())
+    }
+
+    /// Vrai si le checker a besoin du document entier (cohérence entre
+    /// paragraphes) : la vérification incrémentale ne le lance jamais
+    /// paragraphe par paragraphe
+    fn is_document_level(&self) -> bool {
+        false
+    }
+
+    /// Nom du checker dans les métriques : nom du type par défaut
+    /// (`DynamicPatternChecker`, `SpellChecker`, ...)
+    fn name(&self) -> &'static str {
+        let name = std::any::type_name::<Self>();
+        name.rsplit("::").next().unwrap_or(name)
+    }
+
+    /// Métadonnées des règles que ce checker peut émettre
+    ///
+    /// Vide par défaut : les règles non décrites sont catégorisées à partir
+    /// de leur ID par les front-ends.
+    fn rules(&self) -> Vec<RuleMetadata> {
+        Vec::new()
+    }
 }
 
 /// Étape 4: Générer des suggestions (optionnel, peut être intégré au Checker)
@@ -11,12 +11,16 @@ use std::fs::File;
 use std::io::{self, BufRead, BufReader, BufWriter, Write};
 use std::path::Path;
 
+use super::hunspell::{read_hunspell, HunspellStats};
+use super::levenshtein::LevenshteinAutomaton;
+
 /// Error type for dictionary operations
 #[derive(Debug)]
 pub enum DictError {
     Io(io::Error),
     Fst(fst::Error),
     NotSorted(String),
+    Hunspell(String),
 }
 
 impl From<io::Error> for DictError {
@@ -37,6 +41,7 @@ impl std::fmt::Display for DictError {
             DictError::Io(e) => write!(f, "IO error: {}", e),
             DictError::Fst(e) => write!(f, "FST error: {}", e),
             DictError::NotSorted(w) => write!(f, "Words not sorted at: {}", w),
+            DictError::Hunspell(msg) => write!(f, "Hunspell error: {}", msg),
         }
     }
 }
@@ -131,6 +136,50 @@ impl FstDictionary {
         Self::from_sorted_iter(words)
     }
 
+    /// Build dictionary from several word sources (unsorted, merged)
+    ///
+    /// Each line contributes its first tab-separated column, so plain
+    /// wordlists and `form\tlemma\ttag` lexicons (Lefff TSV) both work.
+    pub fn from_wordlists<I, P>(paths: I) -> Result<Self, DictError>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let mut words = Vec::new();
+        for path in paths {
+            let reader = BufReader::new(File::open(path)?);
+            for line in reader.lines() {
+                let line = line?;
+                let word = line.split('\t').next().unwrap_or("").trim();
+                if !word.is_empty() && !word.starts_with('#') {
+                    words.push(word.to_string());
+                }
+            }
+        }
+
+        Self::from_iter(words)
+    }
+
+    /// Rebuild the dictionary with extra (unsorted) words merged in
+    pub fn with_words<I, S>(self, words: I) -> Result<Self, DictError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut merged: Vec<String> = self.set.into_stream().into_strs()?;
+        merged.extend(words.into_iter().map(|w| w.as_ref().to_string()));
+        Self::from_iter(merged)
+    }
+
+    /// Build dictionary from a Hunspell `.dic` / `.aff` pair
+    ///
+    /// Affix rules are expanded into the full form set; returns the
+    /// dictionary and the expansion stats.
+    pub fn from_hunspell<P: AsRef<Path>>(dic_path: P, aff_path: P) -> Result<(Self, HunspellStats), DictError> {
+        let (forms, stats) = read_hunspell(dic_path, aff_path)?;
+        Ok((Self::from_iter(forms)?, stats))
+    }
+
     /// Load dictionary from a pre-built FST file
     pub fn from_fst<P: AsRef<Path>>(path: P) -> Result<Self, DictError> {
         let data = std::fs::read(path)?;
@@ -174,10 +223,10 @@ impl FstDictionary {
         let prefix_lower = prefix.to_lowercase();
         let mut results = Vec::new();
 
-        // Create a range that matches the prefix
+        // Start at the prefix and stop at the first word without it
+        // (no upper bound: accented letters sort after any ASCII sentinel)
         let mut stream = self.set.range()
             .ge(&prefix_lower)
-            .lt(&format!("{}~", prefix_lower)) // ~ is after z in ASCII
             .into_stream();
 
         while let Some(word) = stream.next() {
@@ -193,6 +242,24 @@ impl FstDictionary {
         results
     }
 
+    /// Find all words within `max_distance` edits, with their distance
+    ///
+    /// Uses a Levenshtein automaton: only the FST branches that can still
+    /// match are visited.
+    pub fn fuzzy(&self, word: &str, max_distance: usize) -> Vec<(String, usize)> {
+        let automaton = LevenshteinAutomaton::new(&word.to_lowercase(), max_distance);
+        let mut stream = self.set.search_with_state(automaton).into_stream();
+        let mut results = Vec::new();
+
+        while let Some((key, state)) = stream.next() {
+            if let (Ok(s), Some(state)) = (std::str::from_utf8(key), state) {
+                results.push((s.to_string(), state.distance()));
+            }
+        }
+
+        results
+    }
+
     /// Get memory usage estimate in bytes
     pub fn memory_usage(&self) -> usize {
         self.set.as_fst().as_bytes().len()
@@ -228,6 +295,38 @@ mod tests {
         assert!(!dict.contains("grape"));
     }
 
+    #[test]
+    fn test_from_wordlists_merges_sources() {
+        let mut list = tempfile::NamedTempFile::new().unwrap();
+        writeln!(list, "# comment\nzèbre\nabricot").unwrap();
+        let mut lexicon = tempfile::NamedTempFile::new().unwrap();
+        writeln!(lexicon, "mangeons\tmanger\tV ind pres 1 p\nabricot\tabricot\tN m s").unwrap();
+
+        let dict = FstDictionary::from_wordlists([list.path(), lexicon.path()]).unwrap();
+        assert_eq!(dict.len(), 3);
+        assert!(dict.contains("mangeons"));
+        assert!(dict.contains("Zèbre"));
+        assert!(!dict.contains("manger"));
+
+        let dict = dict.with_words(["peut-être", "Être"]).unwrap();
+        assert_eq!(dict.len(), 5);
+        assert!(dict.contains("peut-être") && dict.contains("être") && dict.contains("abricot"));
+    }
+
+    #[test]
+    fn test_prefix_with_accented_continuation() {
+        let dict = FstDictionary::from_iter(["mat", "maïs", "mère", "nid"]).unwrap();
+        assert_eq!(dict.words_with_prefix("ma"), vec!["mat", "maïs"]);
+        assert_eq!(dict.words_with_prefix("m"), vec!["mat", "maïs", "mère"]);
+    }
+
+    #[test]
+    fn test_fuzzy() {
+        let dict = FstDictionary::from_iter(["library", "liberty", "literary", "lobby"]).unwrap();
+        assert_eq!(dict.fuzzy("Libary", 1), vec![("library".to_string(), 1)]);
+        assert_eq!(dict.fuzzy("libary", 2).len(), 2);
+    }
+
     #[test]
     fn test_from_unsorted_iter() {
         let words = ["cherry", "apple", "banana", "apple"]; // unsorted + duplicate
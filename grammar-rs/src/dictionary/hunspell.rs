@@ -0,0 +1,506 @@
+//! Hunspell `.dic` / `.aff` importer
+//!
+//! Expands every stem of a Hunspell dictionary with its affix rules into
+//! the full set of word forms, ready to be stored in an `FstDictionary`.
+//!
+//! Supported `.aff` directives:
+//! - `SET` (UTF-8, ISO8859-1, ISO8859-15)
+//! - `FLAG` (default single-char, `long`, `num`, `UTF-8`)
+//! - `AF` flag aliases
+//! - `PFX` / `SFX` with strip, append, conditions, cross product and
+//!   continuation classes (one extra level)
+//! - `NEEDAFFIX`, `FORBIDDENWORD`, `ONLYINCOMPOUND`
+//!
+//! Compounding, `REP`/`ICONV` and morphological fields are ignored.
+
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use super::fst_dict::DictError;
+
+/// An affix flag, normalized whatever the `FLAG` mode
+type Flag = u64;
+
+/// How flags are written in the `.dic` and `.aff` files
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum FlagMode {
+    /// One character per flag (default, also `FLAG UTF-8`)
+    #[default]
+    Char,
+    /// Two characters per flag (`FLAG long`)
+    Long,
+    /// Comma-separated numbers (`FLAG num`)
+    Num,
+}
+
+impl FlagMode {
+    fn parse(self, flags: &str) -> Vec<Flag> {
+        match self {
+            FlagMode::Char => flags.chars().map(|c| c as Flag).collect(),
+            FlagMode::Long => {
+                let chars: Vec<char> = flags.chars().collect();
+                chars.chunks(2)
+                    .map(|pair| pair.iter().fold(0, |acc, &c| (acc << 32) | c as Flag))
+                    .collect()
+            }
+            FlagMode::Num => flags.split(',').filter_map(|n| n.trim().parse().ok()).collect(),
+        }
+    }
+
+    fn parse_one(self, flag: &str) -> Option<Flag> {
+        self.parse(flag).first().copied()
+    }
+}
+
+/// One element of an affix condition (`.`, `x`, `[abc]` or `[^abc]`)
+#[derive(Debug, Clone, PartialEq)]
+enum CondChar {
+    Any,
+    Set { chars: Vec<char>, negated: bool },
+}
+
+impl CondChar {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            CondChar::Any => true,
+            CondChar::Set { chars, negated } => chars.contains(&c) != *negated,
+        }
+    }
+}
+
+fn parse_condition(condition: &str) -> Vec<CondChar> {
+    if condition == "." {
+        return Vec::new();
+    }
+
+    let mut result = Vec::new();
+    let mut chars = condition.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '.' => result.push(CondChar::Any),
+            '[' => {
+                let mut set = Vec::new();
+                let mut negated = false;
+                for c in chars.by_ref() {
+                    match c {
+                        ']' => break,
+                        '^' if set.is_empty() && !negated => negated = true,
+                        _ => set.push(c),
+                    }
+                }
+                result.push(CondChar::Set { chars: set, negated });
+            }
+            _ => result.push(CondChar::Set { chars: vec![c], negated: false }),
+        }
+    }
+    result
+}
+
+/// A single PFX/SFX rule line
+#[derive(Debug, Clone)]
+struct AffixRule {
+    strip: String,
+    append: String,
+    condition: Vec<CondChar>,
+    /// Continuation classes (`append/FLAGS`)
+    continuation: Vec<Flag>,
+}
+
+impl AffixRule {
+    /// Apply as a suffix: the condition is checked on the end of the word
+    fn apply_suffix(&self, word: &str) -> Option<String> {
+        let chars: Vec<char> = word.chars().collect();
+        if chars.len() < self.condition.len() || !word.ends_with(&self.strip) {
+            return None;
+        }
+        let tail = &chars[chars.len() - self.condition.len()..];
+        if !self.condition.iter().zip(tail).all(|(cond, &c)| cond.matches(c)) {
+            return None;
+        }
+        let base = &word[..word.len() - self.strip.len()];
+        if base.is_empty() && self.append.is_empty() {
+            return None;
+        }
+        Some(format!("{}{}", base, self.append))
+    }
+
+    /// Apply as a prefix: the condition is checked on the start of the word
+    fn apply_prefix(&self, word: &str) -> Option<String> {
+        let chars: Vec<char> = word.chars().collect();
+        if chars.len() < self.condition.len() || !word.starts_with(&self.strip) {
+            return None;
+        }
+        if !self.condition.iter().zip(&chars).all(|(cond, &c)| cond.matches(c)) {
+            return None;
+        }
+        let base = &word[self.strip.len()..];
+        if base.is_empty() && self.append.is_empty() {
+            return None;
+        }
+        Some(format!("{}{}", self.append, base))
+    }
+}
+
+/// A PFX/SFX class: every rule sharing one flag
+#[derive(Debug, Clone)]
+struct AffixClass {
+    cross_product: bool,
+    rules: Vec<AffixRule>,
+}
+
+/// Parsed `.aff` file
+#[derive(Debug, Default)]
+pub struct AffixFile {
+    flag_mode: FlagMode,
+    aliases: Vec<Vec<Flag>>,
+    prefixes: HashMap<Flag, AffixClass>,
+    suffixes: HashMap<Flag, AffixClass>,
+    need_affix: Option<Flag>,
+    forbidden: Option<Flag>,
+    only_in_compound: Option<Flag>,
+}
+
+impl AffixFile {
+    /// Parse the text of an `.aff` file
+    pub fn parse(text: &str) -> Self {
+        let mut aff = AffixFile::default();
+        let mut lines = text.lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'));
+
+        while let Some(line) = lines.next() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                ["FLAG", mode, ..] => {
+                    aff.flag_mode = match mode.to_ascii_lowercase().as_str() {
+                        "long" => FlagMode::Long,
+                        "num" => FlagMode::Num,
+                        _ => FlagMode::Char,
+                    };
+                }
+                // "AF n" header followed by n "AF flags" lines
+                ["AF", count] if aff.aliases.is_empty() && count.parse::<usize>().is_ok() => {
+                    let count: usize = count.parse().unwrap_or(0);
+                    for alias_line in lines.by_ref().take(count) {
+                        let flags = alias_line.split_whitespace().nth(1).unwrap_or("");
+                        aff.aliases.push(aff.flag_mode.parse(flags));
+                    }
+                }
+                ["NEEDAFFIX", flag, ..] | ["PSEUDOROOT", flag, ..] => {
+                    aff.need_affix = aff.flag_mode.parse_one(flag);
+                }
+                ["FORBIDDENWORD", flag, ..] => aff.forbidden = aff.flag_mode.parse_one(flag),
+                ["ONLYINCOMPOUND", flag, ..] => aff.only_in_compound = aff.flag_mode.parse_one(flag),
+                [kind @ ("PFX" | "SFX"), flag, cross, count, ..] if count.parse::<usize>().is_ok() => {
+                    let Some(flag) = aff.flag_mode.parse_one(flag) else {
+                        continue;
+                    };
+                    let count: usize = count.parse().unwrap_or(0);
+                    let mut class = AffixClass { cross_product: *cross == "Y", rules: Vec::with_capacity(count) };
+
+                    for rule_line in lines.by_ref().take(count) {
+                        if let Some(rule) = aff.parse_rule(rule_line) {
+                            class.rules.push(rule);
+                        }
+                    }
+
+                    let table = if *kind == "PFX" { &mut aff.prefixes } else { &mut aff.suffixes };
+                    match table.entry(flag) {
+                        Entry::Occupied(mut existing) => existing.get_mut().rules.append(&mut class.rules),
+                        Entry::Vacant(slot) => {
+                            slot.insert(class);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        aff
+    }
+
+    /// Parse `PFX|SFX flag strip append[/flags] [condition]`
+    fn parse_rule(&self, line: &str) -> Option<AffixRule> {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let [_, _, strip, append, rest @ ..] = fields.as_slice() else {
+            return None;
+        };
+        let (append, continuation) = match append.split_once('/') {
+            Some((append, flags)) => (append, self.parse_flags(flags)),
+            None => (*append, Vec::new()),
+        };
+        let zero = |s: &str| if s == "0" { String::new() } else { s.to_string() };
+
+        Some(AffixRule {
+            strip: zero(strip),
+            append: zero(append),
+            condition: parse_condition(rest.first().copied().unwrap_or(".")),
+            continuation,
+        })
+    }
+
+    /// Flags of a `.dic` entry or continuation class (aliases resolved)
+    fn parse_flags(&self, flags: &str) -> Vec<Flag> {
+        if !self.aliases.is_empty() {
+            if let Ok(index) = flags.parse::<usize>() {
+                return self.aliases.get(index.wrapping_sub(1)).cloned().unwrap_or_default();
+            }
+        }
+        self.flag_mode.parse(flags)
+    }
+
+    /// Number of PFX/SFX rules
+    pub fn rule_count(&self) -> usize {
+        self.prefixes.values().chain(self.suffixes.values()).map(|c| c.rules.len()).sum()
+    }
+
+    /// Forms produced by the suffix classes in `flags`, with continuation classes
+    fn suffixed(&self, word: &str, flags: &[Flag]) -> Vec<(String, bool)> {
+        let mut forms = Vec::new();
+        for class in flags.iter().filter_map(|f| self.suffixes.get(f)) {
+            for rule in &class.rules {
+                let Some(form) = rule.apply_suffix(word) else {
+                    continue;
+                };
+                for inner in rule.continuation.iter().filter_map(|f| self.suffixes.get(f)) {
+                    forms.extend(inner.rules.iter()
+                        .filter_map(|r| r.apply_suffix(&form))
+                        .map(|f| (f, class.cross_product && inner.cross_product)));
+                }
+                if !self.is_set(self.need_affix, &rule.continuation) {
+                    forms.push((form, class.cross_product));
+                }
+            }
+        }
+        forms
+    }
+
+    fn is_set(&self, flag: Option<Flag>, flags: &[Flag]) -> bool {
+        flag.is_some_and(|flag| flags.contains(&flag))
+    }
+
+    /// Every form of one `.dic` stem
+    fn expand(&self, stem: &str, flags: &[Flag], out: &mut Vec<String>) {
+        if !self.is_set(self.need_affix, flags) {
+            out.push(stem.to_string());
+        }
+
+        let suffixed = self.suffixed(stem, flags);
+        for class in flags.iter().filter_map(|f| self.prefixes.get(f)) {
+            for rule in &class.rules {
+                let Some(prefixed) = rule.apply_prefix(stem) else {
+                    continue;
+                };
+                if !self.is_set(self.need_affix, &rule.continuation) {
+                    out.push(prefixed.clone());
+                }
+                // Suffixes allowed by the prefix continuation classes
+                out.extend(self.suffixed(&prefixed, &rule.continuation).into_iter().map(|(f, _)| f));
+
+                if class.cross_product {
+                    out.extend(suffixed.iter()
+                        .filter(|(_, cross)| *cross)
+                        .filter_map(|(form, _)| rule.apply_prefix(form)));
+                }
+            }
+        }
+        out.extend(suffixed.into_iter().map(|(form, _)| form));
+    }
+}
+
+/// Statistics of a Hunspell expansion
+#[derive(Debug, Clone, Default)]
+pub struct HunspellStats {
+    /// Stems read from the `.dic` file
+    pub stems: usize,
+    /// PFX/SFX rules in the `.aff` file
+    pub affix_rules: usize,
+    /// Forms generated, duplicates included
+    pub expanded_forms: usize,
+    /// Forbidden words removed (`FORBIDDENWORD`)
+    pub forbidden: usize,
+}
+
+/// Split `word/FLAGS` (with `\/` escapes), dropping morphological fields
+fn split_entry(line: &str) -> (String, &str) {
+    let entry = line.split(['\t', ' ']).next().unwrap_or("");
+    let mut word = String::with_capacity(entry.len());
+    let mut chars = entry.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some((_, next)) = chars.next() {
+                    word.push(next);
+                }
+            }
+            '/' => return (word, &entry[i + 1..]),
+            _ => word.push(c),
+        }
+    }
+    (word, "")
+}
+
+/// Expand a Hunspell dictionary into its word forms
+///
+/// Returns the forms (unsorted, may contain duplicates) and the stats.
+pub fn expand_hunspell(dic: &str, aff: &AffixFile) -> (Vec<String>, HunspellStats) {
+    let mut stats = HunspellStats { affix_rules: aff.rule_count(), ..Default::default() };
+    let mut forms = Vec::new();
+    let mut forbidden = HashSet::new();
+
+    // The first line is the approximate entry count
+    for line in dic.lines().skip(1) {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (stem, flags) = split_entry(line);
+        if stem.is_empty() {
+            continue;
+        }
+        let flags = aff.parse_flags(flags);
+        stats.stems += 1;
+
+        if aff.is_set(aff.forbidden, &flags) {
+            forbidden.insert(stem);
+            continue;
+        }
+        if aff.is_set(aff.only_in_compound, &flags) {
+            continue;
+        }
+        aff.expand(&stem, &flags, &mut forms);
+    }
+
+    stats.expanded_forms = forms.len();
+    if !forbidden.is_empty() {
+        forms.retain(|form| !forbidden.contains(form));
+        stats.forbidden = forbidden.len();
+    }
+    (forms, stats)
+}
+
+/// Decode a file according to the `SET` directive of its `.aff`
+fn decode(bytes: &[u8], encoding: &str) -> Result<String, DictError> {
+    match encoding.to_ascii_uppercase().as_str() {
+        "UTF-8" | "UTF8" => String::from_utf8(bytes.to_vec())
+            .map_err(|e| DictError::Hunspell(format!("invalid UTF-8: {}", e))),
+        "ISO8859-1" | "ISO-8859-1" => Ok(bytes.iter().map(|&b| b as char).collect()),
+        "ISO8859-15" | "ISO-8859-15" => Ok(bytes.iter().map(|&b| match b {
+            0xA4 => '€', 0xA6 => 'Š', 0xA8 => 'š', 0xB4 => 'Ž',
+            0xB8 => 'ž', 0xBC => 'Œ', 0xBD => 'œ', 0xBE => 'Ÿ',
+            _ => b as char,
+        }).collect()),
+        other => Err(DictError::Hunspell(format!("unsupported encoding: {}", other))),
+    }
+}
+
+/// Read a `.dic` / `.aff` pair and expand it
+pub fn read_hunspell<P: AsRef<Path>>(dic_path: P, aff_path: P) -> Result<(Vec<String>, HunspellStats), DictError> {
+    let aff_bytes = std::fs::read(aff_path)?;
+    // SET is ASCII, so it can be read before decoding
+    let encoding = String::from_utf8_lossy(&aff_bytes)
+        .lines()
+        .find_map(|l| l.trim().strip_prefix("SET ").map(|e| e.trim().to_string()))
+        .unwrap_or_else(|| "UTF-8".to_string());
+
+    let aff = AffixFile::parse(&decode(&aff_bytes, &encoding)?);
+    let dic = decode(&std::fs::read(dic_path)?, &encoding)?;
+    Ok(expand_hunspell(&dic, &aff))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand(aff: &str, dic: &str) -> Vec<String> {
+        let (mut forms, _) = expand_hunspell(dic, &AffixFile::parse(aff));
+        forms.sort();
+        forms.dedup();
+        forms
+    }
+
+    #[test]
+    fn test_suffix_conditions() {
+        let aff = "SET UTF-8\n\
+                   SFX S Y 3\n\
+                   SFX S y ies [^aeiou]y\n\
+                   SFX S 0 s [aeiou]y\n\
+                   SFX S 0 s [^y]\n";
+        let dic = "3\ncity/S\nday/S\nword/S\n";
+        assert_eq!(expand(aff, dic), vec!["cities", "city", "day", "days", "word", "words"]);
+    }
+
+    #[test]
+    fn test_prefix_cross_product() {
+        let aff = "PFX U Y 1\n\
+                   PFX U 0 un .\n\
+                   PFX R N 1\n\
+                   PFX R 0 re .\n\
+                   SFX D Y 2\n\
+                   SFX D 0 ed [^e]\n\
+                   SFX D 0 d e\n";
+        let dic = "2\nlock/UD\ncode/RD\n";
+        assert_eq!(
+            expand(aff, dic),
+            vec!["code", "coded", "lock", "locked", "recode", "unlock", "unlocked"]
+        );
+    }
+
+    #[test]
+    fn test_continuation_and_need_affix() {
+        // "-ment" only exists followed by "-s"; the stem itself needs an affix
+        let aff = "NEEDAFFIX X\n\
+                   SFX A Y 1\n\
+                   SFX A 0 ment/BX .\n\
+                   SFX B Y 1\n\
+                   SFX B 0 s .\n";
+        let dic = "2\nlent/AX\nvrai/A\n";
+        assert_eq!(expand(aff, dic), vec!["lentments", "vrai", "vraiments"]);
+    }
+
+    #[test]
+    fn test_long_flags_aliases_and_forbidden() {
+        let aff = "FLAG long\n\
+                   FORBIDDENWORD !!\n\
+                   AF 2\n\
+                   AF Aa\n\
+                   AF Aa!!\n\
+                   SFX Aa Y 1\n\
+                   SFX Aa 0 s .\n";
+        let dic = "3\ncat/1\ndogs\ndog/2\n";
+        let (forms, stats) = expand_hunspell(dic, &AffixFile::parse(aff));
+        assert_eq!(stats.stems, 3);
+        assert_eq!(stats.forbidden, 1);
+        assert!(forms.contains(&"cats".to_string()));
+        assert!(!forms.contains(&"dog".to_string()));
+        assert!(forms.contains(&"dogs".to_string()));
+    }
+
+    #[test]
+    fn test_num_flags_and_escaped_slash() {
+        let aff = "FLAG num\nSFX 101 Y 1\nSFX 101 0 s .\n";
+        let dic = "2\nkm\\/h\nfile/101,7 po:noun\n";
+        assert_eq!(expand(aff, dic), vec!["file", "files", "km/h"]);
+    }
+
+    #[test]
+    fn test_read_latin1_files() {
+        use std::io::Write;
+
+        let mut aff = tempfile::NamedTempFile::new().unwrap();
+        aff.write_all(b"SET ISO8859-1\nSFX S Y 1\nSFX S 0 s .\n").unwrap();
+        let mut dic = tempfile::NamedTempFile::new().unwrap();
+        dic.write_all(b"1\ncaf\xE9/S\n").unwrap();
+
+        let (forms, stats) = read_hunspell(dic.path(), aff.path()).unwrap();
+        assert_eq!(forms, vec!["caf\u{e9}", "caf\u{e9}s"]);
+        assert_eq!((stats.stems, stats.affix_rules, stats.expanded_forms), (1, 1, 2));
+    }
+
+    #[test]
+    fn test_latin1_decoding() {
+        assert_eq!(decode(&[0x63, 0x61, 0x66, 0xE9], "ISO8859-1").unwrap(), "café");
+        assert_eq!(decode(&[0xBD], "ISO8859-15").unwrap(), "œ");
+        assert!(decode(b"x", "KOI8-R").is_err());
+    }
+}
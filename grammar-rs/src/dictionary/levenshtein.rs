@@ -0,0 +1,186 @@
+//! Levenshtein automaton for fuzzy FST lookups
+//!
+//! Walks the FST with one dynamic-programming row per state and prunes
+//! every branch whose row minimum exceeds the maximum distance, so only a
+//! tiny part of the dictionary is visited. Distances are counted in chars
+//! (UTF-8 sequences are decoded inside the automaton, `é` is one edit) and
+//! adjacent transpositions cost one edit (`teh` → `the`).
+
+use fst::Automaton;
+
+/// Longest query handled, in chars (states are fixed-size, no allocation)
+pub const MAX_QUERY_CHARS: usize = 48;
+
+/// Damerau-Levenshtein automaton over the chars of a query
+///
+/// Queries longer than `MAX_QUERY_CHARS` match nothing.
+#[derive(Debug, Clone)]
+pub struct LevenshteinAutomaton {
+    query: Vec<char>,
+    max_distance: u8,
+}
+
+/// Automaton state: `None` once the branch can no longer match
+#[derive(Debug, Clone)]
+pub struct LevenshteinState {
+    /// Distances, capped at `max_distance + 1`
+    row: [u8; MAX_QUERY_CHARS + 1],
+    /// Row before the last char, for transpositions
+    prev_row: [u8; MAX_QUERY_CHARS + 1],
+    len: usize,
+    last_char: Option<char>,
+    /// Bytes of an incomplete UTF-8 sequence
+    pending: [u8; 4],
+    pending_len: usize,
+}
+
+impl LevenshteinState {
+    /// Edit distance between the query and the key read so far
+    pub fn distance(&self) -> usize {
+        self.row[self.len] as usize
+    }
+
+    fn min_distance(&self) -> u8 {
+        self.row[..=self.len].iter().copied().min().unwrap_or(u8::MAX)
+    }
+}
+
+impl LevenshteinAutomaton {
+    pub fn new(query: &str, max_distance: usize) -> Self {
+        Self {
+            query: query.chars().collect(),
+            max_distance: max_distance.min(u8::MAX as usize - 2) as u8,
+        }
+    }
+
+    fn step(&self, state: &LevenshteinState, c: char) -> LevenshteinState {
+        let cap = self.max_distance + 1;
+        let row = &state.row;
+        let mut next = [0u8; MAX_QUERY_CHARS + 1];
+        next[0] = (row[0] + 1).min(cap);
+
+        for j in 1..=state.len {
+            let cost = u8::from(self.query[j - 1] != c);
+            let mut value = (row[j] + 1).min(next[j - 1] + 1).min(row[j - 1] + cost);
+            if j > 1 && state.last_char == Some(self.query[j - 1]) && c == self.query[j - 2] {
+                value = value.min(state.prev_row[j - 2] + 1);
+            }
+            next[j] = value.min(cap);
+        }
+
+        LevenshteinState {
+            row: next,
+            prev_row: state.row,
+            len: state.len,
+            last_char: Some(c),
+            pending: [0; 4],
+            pending_len: 0,
+        }
+    }
+}
+
+/// Length of the UTF-8 sequence starting with `byte`
+fn utf8_len(byte: u8) -> usize {
+    match byte {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        _ => 4,
+    }
+}
+
+impl Automaton for LevenshteinAutomaton {
+    type State = Option<LevenshteinState>;
+
+    fn start(&self) -> Self::State {
+        let len = self.query.len();
+        if len > MAX_QUERY_CHARS {
+            return None;
+        }
+        let mut row = [0u8; MAX_QUERY_CHARS + 1];
+        for (j, value) in row.iter_mut().enumerate().take(len + 1) {
+            *value = j.min(self.max_distance as usize + 1) as u8;
+        }
+        Some(LevenshteinState {
+            row,
+            prev_row: [0; MAX_QUERY_CHARS + 1],
+            len,
+            last_char: None,
+            pending: [0; 4],
+            pending_len: 0,
+        })
+    }
+
+    fn is_match(&self, state: &Self::State) -> bool {
+        state.as_ref().is_some_and(|s| s.pending_len == 0 && s.row[s.len] <= self.max_distance)
+    }
+
+    fn can_match(&self, state: &Self::State) -> bool {
+        state.as_ref().is_some_and(|s| s.min_distance() <= self.max_distance)
+    }
+
+    fn accept(&self, state: &Self::State, byte: u8) -> Self::State {
+        let state = state.as_ref()?;
+        let mut pending = state.pending;
+        pending[state.pending_len] = byte;
+        let pending_len = state.pending_len + 1;
+        if pending_len < utf8_len(pending[0]) {
+            return Some(LevenshteinState { pending, pending_len, ..state.clone() });
+        }
+
+        let c = std::str::from_utf8(&pending[..pending_len]).ok()?.chars().next()?;
+        let next = self.step(state, c);
+        (next.min_distance() <= self.max_distance).then_some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fst::{IntoStreamer, Set, Streamer};
+
+    fn fuzzy(words: &[&str], query: &str, max: usize) -> Vec<(String, usize)> {
+        let mut words = words.to_vec();
+        words.sort();
+        let set = Set::from_iter(words).unwrap();
+        let mut stream = set.search_with_state(LevenshteinAutomaton::new(query, max)).into_stream();
+        let mut results = Vec::new();
+        while let Some((key, state)) = stream.next() {
+            let distance = state.map_or(usize::MAX, |s| s.distance());
+            results.push((String::from_utf8(key.to_vec()).unwrap(), distance));
+        }
+        results
+    }
+
+    #[test]
+    fn test_distances() {
+        let words = ["hello", "help", "helo", "world", "yellow"];
+        assert_eq!(
+            fuzzy(&words, "helo", 1),
+            vec![("hello".to_string(), 1), ("helo".to_string(), 0), ("help".to_string(), 1)]
+        );
+        assert!(fuzzy(&words, "wrld", 1).iter().any(|(w, d)| w == "world" && *d == 1));
+        assert!(fuzzy(&words, "xyz", 1).is_empty());
+    }
+
+    #[test]
+    fn test_long_query() {
+        let long = "a".repeat(MAX_QUERY_CHARS + 1);
+        assert!(fuzzy(&[long.as_str()], &long, 2).is_empty());
+        let word = "pneumonoultramicroscopicsilicovolcanoconiosis";
+        assert_eq!(fuzzy(&[word], "pneumonoultramicroscopicsilicovolcanokoniosis", 1).len(), 1);
+    }
+
+    #[test]
+    fn test_transposition_is_one_edit() {
+        assert_eq!(fuzzy(&["the", "then"], "teh", 1), vec![("the".to_string(), 1)]);
+    }
+
+    #[test]
+    fn test_accented_chars_count_once() {
+        let words = ["été", "ete", "mère", "mer"];
+        assert_eq!(fuzzy(&words, "ete", 1), vec![("ete".to_string(), 0)]);
+        assert!(fuzzy(&words, "ete", 2).contains(&("été".to_string(), 2)));
+        assert_eq!(fuzzy(&words, "mere", 1), vec![("mer".to_string(), 1), ("mère".to_string(), 1)]);
+    }
+}
@@ -2,7 +2,12 @@
 //!
 //! Provides efficient dictionary storage using FST (Finite State Transducer).
 //! FST provides O(key_length) lookup with minimal memory usage.
+//! Hunspell `.dic`/`.aff` dictionaries are expanded into full form sets.
 
 mod fst_dict;
+mod hunspell;
+mod levenshtein;
 
-pub use fst_dict::FstDictionary;
+pub use fst_dict::{DictError, FstDictionary};
+pub use hunspell::{expand_hunspell, AffixFile, HunspellStats};
+pub use levenshtein::{LevenshteinAutomaton, LevenshteinState};
@@ -0,0 +1,386 @@
+//! Auto-fix - applique la première suggestion de chaque match
+//!
+//! Les spans qui se chevauchent sont départagés de façon déterministe :
+//! sévérité la plus forte d'abord, puis ID de règle, puis position. Un
+//! match perdant est simplement ignoré (il réapparaîtra à la prochaine
+//! vérification s'il est toujours pertinent).
+//!
+//! ```rust
+//! use grammar_rs::fix::{apply_fixes, FixOptions};
+//! use grammar_rs::prelude::*;
+//!
+//! let pipeline = Pipeline::new(SimpleTokenizer::new(), PassthroughAnalyzer::new())
+//!     .with_checker(RuleChecker::new().with_english_rules());
+//! let text = "hello  world";
+//! let result = pipeline.check_text(text);
+//!
+//! let fixed = apply_fixes(text, &result.matches, &FixOptions::safe());
+//! assert_eq!(fixed.text, "hello world");
+//! ```
+
+use crate::core::{Match, Severity};
+use std::collections::HashSet;
+use std::ops::Range;
+
+/// Règles dont la correction ne change jamais le sens du texte
+pub const SAFE_RULES: &[&str] = &["DOUBLE_SPACE", "EN_A_AN", "EN_A_AN_IMPROVED", "PROHIBIT"];
+
+/// Règles autorisées à corriger le texte
+#[derive(Debug, Clone, Default)]
+pub struct FixOptions {
+    /// `None` = toutes les règles avec une suggestion
+    pub allowed_rules: Option<HashSet<String>>,
+}
+
+impl FixOptions {
+    /// Corrige avec toutes les règles
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    /// Ne corrige qu'avec `SAFE_RULES`
+    pub fn safe() -> Self {
+        Self::only(SAFE_RULES.iter().copied())
+    }
+
+    /// Ne corrige qu'avec les règles données
+    pub fn only<'a>(rules: impl IntoIterator<Item = &'a str>) -> Self {
+        Self {
+            allowed_rules: Some(rules.into_iter().map(str::to_string).collect()),
+        }
+    }
+
+    fn allows(&self, rule_id: &str) -> bool {
+        self.allowed_rules.as_ref().is_none_or(|rules| rules.contains(rule_id))
+    }
+}
+
+/// Correction appliquée (span dans le texte d'origine)
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppliedFix {
+    pub span: Range<usize>,
+    pub rule_id: String,
+    pub replacement: String,
+}
+
+/// Résultat de `apply_fixes`
+#[derive(Debug, Clone)]
+pub struct FixResult {
+    /// Texte corrigé
+    pub text: String,
+    /// Corrections appliquées, dans l'ordre du texte
+    pub applied: Vec<AppliedFix>,
+    /// Matches corrigeables écartés car en conflit avec une correction prioritaire
+    pub conflicts: usize,
+}
+
+/// Applique la première suggestion de chaque match autorisé par `options`
+///
+/// Les matches sans suggestion, hors de la liste autorisée ou dont le span
+/// ne tombe pas sur des limites de caractères sont ignorés.
+pub fn apply_fixes(text: &str, matches: &[Match], options: &FixOptions) -> FixResult {
+    let mut candidates: Vec<&Match> = matches
+        .iter()
+        .filter(|m| options.allows(&m.rule_id))
+        .filter(|m| !m.suggestions.is_empty())
+        .filter(|m| {
+            m.span.start <= m.span.end
+                && text.is_char_boundary(m.span.start)
+                && text.is_char_boundary(m.span.end)
+        })
+        .filter(|m| text[m.span.clone()] != m.suggestions[0])
+        .collect();
+
+    // Priorité : sévérité, puis règle, puis position (ordre total => déterministe)
+    candidates.sort_by(|a, b| {
+        severity_rank(b.severity)
+            .cmp(&severity_rank(a.severity))
+            .then_with(|| a.rule_id.cmp(&b.rule_id))
+            .then_with(|| a.span.start.cmp(&b.span.start))
+            .then_with(|| a.span.end.cmp(&b.span.end))
+            .then_with(|| a.suggestions[0].cmp(&b.suggestions[0]))
+    });
+
+    let mut accepted: Vec<&Match> = Vec::new();
+    let mut conflicts = 0;
+    for candidate in candidates {
+        if accepted.iter().any(|m| conflict(&m.span, &candidate.span)) {
+            conflicts += 1;
+        } else {
+            accepted.push(candidate);
+        }
+    }
+
+    accepted.sort_by_key(|m| m.span.start);
+
+    let mut fixed = String::with_capacity(text.len());
+    let mut last = 0;
+    for m in &accepted {
+        fixed.push_str(&text[last..m.span.start]);
+        fixed.push_str(&m.suggestions[0]);
+        last = m.span.end;
+    }
+    fixed.push_str(&text[last..]);
+
+    FixResult {
+        text: fixed,
+        applied: accepted
+            .into_iter()
+            .map(|m| AppliedFix {
+                span: m.span.clone(),
+                rule_id: m.rule_id.clone(),
+                replacement: m.suggestions[0].clone(),
+            })
+            .collect(),
+        conflicts,
+    }
+}
+
+fn severity_rank(severity: Severity) -> u8 {
+    match severity {
+        Severity::Error => 2,
+        Severity::Warning => 1,
+        Severity::Hint => 0,
+    }
+}
+
+/// Deux spans se chevauchent (deux insertions au même endroit aussi)
+fn conflict(a: &Range<usize>, b: &Range<usize>) -> bool {
+    (a.start < b.end && b.start < a.end) || a.start == b.start
+}
+
+/// Diff unifié (3 lignes de contexte) entre le texte d'origine et le texte corrigé
+///
+/// Renvoie une chaîne vide si les deux textes sont identiques.
+pub fn unified_diff(path: &str, old: &str, new: &str) -> String {
+    const CONTEXT: usize = 3;
+
+    if old == new {
+        return String::new();
+    }
+
+    let a: Vec<&str> = old.split_inclusive('\n').collect();
+    let b: Vec<&str> = new.split_inclusive('\n').collect();
+    let edits = diff_lines(&a, &b);
+
+    let mut out = format!("--- a/{}\n+++ b/{}\n", path, path);
+    let mut i = 0;
+    while i < edits.len() {
+        // Début du prochain bloc de changements
+        let Some(first_change) = edits[i..].iter().position(|e| !matches!(e, Edit::Equal(..))) else {
+            break;
+        };
+        let change = i + first_change;
+        let start = change.saturating_sub(CONTEXT).max(i);
+
+        // Étendre le hunk tant que deux changements sont séparés par <= 2×CONTEXT lignes
+        let mut end = change;
+        let mut equal_run = 0;
+        for (k, edit) in edits.iter().enumerate().skip(change) {
+            if matches!(edit, Edit::Equal(..)) {
+                equal_run += 1;
+                if equal_run > 2 * CONTEXT {
+                    break;
+                }
+            } else {
+                equal_run = 0;
+                end = k;
+            }
+        }
+        let end = (end + 1 + CONTEXT).min(edits.len());
+
+        let old_start = edits[..start].iter().filter(|e| !matches!(e, Edit::Insert(_))).count();
+        let new_start = edits[..start].iter().filter(|e| !matches!(e, Edit::Delete(_))).count();
+        let old_len = edits[start..end].iter().filter(|e| !matches!(e, Edit::Insert(_))).count();
+        let new_len = edits[start..end].iter().filter(|e| !matches!(e, Edit::Delete(_))).count();
+        out.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            hunk_range(old_start, old_len),
+            hunk_range(new_start, new_len)
+        ));
+
+        for edit in &edits[start..end] {
+            let (prefix, line) = match *edit {
+                Edit::Equal(x) => (' ', a[x]),
+                Edit::Delete(x) => ('-', a[x]),
+                Edit::Insert(y) => ('+', b[y]),
+            };
+            out.push(prefix);
+            out.push_str(line);
+            if !line.ends_with('\n') {
+                out.push_str("\n\\ No newline at end of file\n");
+            }
+        }
+
+        i = end;
+    }
+
+    out
+}
+
+fn hunk_range(start: usize, len: usize) -> String {
+    // Les numéros de ligne sont 1-based ; un hunk vide pointe sur la ligne précédente
+    if len == 0 {
+        format!("{},0", start)
+    } else {
+        format!("{},{}", start + 1, len)
+    }
+}
+
+/// Opération d'édition entre deux listes de lignes
+#[derive(Debug, Clone, Copy)]
+enum Edit {
+    Equal(usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Diff de Myers (O((N+M)·D)) sur des lignes
+fn diff_lines(a: &[&str], b: &[&str]) -> Vec<Edit> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max = (n + m) as usize;
+    let offset = max as isize;
+    let mut v = vec![0isize; 2 * max + 2];
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+
+    'search: for d in 0..=max as isize {
+        trace.push(v.clone());
+        let mut k = -d;
+        while k <= d {
+            let idx = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                v[idx + 1]
+            } else {
+                v[idx - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[idx] = x;
+            if x >= n && y >= m {
+                trace.push(v.clone());
+                break 'search;
+            }
+            k += 2;
+        }
+    }
+
+    // Remonter la trace pour reconstruire le chemin
+    let mut edits = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for d in (1..trace.len() as isize - 1).rev() {
+        let v = &trace[d as usize];
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && v[(k - 1 + offset) as usize] < v[(k + 1 + offset) as usize]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = v[(prev_k + offset) as usize];
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            edits.push(Edit::Equal(x as usize));
+        }
+        if x == prev_x {
+            y -= 1;
+            edits.push(Edit::Insert(y as usize));
+        } else {
+            x -= 1;
+            edits.push(Edit::Delete(x as usize));
+        }
+    }
+    while x > 0 && y > 0 {
+        x -= 1;
+        y -= 1;
+        edits.push(Edit::Equal(x as usize));
+    }
+
+    edits.reverse();
+    edits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::IssueType;
+
+    fn m(span: Range<usize>, rule: &str, severity: Severity, suggestion: &str) -> Match {
+        Match {
+            span,
+            message: String::new(),
+            rule_id: rule.to_string(),
+            suggestions: vec![suggestion.to_string()],
+            severity,
+            issue_type: Some(IssueType::Grammar),
+        }
+    }
+
+    #[test]
+    fn test_apply_fixes() {
+        let text = "I have a apple and  a pear.";
+        let matches = vec![
+            m(7..8, "EN_A_AN", Severity::Error, "an"),
+            m(18..20, "DOUBLE_SPACE", Severity::Warning, " "),
+        ];
+        let result = apply_fixes(text, &matches, &FixOptions::all());
+        assert_eq!(result.text, "I have an apple and a pear.");
+        assert_eq!(result.applied.len(), 2);
+        assert_eq!(result.applied[0].rule_id, "EN_A_AN");
+    }
+
+    #[test]
+    fn test_overlaps_resolved_by_severity_then_rule() {
+        let text = "teh cat";
+        let matches = vec![
+            m(0..3, "STYLE_X", Severity::Hint, "a"),
+            m(0..7, "B_RULE", Severity::Error, "the dog"),
+            m(0..3, "A_RULE", Severity::Error, "the"),
+        ];
+        let result = apply_fixes(text, &matches, &FixOptions::all());
+        assert_eq!(result.text, "the cat");
+        assert_eq!(result.conflicts, 2);
+
+        // Same result whatever the input order
+        let reversed: Vec<Match> = matches.into_iter().rev().collect();
+        assert_eq!(apply_fixes(text, &reversed, &FixOptions::all()).text, "the cat");
+    }
+
+    #[test]
+    fn test_safe_allowlist() {
+        let text = "teh  cat";
+        let matches = vec![
+            m(0..3, "SPELL", Severity::Error, "the"),
+            m(3..5, "DOUBLE_SPACE", Severity::Warning, " "),
+        ];
+        let result = apply_fixes(text, &matches, &FixOptions::safe());
+        assert_eq!(result.text, "teh cat");
+    }
+
+    #[test]
+    fn test_unified_diff() {
+        let old = "one\ntwo\nthree\nfour\nfive\nsix\nseven\neight\nnine\nten\n";
+        let new = old.replace("two", "2").replace("nine", "9");
+        let diff = unified_diff("a.md", old, &new);
+        assert_eq!(
+            diff,
+            "--- a/a.md\n+++ b/a.md\n\
+             @@ -1,10 +1,10 @@\n one\n-two\n+2\n three\n four\n five\n six\n seven\n eight\n-nine\n+9\n ten\n"
+        );
+        assert_eq!(unified_diff("a.md", old, old), "");
+    }
+
+    #[test]
+    fn test_unified_diff_separate_hunks() {
+        let lines: Vec<String> = (1..=20).map(|i| format!("line {}\n", i)).collect();
+        let old = lines.concat();
+        let new = old.replace("line 2\n", "LINE 2\n").replace("line 18\n", "LINE 18\n");
+        let diff = unified_diff("a.md", &old, &new);
+        assert!(diff.contains("@@ -1,5 +1,5 @@\n"));
+        assert!(diff.contains("@@ -15,6 +15,6 @@\n"));
+        assert!(!diff.contains(" line 10\n"));
+    }
+}
@@ -38,6 +38,9 @@ pub mod filter;
 pub mod lucene;
 pub mod language_model;
 pub mod morphology;
+pub mod presets;
+pub mod fix;
+pub mod markup;
 
 /// Pre-warm all lazy statics to avoid first-call latency
 ///
@@ -75,24 +78,27 @@ pub fn warm_up() {
     let _ = en_checker.check("air plane", &[]);
     let _ = fr_checker.check("aller retour", &[]);
 
-    // Force initialization of French morphology
+    // Force initialization of French and English morphology
     let _ = morphology::FrenchMorphology::load();
+    let _ = morphology::EnglishMorphology::load();
 }
 
 /// Prelude - importe tout ce dont tu as besoin
 pub mod prelude {
     pub use crate::core::{
         Token, TokenKind, AnalyzedToken, PosTag,
-        Match, Severity, CheckResult,
+        Match, Severity, IssueType, CheckLevel, CheckResult,
         MaskKind, MaskedRegion,
     };
     pub use crate::core::traits::{
-        Tokenizer, Analyzer, Checker, Suggester, GrammarChecker,
+        Tokenizer, Analyzer, SentenceSplitter, Disambiguator, Checker, Suggester, GrammarChecker,
     };
     pub use crate::core::filter::{Filter, FilterChain};
+    pub use crate::core::registry::{RuleCategory, RuleMetadata, RuleRegistry};
     pub use crate::core::pipeline::Pipeline;
-    pub use crate::tokenizer::{SimpleTokenizer, ContractionTokenizer};
-    pub use crate::analyzer::{PassthroughAnalyzer, DictAnalyzer, PosTagger};
+    pub use crate::core::incremental::{IncrementalChecker, IncrementalResult};
+    pub use crate::tokenizer::{SimpleTokenizer, ContractionTokenizer, SimpleSentenceSplitter};
+    pub use crate::analyzer::{PassthroughAnalyzer, DictAnalyzer, PosTagger, RuleDisambiguator};
     pub use crate::checker::{SpellChecker, RuleChecker};
     pub use crate::dictionary::FstDictionary;
     pub use crate::lang_detect::{Language, LanguageDetector, DetectionResult};
@@ -123,6 +129,108 @@ mod integration_tests {
         assert!(result.matches.iter().any(|m| m.rule_id == "SPELL"));
     }
 
+    #[test]
+    fn test_check_batch_matches_single_checks() {
+        let pipeline = Pipeline::new(
+            SimpleTokenizer::new(),
+            PassthroughAnalyzer::new(),
+        )
+        .with_checker(SpellChecker::new().with_words(["hello", "world"]));
+
+        let texts: Vec<String> = (0..50)
+            .map(|i| if i % 2 == 0 { "helo world".to_string() } else { "hello world".to_string() })
+            .collect();
+        let refs: Vec<&str> = texts.iter().map(String::as_str).collect();
+        let results = pipeline.check_batch(&refs);
+
+        assert_eq!(results.len(), texts.len());
+        for (text, result) in refs.iter().zip(&results) {
+            assert_eq!(result.matches.len(), pipeline.check_text(text).matches.len());
+        }
+        assert_eq!(results[0].matches.len(), 1);
+        assert!(results[1].matches.is_empty());
+    }
+
+    #[test]
+    fn test_disambiguator_immunizes_tokens() {
+        let spell = || SpellChecker::new().with_words(["see", "smith"]);
+        let text = "See Smith et al.";
+
+        let plain = Pipeline::new(SimpleTokenizer::new(), PassthroughAnalyzer::new()).with_checker(spell());
+        assert_eq!(plain.check_text(text).matches.len(), 2);
+
+        let pipeline = Pipeline::new(SimpleTokenizer::new(), PassthroughAnalyzer::new())
+            .with_disambiguator(crate::analyzer::create_en_disambiguator())
+            .with_checker(spell());
+        assert!(pipeline.check_text(text).matches.is_empty());
+    }
+
+    #[test]
+    fn test_observer_sees_every_checker() {
+        use crate::core::pipeline::CheckObserver;
+        use std::sync::{Arc, Mutex};
+        use std::time::Duration;
+
+        #[derive(Default)]
+        struct Recorder(Mutex<Vec<(String, usize)>>);
+
+        impl CheckObserver for Recorder {
+            fn checker_done(&self, checker: &'static str, _elapsed: Duration, result: &CheckResult) {
+                self.0.lock().unwrap().push((checker.to_string(), result.matches.len()));
+            }
+        }
+
+        let pipeline = Pipeline::new(SimpleTokenizer::new(), PassthroughAnalyzer::new())
+            .with_checker(SpellChecker::new().with_words(["hello", "world"]))
+            .with_checker(RuleChecker::new().with_english_rules());
+        let recorder = Arc::new(Recorder::default());
+        assert!(pipeline.set_observer(recorder.clone()));
+        assert!(!pipeline.set_observer(Arc::new(Recorder::default())));
+
+        pipeline.check_text("helo world");
+        let mut calls = recorder.0.lock().unwrap().clone();
+        calls.sort();
+        let names: Vec<_> = calls.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["RuleChecker", "SpellChecker"]);
+        assert_eq!(calls[1].1, 1);
+    }
+
+    const TWO_RULES: &str = r#"[
+        {"id": "A_APPLE", "name": "", "category": "Grammar", "message": "Use 'an'", "suggestions": ["an apple"], "antipatterns": [],
+         "pattern": [
+            {"text": "a", "regexp": null, "postag": null, "postag_regexp": false, "inflected": false,
+             "case_sensitive": false, "negation": false, "min": 1, "max": 1, "skip": null},
+            {"text": "apple", "regexp": null, "postag": null, "postag_regexp": false, "inflected": false,
+             "case_sensitive": false, "negation": false, "min": 1, "max": 1, "skip": null}]},
+        {"id": "EXMPLE", "name": "", "category": "Typos", "message": "Typo", "suggestions": [], "antipatterns": [],
+         "pattern": [
+            {"text": "exmple", "regexp": null, "postag": null, "postag_regexp": false, "inflected": false,
+             "case_sensitive": false, "negation": false, "min": 1, "max": 1, "skip": null}]}
+    ]"#;
+
+    #[test]
+    fn test_profiled_check_matches_plain_check() {
+        let pipeline = Pipeline::new(SimpleTokenizer::new(), PassthroughAnalyzer::new())
+            .with_checker(SpellChecker::new().with_words(["i", "have", "an", "apple", "a"]))
+            .with_checker(crate::checker::DynamicPatternChecker::from_json(TWO_RULES).unwrap())
+            .with_default_filters();
+        let text = "I have a apple, see https://exmple.com";
+
+        let (result, profile) = pipeline.check_text_profiled_with_level(text, CheckLevel::Picky, 1);
+        let spans = |r: &CheckResult| r.matches.iter().map(|m| (m.span.clone(), m.rule_id.clone())).collect::<Vec<_>>();
+        assert_eq!(spans(&result), spans(&pipeline.check_text(text)));
+
+        let names: Vec<_> = profile.checkers.iter().map(|c| c.name).collect();
+        assert_eq!(names, vec!["SpellChecker", "DynamicPatternChecker"]);
+        assert!(profile.checkers[0].slowest_rules.is_empty());
+        let dynamic = &profile.checkers[1];
+        // Counted before the URL filter drops the match inside the link
+        assert_eq!(dynamic.matches, 2);
+        assert!(!result.matches.iter().any(|m| m.rule_id == "EXMPLE"));
+        assert_eq!(dynamic.slowest_rules.len(), 1);
+        assert!(profile.total >= profile.tokenize + profile.analyze);
+    }
+
     #[test]
     fn test_french_pipeline() {
         let pipeline = Pipeline::new(
@@ -0,0 +1,183 @@
+//! HTML → texte annoté
+//!
+//! Balises et commentaires ignorés, balises de bloc vues comme une
+//! séparation de paragraphes, entités décodées, `<script>`, `<style>`,
+//! `<pre>` et `<code>` retirés en entier.
+
+use super::{AnnotatedText, AnnotatedTextBuilder, PLACEHOLDER};
+
+/// Balises qui séparent des paragraphes
+const BLOCK_TAGS: &[&str] = &[
+    "address", "article", "aside", "blockquote", "body", "dd", "details", "div", "dl", "dt",
+    "figcaption", "figure", "footer", "form", "h1", "h2", "h3", "h4", "h5", "h6", "head",
+    "header", "hr", "html", "li", "main", "nav", "ol", "p", "section", "summary", "table",
+    "tbody", "td", "tfoot", "th", "thead", "title", "tr", "ul",
+];
+
+/// Éléments retirés avec leur contenu : (nom, interprétation)
+const OPAQUE_ELEMENTS: &[(&str, &str)] = &[
+    ("script", ""),
+    ("style", ""),
+    ("pre", "\n\n"),
+    ("code", PLACEHOLDER),
+];
+
+pub(super) fn annotate(text: &str) -> AnnotatedText {
+    let mut builder = AnnotatedTextBuilder::new();
+    let mut text_start = 0;
+    let mut i = 0;
+
+    while i < text.len() {
+        let rest = &text[i..];
+        let markup = if rest.starts_with("<!--") {
+            let end = rest.find("-->").map_or(text.len(), |e| i + e + 3);
+            Some((end, ""))
+        } else if rest.starts_with('<') {
+            rest.find('>').and_then(|len| {
+                let tag = &rest[..=len];
+                let end = i + len + 1;
+                match opaque_element(tag) {
+                    Some((name, interpret_as)) => Some((closing_tag_end(text, end, name), interpret_as)),
+                    None => tag_interpretation(tag).map(|interpret_as| (end, interpret_as)),
+                }
+            })
+        } else if rest.starts_with('&') {
+            entity(rest).map(|(len, decoded)| (i + len, decoded))
+        } else {
+            None
+        };
+
+        match markup {
+            Some((end, interpret_as)) => {
+                builder.add_text(&text[text_start..i]).add_markup_as(&text[i..end], interpret_as);
+                text_start = end;
+                i = end;
+            }
+            // Avance d'un caractère entier (UTF-8)
+            None => i += rest.chars().next().map_or(1, char::len_utf8),
+        }
+    }
+
+    builder.add_text(&text[text_start..]);
+    builder.build()
+}
+
+/// Nom d'une balise (`<p class="x">` → `p`, `</P>` → `p`), `None` si ce n'en est pas une
+fn tag_name(tag: &str) -> Option<String> {
+    let inner = tag.strip_prefix('<')?.strip_suffix('>')?;
+    let inner = inner.strip_prefix('/').unwrap_or(inner);
+    let name: String = inner
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '-')
+        .collect();
+    let starts_with_letter = name.chars().next().is_some_and(|c| c.is_ascii_alphabetic());
+    starts_with_letter.then(|| name.to_ascii_lowercase())
+}
+
+/// Interprétation d'une balise : `"\n"` pour `<br>`, `"\n\n"` pour une
+/// balise de bloc, rien sinon ; `None` si `tag` n'est pas une balise
+/// (« a <3 b > c »)
+pub(super) fn tag_interpretation(tag: &str) -> Option<&'static str> {
+    if tag.starts_with("<!") || tag.starts_with("<?") {
+        // Doctype, instruction de traitement
+        return Some("");
+    }
+    let name = tag_name(tag)?;
+    Some(if name == "br" {
+        "\n"
+    } else if BLOCK_TAGS.contains(&name.as_str()) {
+        "\n\n"
+    } else {
+        ""
+    })
+}
+
+/// Balise ouvrante d'un élément retiré avec son contenu
+fn opaque_element(tag: &str) -> Option<(&'static str, &'static str)> {
+    if tag.starts_with("</") || tag.ends_with("/>") {
+        return None;
+    }
+    let name = tag_name(tag)?;
+    OPAQUE_ELEMENTS.iter().copied().find(|(opaque, _)| *opaque == name)
+}
+
+/// Fin de `</name>` à partir de `from` (fin du texte si absente)
+fn closing_tag_end(text: &str, from: usize, name: &str) -> usize {
+    let bytes = &text.as_bytes()[from..];
+    // Recherche insensible à la casse sans copier le reste du texte
+    let is_closing = |window: &[u8]| window.starts_with(b"</") && window[2..].eq_ignore_ascii_case(name.as_bytes());
+    bytes
+        .windows(name.len() + 2)
+        .position(is_closing)
+        .and_then(|start| bytes[start..].iter().position(|&b| b == b'>').map(|end| from + start + end + 1))
+        .unwrap_or(text.len())
+}
+
+/// Entité au début de `s` : (longueur, texte décodé)
+fn entity(s: &str) -> Option<(usize, &'static str)> {
+    let end = s.find(';').filter(|end| (2..=10).contains(end))?;
+    let name = &s[1..end];
+    let decoded = match name {
+        "amp" => "&",
+        "lt" => "<",
+        "gt" => ">",
+        "quot" => "\"",
+        "apos" | "#39" | "#x27" => "'",
+        "nbsp" | "#160" | "#xa0" | "#xA0" => " ",
+        "ndash" => "–",
+        "mdash" => "—",
+        "hellip" => "…",
+        "lsquo" => "‘",
+        "rsquo" | "#8217" => "’",
+        "ldquo" => "“",
+        "rdquo" => "”",
+        "laquo" => "«",
+        "raquo" => "»",
+        "eacute" => "é",
+        "egrave" => "è",
+        "agrave" => "à",
+        "ccedil" => "ç",
+        "copy" => "©",
+        // Autre entité numérique : un caractère neutre
+        _ if name.starts_with('#') => PLACEHOLDER,
+        _ => return None,
+    };
+    Some((end + 1, decoded))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(text: &str) -> String {
+        annotate(text).plain_text().to_string()
+    }
+
+    #[test]
+    fn test_tags_and_entities() {
+        assert_eq!(
+            plain("<p>A <b>bold</b> claim &amp; more.</p><p>Next<br/>line</p>"),
+            "\n\nA bold claim & more.\n\n\n\nNext\nline\n\n"
+        );
+        assert_eq!(plain("if a <3 b"), "if a <3 b");
+        assert_eq!(plain("AT&T rocks"), "AT&T rocks");
+    }
+
+    #[test]
+    fn test_opaque_elements() {
+        assert_eq!(
+            plain("Run <code>ls -la</code> now.<!-- note --><script>x()</script>"),
+            "Run X now."
+        );
+        assert_eq!(plain("<STYLE>é { }</StYlE >Après <script>x()"), "Après ");
+    }
+
+    #[test]
+    fn test_offsets_point_into_original() {
+        let text = "<p>It&rsquo;s a <em>tset</em>.</p>";
+        let annotated = annotate(text);
+        assert_eq!(annotated.plain_text(), "\n\nIt’s a tset.\n\n");
+        let start = annotated.plain_text().find("tset").unwrap();
+        assert_eq!(&text[annotated.to_original(start..start + 4)], "tset");
+    }
+}
@@ -0,0 +1,293 @@
+//! LaTeX → texte annoté
+//!
+//! Les commandes et accolades sont retirées, le texte de leurs arguments
+//! est gardé (`\emph{très}` → `très`). Formules, citations et références
+//! deviennent `PLACEHOLDER`, les environnements verbatim disparaissent.
+
+use super::{AnnotatedText, AnnotatedTextBuilder, PLACEHOLDER};
+
+/// Commandes retirées avec leurs arguments : (nom, interprétation)
+const DROPPED_COMMANDS: &[(&str, &str)] = &[
+    ("cite", PLACEHOLDER),
+    ("citep", PLACEHOLDER),
+    ("citet", PLACEHOLDER),
+    ("ref", PLACEHOLDER),
+    ("eqref", PLACEHOLDER),
+    ("pageref", PLACEHOLDER),
+    ("autoref", PLACEHOLDER),
+    ("cref", PLACEHOLDER),
+    ("Cref", PLACEHOLDER),
+    ("url", PLACEHOLDER),
+    ("label", ""),
+    ("includegraphics", ""),
+    ("documentclass", ""),
+    ("usepackage", ""),
+    ("input", ""),
+    ("include", ""),
+    ("bibliography", ""),
+    ("bibliographystyle", ""),
+    ("addbibresource", ""),
+    ("newcommand", ""),
+    ("renewcommand", ""),
+    ("setlength", ""),
+    ("vspace", ""),
+    ("hspace", ""),
+    ("color", ""),
+];
+
+/// Commandes dont le texte du dernier argument est gardé, comme un paragraphe
+const PARAGRAPH_COMMANDS: &[&str] = &[
+    "part", "chapter", "section", "subsection", "subsubsection", "paragraph",
+    "subparagraph", "title", "author", "date", "caption",
+];
+
+/// Commandes dont le premier argument est retiré (`\href{url}{texte}`)
+const SKIP_FIRST_ARG_COMMANDS: &[&str] = &["href", "textcolor", "colorbox"];
+
+/// Commandes remplacées par un texte
+const WORD_COMMANDS: &[(&str, &str)] = &[
+    ("LaTeX", "LaTeX"),
+    ("TeX", "TeX"),
+    ("ldots", "..."),
+    ("dots", "..."),
+    ("item", "\n\n"),
+    ("par", "\n\n"),
+    ("newline", "\n"),
+    ("linebreak", "\n"),
+];
+
+/// Environnements retirés en entier : (nom, interprétation)
+const OPAQUE_ENVIRONMENTS: &[(&str, &str)] = &[
+    ("equation", PLACEHOLDER),
+    ("equation*", PLACEHOLDER),
+    ("align", PLACEHOLDER),
+    ("align*", PLACEHOLDER),
+    ("gather", PLACEHOLDER),
+    ("gather*", PLACEHOLDER),
+    ("multline", PLACEHOLDER),
+    ("multline*", PLACEHOLDER),
+    ("eqnarray", PLACEHOLDER),
+    ("eqnarray*", PLACEHOLDER),
+    ("displaymath", PLACEHOLDER),
+    ("math", PLACEHOLDER),
+    ("verbatim", "\n\n"),
+    ("verbatim*", "\n\n"),
+    ("lstlisting", "\n\n"),
+    ("minted", "\n\n"),
+    ("comment", "\n\n"),
+    ("tikzpicture", "\n\n"),
+];
+
+/// Nombre d'arguments obligatoires après `\begin{env}`
+const ENVIRONMENT_ARGS: &[(&str, usize)] = &[
+    ("tabular", 1),
+    ("tabularx", 2),
+    ("array", 1),
+    ("minipage", 1),
+    ("thebibliography", 1),
+];
+
+pub(super) fn annotate(text: &str) -> AnnotatedText {
+    let mut builder = AnnotatedTextBuilder::new();
+    let bytes = text.as_bytes();
+    // Interprétation de l'accolade fermante de chaque groupe ouvert
+    let mut braces: Vec<&'static str> = Vec::new();
+    let mut text_start = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let markup: Option<(usize, &str)> = match bytes[i] {
+            b'\\' => command(text, i, &mut braces),
+            b'%' => Some((text[i..].find('\n').map_or(text.len(), |end| i + end), "")),
+            b'$' => inline_math_end(text, i).map(|end| (end, PLACEHOLDER)),
+            b'~' => Some((i + 1, " ")),
+            b'{' => {
+                braces.push("");
+                Some((i + 1, ""))
+            }
+            b'}' => Some((i + 1, braces.pop().unwrap_or(""))),
+            b'`' if bytes.get(i + 1) == Some(&b'`') => Some((i + 2, "\"")),
+            b'\'' if bytes.get(i + 1) == Some(&b'\'') => Some((i + 2, "\"")),
+            _ => None,
+        };
+
+        match markup {
+            Some((end, interpret_as)) => {
+                builder.add_text(&text[text_start..i]).add_markup_as(&text[i..end], interpret_as);
+                text_start = end;
+                i = end;
+            }
+            None => i += 1,
+        }
+    }
+
+    builder.add_text(&text[text_start..]);
+    builder.build()
+}
+
+/// Commande commençant en `i` (sur `\`) : (fin, interprétation)
+fn command(text: &str, i: usize, braces: &mut Vec<&'static str>) -> Option<(usize, &'static str)> {
+    let bytes = text.as_bytes();
+    let next = *bytes.get(i + 1)?;
+
+    if !next.is_ascii_alphabetic() {
+        return Some(match next {
+            b'\\' => (i + 2, "\n"),
+            b',' | b' ' | b';' | b':' => (i + 2, " "),
+            b'%' => (i + 2, "%"),
+            b'&' => (i + 2, "&"),
+            b'$' => (i + 2, "$"),
+            b'#' => (i + 2, "#"),
+            b'_' => (i + 2, "_"),
+            b'{' => (i + 2, "{"),
+            b'}' => (i + 2, "}"),
+            b'(' => (text[i..].find("\\)").map_or(text.len(), |end| i + end + 2), PLACEHOLDER),
+            b'[' => (text[i..].find("\\]").map_or(text.len(), |end| i + end + 2), PLACEHOLDER),
+            // `\-` (césure), `\!`, `\@`, accents non alphabétiques...
+            _ => (i + 2, ""),
+        });
+    }
+
+    let name_len = bytes[i + 1..].iter().take_while(|b| b.is_ascii_alphabetic()).count();
+    let name = &text[i + 1..i + 1 + name_len];
+    let mut end = i + 1 + name_len;
+    if bytes.get(end) == Some(&b'*') {
+        end += 1;
+    }
+
+    match name {
+        "begin" => {
+            let env_end = group_end(text, end, b'{', b'}')?;
+            let env = &text[end + 1..env_end - 1];
+            if let Some(&(_, interpret_as)) = OPAQUE_ENVIRONMENTS.iter().find(|(name, _)| *name == env) {
+                let closing = format!("\\end{{{}}}", env);
+                let close = text[env_end..]
+                    .find(&closing)
+                    .map_or(text.len(), |start| env_end + start + closing.len());
+                return Some((close, interpret_as));
+            }
+            let mut end = skip_optional_args(text, env_end);
+            let args = ENVIRONMENT_ARGS.iter().find(|(name, _)| *name == env).map_or(0, |(_, n)| *n);
+            for _ in 0..args {
+                end = group_end(text, end, b'{', b'}').unwrap_or(end);
+            }
+            Some((end, "\n\n"))
+        }
+        "end" => Some((group_end(text, end, b'{', b'}').unwrap_or(end), "\n\n")),
+        _ => {
+            if let Some(&(_, interpret_as)) = DROPPED_COMMANDS.iter().find(|(dropped, _)| *dropped == name) {
+                let mut end = end;
+                loop {
+                    let next = skip_optional_args(text, end);
+                    match group_end(text, next, b'{', b'}') {
+                        Some(group) => end = group,
+                        None => return Some((next, interpret_as)),
+                    }
+                }
+            }
+
+            if let Some(&(_, word)) = WORD_COMMANDS.iter().find(|(command, _)| *command == name) {
+                let mut end = skip_optional_args(text, end);
+                // `\item Texte` : pas d'espace en début de ligne
+                if word.ends_with('\n') {
+                    end += bytes[end..].iter().take_while(|b| **b == b' ').count();
+                }
+                return Some((end, word));
+            }
+
+            let mut end = skip_optional_args(text, end);
+            if SKIP_FIRST_ARG_COMMANDS.contains(&name) {
+                end = group_end(text, end, b'{', b'}').unwrap_or(end);
+            }
+
+            // Argument gardé : son accolade ouvrante fait partie du balisage
+            if bytes.get(end) == Some(&b'{') {
+                let paragraph = PARAGRAPH_COMMANDS.contains(&name);
+                braces.push(if paragraph { "\n\n" } else { "" });
+                return Some((end + 1, if paragraph { "\n\n" } else { "" }));
+            }
+
+            // Commande sans argument : l'espace qui la suit appartient à la commande
+            let spaces = bytes[end..].iter().take_while(|b| **b == b' ').count();
+            Some((end + spaces, ""))
+        }
+    }
+}
+
+/// Fin d'un groupe `{...}` ou `[...]` commençant exactement en `start`
+fn group_end(text: &str, start: usize, open: u8, close: u8) -> Option<usize> {
+    let bytes = text.as_bytes();
+    if bytes.get(start) != Some(&open) {
+        return None;
+    }
+    let mut depth = 0;
+    let mut i = start;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b if b == open => depth += 1,
+            b if b == close => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Saute les arguments optionnels `[...]`
+fn skip_optional_args(text: &str, mut end: usize) -> usize {
+    while let Some(group) = group_end(text, end, b'[', b']') {
+        end = group;
+    }
+    end
+}
+
+/// Fin de `$...$` ou `$$...$$` commençant en `start`
+fn inline_math_end(text: &str, start: usize) -> Option<usize> {
+    let delimiter = if text[start..].starts_with("$$") { "$$" } else { "$" };
+    let from = start + delimiter.len();
+    let mut search = from;
+    while let Some(offset) = text[search..].find(delimiter) {
+        let at = search + offset;
+        if !text[..at].ends_with('\\') {
+            return Some(at + delimiter.len());
+        }
+        search = at + 1;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(text: &str) -> String {
+        annotate(text).plain_text().to_string()
+    }
+
+    #[test]
+    fn test_commands_and_math() {
+        let text = "\\section{Intro}\nThis is \\emph{very} good~\\cite[p.~2]{k}. We have $x^2$ and 50\\%.\n% comment\n\\begin{equation}a=b\\end{equation}\nDone.";
+        assert_eq!(plain(text), "\n\nIntro\n\n\nThis is very good X. We have X and 50%.\n\nX\nDone.");
+    }
+
+    #[test]
+    fn test_environments_and_quotes() {
+        let text = "\\begin{itemize}\n\\item ``Quoted'' text\n\\end{itemize}\n\\begin{verbatim}\nraw $ \\x\n\\end{verbatim}";
+        assert_eq!(plain(text), "\n\n\n\n\n\"Quoted\" text\n\n\n\n\n\n");
+    }
+
+    #[test]
+    fn test_offsets_point_into_original() {
+        let text = "See \\href{https://a.b}{the tset} and \\textbf{it}.";
+        let annotated = annotate(text);
+        assert_eq!(annotated.plain_text(), "See the tset and it.");
+        let start = annotated.plain_text().find("tset").unwrap();
+        assert_eq!(&text[annotated.to_original(start..start + 4)], "tset");
+    }
+}
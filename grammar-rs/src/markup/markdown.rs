@@ -0,0 +1,339 @@
+//! Markdown (CommonMark + tables GFM) → texte annoté
+//!
+//! Pas un parseur complet : on retire ce qui gêne les checkers (préfixes de
+//! bloc, emphase, liens, code, balises HTML) et on garde le texte visible.
+
+use super::html::tag_interpretation;
+use super::{AnnotatedText, AnnotatedTextBuilder, PLACEHOLDER};
+
+pub(super) fn annotate(text: &str) -> AnnotatedText {
+    let mut builder = AnnotatedTextBuilder::new();
+
+    let body_start = front_matter_len(text);
+    builder.add_markup(&text[..body_start]);
+
+    // Clôture attendue du bloc de code en cours : (caractère, longueur)
+    let mut fence: Option<(char, usize)> = None;
+
+    for line in text[body_start..].split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        let newline = &line[content.len()..];
+        let trimmed = content.trim_start();
+
+        if let Some((ch, len)) = fence {
+            builder.add_markup(line);
+            if fence_run(trimmed).is_some_and(|(c, n)| c == ch && n >= len && trimmed[n..].trim().is_empty()) {
+                fence = None;
+            }
+            continue;
+        }
+
+        if let Some(run) = fence_run(trimmed) {
+            // Un bloc de code sépare les paragraphes
+            fence = Some(run);
+            builder.add_markup_as(line, "\n");
+            continue;
+        }
+
+        if is_thematic_break(trimmed) || is_link_definition(trimmed) || is_table_separator(trimmed) {
+            builder.add_markup(content).add_text(newline);
+            continue;
+        }
+
+        if trimmed.starts_with('|') {
+            table_row(&mut builder, content);
+            builder.add_text(newline);
+            continue;
+        }
+
+        let prefix = block_prefix_len(content);
+        builder.add_markup(&content[..prefix]);
+        inline(&mut builder, &content[prefix..]);
+        builder.add_text(newline);
+    }
+
+    builder.build()
+}
+
+/// Longueur du front matter YAML (`---` ... `---`) en tête de fichier
+fn front_matter_len(text: &str) -> usize {
+    if !text.starts_with("---\n") && !text.starts_with("---\r\n") {
+        return 0;
+    }
+    let mut offset = 0;
+    for (i, line) in text.split_inclusive('\n').enumerate() {
+        offset += line.len();
+        if i > 0 && line.trim_end() == "---" {
+            return offset;
+        }
+    }
+    0
+}
+
+/// Ouverture ou clôture de bloc de code : 3+ backticks ou tildes
+fn fence_run(line: &str) -> Option<(char, usize)> {
+    let ch = line.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let len = line.chars().take_while(|c| *c == ch).count();
+    (len >= 3).then_some((ch, len))
+}
+
+/// `---`, `***` ou `___` (espaces permis)
+fn is_thematic_break(line: &str) -> bool {
+    let mut chars = line.chars().filter(|c| !c.is_whitespace());
+    let Some(first) = chars.next().filter(|c| matches!(c, '-' | '*' | '_')) else {
+        return false;
+    };
+    let rest: Vec<char> = chars.collect();
+    rest.len() >= 2 && rest.iter().all(|c| *c == first)
+}
+
+/// `[id]: https://...`
+fn is_link_definition(line: &str) -> bool {
+    line.starts_with('[') && line.find("]:").is_some_and(|i| i > 1)
+}
+
+/// `|---|:---:|`
+fn is_table_separator(line: &str) -> bool {
+    line.starts_with('|')
+        && line.contains('-')
+        && line.chars().all(|c| matches!(c, '|' | '-' | ':' | ' ' | '\t'))
+}
+
+/// Une cellule par ligne : `| a | b |` → "a\nb"
+fn table_row(builder: &mut AnnotatedTextBuilder, line: &str) {
+    // Début du balisage qui précède la prochaine cellule
+    let mut separator_start = 0;
+    let mut first = true;
+    let mut cell_start = 0;
+
+    for (i, c) in line.char_indices() {
+        if c != '|' || line[..i].ends_with('\\') {
+            continue;
+        }
+        let cell = &line[cell_start..i];
+        let content = cell.trim();
+        // Les cellules vides restent dans le séparateur
+        if !content.is_empty() {
+            let content_start = cell_start + (cell.len() - cell.trim_start().len());
+            builder.add_markup_as(&line[separator_start..content_start], if first { "" } else { "\n" });
+            inline(builder, content);
+            separator_start = content_start + content.len();
+            first = false;
+        }
+        cell_start = i + 1;
+    }
+    builder.add_markup(&line[separator_start..]);
+}
+
+/// Indentation, citations (`>`), titre (`#`), puce ou numéro, case à cocher
+fn block_prefix_len(line: &str) -> usize {
+    let bytes = line.as_bytes();
+    let mut i = 0;
+    let skip_spaces = |mut i: usize| {
+        while i < bytes.len() && (bytes[i] == b' ' || bytes[i] == b'\t') {
+            i += 1;
+        }
+        i
+    };
+
+    i = skip_spaces(i);
+    while i < bytes.len() && bytes[i] == b'>' {
+        i = skip_spaces(i + 1);
+    }
+
+    let marker_end = |end: usize| (end == bytes.len() || bytes[end] == b' ' || bytes[end] == b'\t').then_some(end);
+    let hashes = bytes[i..].iter().take_while(|b| **b == b'#').count();
+    let digits = bytes[i..].iter().take_while(|b| b.is_ascii_digit()).count();
+
+    let marker = if (1..=6).contains(&hashes) {
+        marker_end(i + hashes)
+    } else if i < bytes.len() && matches!(bytes[i], b'-' | b'*' | b'+') {
+        marker_end(i + 1)
+    } else if (1..=9).contains(&digits) && matches!(bytes.get(i + digits), Some(b'.' | b')')) {
+        marker_end(i + digits + 1)
+    } else {
+        None
+    };
+
+    let Some(end) = marker else {
+        return i;
+    };
+    i = skip_spaces(end);
+
+    // Liste de tâches GFM
+    for checkbox in ["[ ]", "[x]", "[X]"] {
+        if line[i..].starts_with(checkbox) {
+            return skip_spaces(i + checkbox.len());
+        }
+    }
+    i
+}
+
+/// Balisage en ligne : emphase, code, liens, images, balises HTML, échappements
+fn inline(builder: &mut AnnotatedTextBuilder, s: &str) {
+    let bytes = s.as_bytes();
+    let mut text_start = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        // (fin du balisage, interprétation)
+        let markup: Option<(usize, &str)> = match bytes[i] {
+            b'\\' if bytes.get(i + 1).is_some_and(u8::is_ascii_punctuation) => {
+                builder.add_text(&s[text_start..i]).add_markup("\\");
+                text_start = i + 1;
+                i += 2;
+                continue;
+            }
+            b'`' => {
+                let run = bytes[i..].iter().take_while(|b| **b == b'`').count();
+                match find_backtick_run(s, i + run, run) {
+                    Some(end) => Some((end, PLACEHOLDER)),
+                    None => {
+                        i += run;
+                        continue;
+                    }
+                }
+            }
+            b'!' if bytes.get(i + 1) == Some(&b'[') => link(s, i + 1).map(|(_, end)| (end, "")),
+            b'[' => {
+                if let Some((label_end, end)) = link(s, i) {
+                    // Le texte du lien est vérifié, pas sa cible
+                    builder.add_text(&s[text_start..i]).add_markup("[");
+                    inline(builder, &s[i + 1..label_end]);
+                    builder.add_markup(&s[label_end..end]);
+                    text_start = end;
+                    i = end;
+                    continue;
+                }
+                None
+            }
+            b'<' => s[i..].find('>').and_then(|len| {
+                let tag = &s[i..=i + len];
+                if tag.contains("://") || (tag.contains('@') && !tag.contains(' ')) {
+                    Some((i + len + 1, PLACEHOLDER))
+                } else {
+                    tag_interpretation(tag).map(|interpret| (i + len + 1, interpret))
+                }
+            }),
+            b'*' | b'_' | b'~' => emphasis_run(s, i),
+            _ => None,
+        };
+
+        match markup {
+            Some((end, interpret_as)) => {
+                builder.add_text(&s[text_start..i]).add_markup_as(&s[i..end], interpret_as);
+                text_start = end;
+                i = end;
+            }
+            None => i += 1,
+        }
+    }
+
+    builder.add_text(&s[text_start..]);
+}
+
+/// Fin du code en ligne ouvert par `run` backticks (même longueur à la fermeture)
+fn find_backtick_run(s: &str, from: usize, run: usize) -> Option<usize> {
+    let bytes = s.as_bytes();
+    let mut i = from;
+    while i < bytes.len() {
+        if bytes[i] == b'`' {
+            let len = bytes[i..].iter().take_while(|b| **b == b'`').count();
+            if len == run {
+                return Some(i + len);
+            }
+            i += len;
+        } else {
+            i += 1;
+        }
+    }
+    None
+}
+
+/// `[texte](cible)` ou `[texte][ref]` : (position du `]`, fin du lien)
+fn link(s: &str, open: usize) -> Option<(usize, usize)> {
+    let label_end = matching(s, open, b'[', b']')?;
+    let close = match s.as_bytes().get(label_end + 1)? {
+        b'(' => matching(s, label_end + 1, b'(', b')')?,
+        b'[' => matching(s, label_end + 1, b'[', b']')?,
+        _ => return None,
+    };
+    Some((label_end, close + 1))
+}
+
+/// Position du délimiteur fermant qui équilibre celui en `open`
+fn matching(s: &str, open: usize, left: u8, right: u8) -> Option<usize> {
+    let mut depth = 0;
+    for (i, b) in s.bytes().enumerate().skip(open) {
+        if b == left {
+            depth += 1;
+        } else if b == right {
+            depth -= 1;
+            if depth == 0 {
+                return Some(i);
+            }
+        }
+    }
+    None
+}
+
+/// Délimiteur d'emphase (`*`, `_`, `**`, `~~`) collé à un mot
+fn emphasis_run(s: &str, i: usize) -> Option<(usize, &'static str)> {
+    let ch = s.as_bytes()[i];
+    let len = s.as_bytes()[i..].iter().take_while(|b| **b == ch).count();
+    if ch == b'~' && len != 2 {
+        return None;
+    }
+
+    let prev = s[..i].chars().next_back();
+    let next = s[i + len..].chars().next();
+    let blank = |c: Option<char>| c.is_none_or(char::is_whitespace);
+    let punct = |c: Option<char>| c.is_some_and(|c| c.is_ascii_punctuation());
+
+    let left_flanking = !blank(next) && (!punct(next) || blank(prev) || punct(prev));
+    let right_flanking = !blank(prev) && (!punct(prev) || blank(next) || punct(next));
+    // snake_case n'est pas de l'emphase
+    let intraword = prev.is_some_and(char::is_alphanumeric) && next.is_some_and(char::is_alphanumeric);
+
+    let delimiter = (left_flanking || right_flanking) && !(ch == b'_' && intraword);
+    delimiter.then_some((i + len, ""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(text: &str) -> String {
+        annotate(text).plain_text().to_string()
+    }
+
+    #[test]
+    fn test_inline_markup() {
+        assert_eq!(plain("This is **bold** and _it_."), "This is bold and it.");
+        assert_eq!(plain("See [the docs](https://x.y/a_(b)) now."), "See the docs now.");
+        assert_eq!(plain("Run `cargo build` first."), "Run X first.");
+        assert_eq!(plain("Keep snake_case and 2 * 3."), "Keep snake_case and 2 * 3.");
+        assert_eq!(plain("An ![logo](a.png) here <br> too"), "An  here \n too");
+    }
+
+    #[test]
+    fn test_blocks() {
+        let text = "---\ntitle: x\n---\n# Title\n\n> - [x] Done it\n\n```rust\nlet a = 1;\n```\nAfter.\n";
+        assert_eq!(plain(text), "Title\n\nDone it\n\n\nAfter.\n");
+    }
+
+    #[test]
+    fn test_table() {
+        let text = "| Name | Role |\n|------|:----:|\n| Bob | **dev** |\n";
+        assert_eq!(plain(text), "Name\nRole\n\nBob\ndev\n");
+    }
+
+    #[test]
+    fn test_offsets_point_into_original() {
+        let text = "Some **bold tset** here.";
+        let annotated = annotate(text);
+        let start = annotated.plain_text().find("tset").unwrap();
+        let span = annotated.to_original(start..start + 4);
+        assert_eq!(&text[span], "tset");
+    }
+}
@@ -0,0 +1,330 @@
+//! Texte annoté - vérifier du Markdown, HTML ou LaTeX sans leur syntaxe
+//!
+//! Équivalent du paramètre `data` de LanguageTool : un document est une
+//! suite de parties texte et balisage. Le pipeline ne voit que le texte
+//! brut (chaque balise remplacée par son `interpret_as`), puis les spans
+//! des `Match` sont reportés sur les offsets du document original.
+//!
+//! ```text
+//! "A <b>tset</b>."  →  plain "A tset."  →  SPELL 2..6  →  original 5..9
+//! ```
+
+mod html;
+mod latex;
+mod markdown;
+
+use crate::core::Match;
+use serde::Deserialize;
+use std::ops::Range;
+use std::path::Path;
+
+/// Texte de remplacement du code et des formules
+///
+/// Un mot neutre plutôt qu'une chaîne vide : « run `make` now » ne doit pas
+/// devenir « run  now » (double espace) ni « see . » (espace avant le point).
+pub const PLACEHOLDER: &str = "X";
+
+/// Correspondance entre un morceau du texte brut et du document original
+#[derive(Debug, Clone)]
+struct Segment {
+    plain: Range<usize>,
+    original: Range<usize>,
+    /// Texte copié tel quel (sinon balisage, mappé en bloc)
+    is_text: bool,
+}
+
+/// Document découpé en texte et balisage, avec la carte des offsets
+#[derive(Debug, Clone, Default)]
+pub struct AnnotatedText {
+    original: String,
+    plain: String,
+    segments: Vec<Segment>,
+}
+
+impl AnnotatedText {
+    /// Document sans balisage
+    pub fn plain(text: &str) -> Self {
+        let mut builder = AnnotatedTextBuilder::new();
+        builder.add_text(text);
+        builder.build()
+    }
+
+    /// Parse le JSON du paramètre `data` de LanguageTool
+    ///
+    /// `{"annotation": [{"text": "A "}, {"markup": "<b>"}, {"markup": "<br>", "interpretAs": "\n"}]}`
+    pub fn from_lt_json(data: &str) -> Result<Self, String> {
+        #[derive(Deserialize)]
+        struct Data {
+            annotation: Vec<Part>,
+        }
+
+        #[derive(Deserialize)]
+        struct Part {
+            text: Option<String>,
+            markup: Option<String>,
+            #[serde(rename = "interpretAs")]
+            interpret_as: Option<String>,
+        }
+
+        let data: Data = serde_json::from_str(data).map_err(|e| format!("invalid data: {}", e))?;
+        let mut builder = AnnotatedTextBuilder::new();
+        for (i, part) in data.annotation.into_iter().enumerate() {
+            match (part.text, part.markup) {
+                (Some(text), None) => builder.add_text(&text),
+                (None, Some(markup)) => {
+                    builder.add_markup_as(&markup, part.interpret_as.as_deref().unwrap_or(""))
+                }
+                _ => return Err(format!("invalid data: annotation {} needs either 'text' or 'markup'", i)),
+            };
+        }
+        Ok(builder.build())
+    }
+
+    /// Texte vu par le pipeline
+    pub fn plain_text(&self) -> &str {
+        &self.plain
+    }
+
+    /// Document original, balisage compris
+    pub fn original_text(&self) -> &str {
+        &self.original
+    }
+
+    /// Reporte un span du texte brut sur le document original
+    ///
+    /// Un span qui commence ou finit dans une balise interprétée (`&amp;`,
+    /// formule) l'englobe entièrement.
+    pub fn to_original(&self, span: Range<usize>) -> Range<usize> {
+        let start = self.original_start(span.start);
+        if span.end <= span.start {
+            return start..start;
+        }
+        start..self.original_end(span.end).max(start)
+    }
+
+    /// Reporte les spans de `matches` sur le document original
+    pub fn map_matches(&self, matches: &mut [Match]) {
+        for m in matches {
+            m.span = self.to_original(m.span.clone());
+        }
+    }
+
+    /// Vrai si un span du document original touche du balisage
+    ///
+    /// Remplacer un tel span (`apply_fixes`) supprimerait la balise :
+    /// « an <b>air</b> plane » → « an <b>airplane ».
+    pub fn covers_markup(&self, original: Range<usize>) -> bool {
+        let i = self.segments.partition_point(|s| s.original.end <= original.start);
+        self.segments[i..]
+            .iter()
+            .take_while(|s| s.original.start < original.end)
+            .any(|s| !s.is_text)
+    }
+
+    fn original_start(&self, pos: usize) -> usize {
+        // Premier segment non vide qui contient `pos` : à la frontière
+        // balise / texte, le match commence après la balise
+        let i = self.segments.partition_point(|s| s.plain.end <= pos);
+        match self.segments.get(i) {
+            Some(s) if s.is_text => s.original.start + (pos - s.plain.start),
+            Some(s) => s.original.start,
+            None => self.original.len(),
+        }
+    }
+
+    fn original_end(&self, pos: usize) -> usize {
+        // Premier segment qui finit à `pos` ou après : le match s'arrête
+        // avant la balise fermante
+        let i = self.segments.partition_point(|s| s.plain.end < pos);
+        match self.segments.get(i) {
+            Some(s) if s.is_text => s.original.start + (pos - s.plain.start),
+            Some(s) => s.original.end,
+            None => self.original.len(),
+        }
+    }
+}
+
+/// Construit un `AnnotatedText` partie par partie
+#[derive(Debug, Default)]
+pub struct AnnotatedTextBuilder {
+    text: AnnotatedText,
+}
+
+impl AnnotatedTextBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Texte vérifié tel quel
+    pub fn add_text(&mut self, text: &str) -> &mut Self {
+        if text.is_empty() {
+            return self;
+        }
+        let doc = &mut self.text;
+        match doc.segments.last_mut() {
+            // Fusionne avec le texte précédent
+            Some(last) if last.is_text => {
+                last.plain.end += text.len();
+                last.original.end += text.len();
+            }
+            _ => doc.segments.push(Segment {
+                plain: doc.plain.len()..doc.plain.len() + text.len(),
+                original: doc.original.len()..doc.original.len() + text.len(),
+                is_text: true,
+            }),
+        }
+        doc.plain.push_str(text);
+        doc.original.push_str(text);
+        self
+    }
+
+    /// Balisage ignoré
+    pub fn add_markup(&mut self, markup: &str) -> &mut Self {
+        self.add_markup_as(markup, "")
+    }
+
+    /// Balisage vu comme `interpret_as` par le pipeline (ex: `<br>` → `"\n"`)
+    pub fn add_markup_as(&mut self, markup: &str, interpret_as: &str) -> &mut Self {
+        if markup.is_empty() {
+            return self.add_text(interpret_as);
+        }
+        let doc = &mut self.text;
+        doc.segments.push(Segment {
+            plain: doc.plain.len()..doc.plain.len() + interpret_as.len(),
+            original: doc.original.len()..doc.original.len() + markup.len(),
+            is_text: false,
+        });
+        doc.plain.push_str(interpret_as);
+        doc.original.push_str(markup);
+        self
+    }
+
+    pub fn build(&mut self) -> AnnotatedText {
+        std::mem::take(&mut self.text)
+    }
+}
+
+/// Format d'un document
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MarkupFormat {
+    #[default]
+    Plain,
+    Markdown,
+    Html,
+    Latex,
+}
+
+impl MarkupFormat {
+    /// Format déduit de l'extension du fichier (texte brut par défaut)
+    pub fn from_path(path: &Path) -> Self {
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("md" | "markdown" | "mdx") => MarkupFormat::Markdown,
+            Some("html" | "htm" | "xhtml") => MarkupFormat::Html,
+            Some("tex" | "latex" | "ltx") => MarkupFormat::Latex,
+            _ => MarkupFormat::Plain,
+        }
+    }
+
+    /// Sépare le texte de son balisage
+    pub fn annotate(self, text: &str) -> AnnotatedText {
+        match self {
+            MarkupFormat::Plain => AnnotatedText::plain(text),
+            MarkupFormat::Markdown => markdown::annotate(text),
+            MarkupFormat::Html => html::annotate(text),
+            MarkupFormat::Latex => latex::annotate(text),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MarkupFormat::Plain => "plain",
+            MarkupFormat::Markdown => "markdown",
+            MarkupFormat::Html => "html",
+            MarkupFormat::Latex => "latex",
+        }
+    }
+}
+
+impl std::str::FromStr for MarkupFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "plain" | "text" | "txt" => Ok(MarkupFormat::Plain),
+            "markdown" | "md" => Ok(MarkupFormat::Markdown),
+            "html" => Ok(MarkupFormat::Html),
+            "latex" | "tex" => Ok(MarkupFormat::Latex),
+            other => Err(format!("unknown markup '{}'", other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> AnnotatedText {
+        let mut builder = AnnotatedTextBuilder::new();
+        builder
+            .add_text("A ")
+            .add_markup("<b>")
+            .add_text("tset")
+            .add_markup("</b>")
+            .add_markup_as("&amp;", "&")
+            .add_text(" b.");
+        builder.build()
+    }
+
+    #[test]
+    fn test_plain_and_original() {
+        let text = sample();
+        assert_eq!(text.plain_text(), "A tset& b.");
+        assert_eq!(text.original_text(), "A <b>tset</b>&amp; b.");
+    }
+
+    #[test]
+    fn test_covers_markup() {
+        let text = sample();
+        assert!(!text.covers_markup(5..9));
+        assert!(text.covers_markup(5..10));
+        assert!(text.covers_markup(13..18));
+        assert!(!text.covers_markup(18..21));
+    }
+
+    #[test]
+    fn test_span_mapping() {
+        let text = sample();
+        // "tset" : entre les balises
+        assert_eq!(text.to_original(2..6), 5..9);
+        // "&" : toute l'entité
+        assert_eq!(text.to_original(6..7), 13..18);
+        // "tset&" : de la balise ouvrante à la fin de l'entité
+        assert_eq!(text.to_original(2..7), 5..18);
+        // Span vide en fin de texte
+        assert_eq!(text.to_original(10..10), 21..21);
+    }
+
+    #[test]
+    fn test_from_lt_json() {
+        let text = AnnotatedText::from_lt_json(
+            r#"{"annotation":[{"text":"A "},{"markup":"<b>"},{"text":"test"},{"markup":"<br/>","interpretAs":"\n"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(text.plain_text(), "A test\n");
+        assert_eq!(text.original_text(), "A <b>test<br/>");
+
+        assert!(AnnotatedText::from_lt_json(r#"{"annotation":[{"interpretAs":"x"}]}"#).is_err());
+        assert!(AnnotatedText::from_lt_json("not json").is_err());
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(MarkupFormat::from_path(Path::new("docs/a.MD")), MarkupFormat::Markdown);
+        assert_eq!(MarkupFormat::from_path(Path::new("paper.tex")), MarkupFormat::Latex);
+        assert_eq!(MarkupFormat::from_path(Path::new("notes.txt")), MarkupFormat::Plain);
+    }
+}
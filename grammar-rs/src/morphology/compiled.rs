@@ -0,0 +1,514 @@
+//! Compiled morphology lexicon with memory-mapped storage
+//!
+//! Replaces parsing the Lefff TSV at startup: the lexicon is compiled once
+//! with `build-morphology` and then opened with a single `mmap`. Lookups go
+//! through two FSTs and read strings straight from the mapped file.
+//!
+//! File format:
+//! ```text
+//! Header (96 bytes):
+//!   - magic: "MRPH" (4 bytes)
+//!   - version: u32 (4 bytes)
+//!   - string_count: u64            - string_index_offset: u64
+//!   - strings_offset: u64          - entry_count: u64
+//!   - entries_offset: u64          - synth_count: u64
+//!   - synth_offset: u64            - forms_fst_offset: u64
+//!   - forms_fst_len: u64           - synth_fst_offset: u64
+//!   - synth_fst_len: u64
+//!
+//! Sections:
+//!   - String index: [(offset: u32, len: u32)] * string_count
+//!   - String data: packed UTF-8 (forms, lemmas and tags, deduplicated)
+//!   - Entries: [(lemma_id: u32, tag_id: u32)] * entry_count
+//!   - Synthesis: [form_id: u32] * synth_count
+//!   - Forms FST: form → (first entry << 32 | entry count)
+//!   - Synthesis FST: "lemma\ttag" → (first form << 32 | form count)
+//! ```
+
+use fst::{IntoStreamer, Map, MapBuilder, Streamer};
+use memmap2::Mmap;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{self, BufRead, BufWriter, Write};
+use std::ops::Range;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Magic bytes for the compiled morphology format
+pub const MAGIC: &[u8; 4] = b"MRPH";
+/// Current format version
+pub const VERSION: u32 = 1;
+/// Header size in bytes
+pub const HEADER_SIZE: usize = 96;
+
+/// Separator between lemma and tag in synthesis keys
+const KEY_SEPARATOR: char = '\t';
+
+fn invalid_data(msg: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.into())
+}
+
+/// Header structure for the compiled morphology file
+#[derive(Debug, Clone, Copy, Default)]
+struct MorphHeader {
+    string_count: u64,
+    string_index_offset: u64,
+    strings_offset: u64,
+    entry_count: u64,
+    entries_offset: u64,
+    synth_count: u64,
+    synth_offset: u64,
+    forms_fst_offset: u64,
+    forms_fst_len: u64,
+    synth_fst_offset: u64,
+    synth_fst_len: u64,
+}
+
+impl MorphHeader {
+    fn from_bytes(data: &[u8]) -> io::Result<Self> {
+        if data.len() < HEADER_SIZE {
+            return Err(invalid_data("Header too small"));
+        }
+        if &data[0..4] != MAGIC {
+            return Err(invalid_data("Invalid magic bytes"));
+        }
+        let version = u32::from_le_bytes(data[4..8].try_into().unwrap());
+        if version != VERSION {
+            return Err(invalid_data(format!("Unsupported version: {}", version)));
+        }
+
+        let field = |i: usize| u64::from_le_bytes(data[8 + i * 8..16 + i * 8].try_into().unwrap());
+        Ok(MorphHeader {
+            string_count: field(0),
+            string_index_offset: field(1),
+            strings_offset: field(2),
+            entry_count: field(3),
+            entries_offset: field(4),
+            synth_count: field(5),
+            synth_offset: field(6),
+            forms_fst_offset: field(7),
+            forms_fst_len: field(8),
+            synth_fst_offset: field(9),
+            synth_fst_len: field(10),
+        })
+    }
+
+    fn to_bytes(self) -> [u8; HEADER_SIZE] {
+        let mut buf = [0u8; HEADER_SIZE];
+        buf[0..4].copy_from_slice(MAGIC);
+        buf[4..8].copy_from_slice(&VERSION.to_le_bytes());
+        let fields = [
+            self.string_count,
+            self.string_index_offset,
+            self.strings_offset,
+            self.entry_count,
+            self.entries_offset,
+            self.synth_count,
+            self.synth_offset,
+            self.forms_fst_offset,
+            self.forms_fst_len,
+            self.synth_fst_offset,
+            self.synth_fst_len,
+        ];
+        for (i, value) in fields.iter().enumerate() {
+            buf[8 + i * 8..16 + i * 8].copy_from_slice(&value.to_le_bytes());
+        }
+        buf
+    }
+}
+
+/// A range of the shared mapping, usable as FST storage without copying
+#[derive(Clone)]
+struct MmapSlice {
+    mmap: Arc<Mmap>,
+    range: Range<usize>,
+}
+
+impl AsRef<[u8]> for MmapSlice {
+    fn as_ref(&self) -> &[u8] {
+        &self.mmap[self.range.clone()]
+    }
+}
+
+/// Packs a (start, count) pair into an FST value
+fn pack(start: usize, count: usize) -> u64 {
+    ((start as u64) << 32) | count as u64
+}
+
+fn unpack(value: u64) -> (usize, usize) {
+    ((value >> 32) as usize, (value & 0xFFFF_FFFF) as usize)
+}
+
+/// `start..start + count`, cut to the `len` items of a section
+fn clamp(start: usize, count: usize, len: u64) -> Range<usize> {
+    let len = usize::try_from(len).unwrap_or(usize::MAX);
+    start.min(len)..start.saturating_add(count).min(len)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset.checked_add(4)?).map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+}
+
+/// Memory-mapped morphology lexicon
+///
+/// Supports form → (lemma, tag) analysis and (lemma, tag) → form synthesis.
+/// Opening the file only maps it; pages are read on demand.
+pub struct CompiledMorphology {
+    mmap: Arc<Mmap>,
+    header: MorphHeader,
+    forms: Map<MmapSlice>,
+    synthesis: Map<MmapSlice>,
+}
+
+impl CompiledMorphology {
+    /// Open a compiled morphology file
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let file = File::open(path)?;
+        // SAFETY: the mapping is read-only and the lexicon is a build artifact
+        // that must not be modified while it is mapped. The sections are
+        // checked against the file size here, and lookups use `get` with
+        // checked offsets, so a corrupt file yields errors or empty lookups.
+        let mmap = Arc::new(unsafe { Mmap::map(&file)? });
+
+        if mmap.len() < HEADER_SIZE {
+            return Err(invalid_data("File too small"));
+        }
+        let header = MorphHeader::from_bytes(&mmap[..HEADER_SIZE])?;
+
+        // The string data has no length in the header: only its start is checked
+        let sections = [
+            (header.string_index_offset, header.string_count.saturating_mul(8)),
+            (header.strings_offset, 0),
+            (header.entries_offset, header.entry_count.saturating_mul(8)),
+            (header.synth_offset, header.synth_count.saturating_mul(4)),
+            (header.forms_fst_offset, header.forms_fst_len),
+            (header.synth_fst_offset, header.synth_fst_len),
+        ];
+        for (offset, len) in sections {
+            if offset.checked_add(len).is_none_or(|end| end > mmap.len() as u64) {
+                return Err(invalid_data("Section out of bounds"));
+            }
+        }
+
+        let fst_slice = |offset: u64, len: u64| -> io::Result<Map<MmapSlice>> {
+            let slice = MmapSlice {
+                mmap: Arc::clone(&mmap),
+                range: offset as usize..(offset + len) as usize,
+            };
+            Map::new(slice).map_err(|e| invalid_data(e.to_string()))
+        };
+        let forms = fst_slice(header.forms_fst_offset, header.forms_fst_len)?;
+        let synthesis = fst_slice(header.synth_fst_offset, header.synth_fst_len)?;
+
+        Ok(CompiledMorphology { mmap, header, forms, synthesis })
+    }
+
+    /// String by id, borrowed from the mapping
+    fn string(&self, id: u32) -> Option<&str> {
+        if u64::from(id) >= self.header.string_count {
+            return None;
+        }
+        // In bounds: the string index section was checked in `open`
+        let index = self.header.string_index_offset as usize + id as usize * 8;
+        let offset = read_u32(&self.mmap, index)? as usize;
+        let len = read_u32(&self.mmap, index + 4)? as usize;
+        let start = (self.header.strings_offset as usize).checked_add(offset)?;
+        std::str::from_utf8(self.mmap.get(start..start.checked_add(len)?)?).ok()
+    }
+
+    /// All (lemma, tag) readings of a lowercase form
+    pub fn analyze(&self, form: &str) -> Vec<(&str, &str)> {
+        self.readings(form).collect()
+    }
+
+    /// (lemma, tag) readings of a lowercase form, read lazily from the mapping
+    pub fn readings(&self, form: &str) -> Readings<'_> {
+        let (start, count) = self.forms.get(form).map_or((0, 0), unpack);
+        Readings { morph: self, entries: clamp(start, count, self.header.entry_count) }
+    }
+
+    /// (lemma, tag) of the entry at `index`
+    fn entry(&self, index: usize) -> Option<(&str, &str)> {
+        if index as u64 >= self.header.entry_count {
+            return None;
+        }
+        let offset = self.header.entries_offset as usize + index * 8;
+        let lemma = self.string(read_u32(&self.mmap, offset)?)?;
+        let tag = self.string(read_u32(&self.mmap, offset + 4)?)?;
+        Some((lemma, tag))
+    }
+
+    fn forms_at(&self, value: u64) -> Vec<&str> {
+        let (start, count) = unpack(value);
+        clamp(start, count, self.header.synth_count)
+            .filter_map(|i| {
+                let offset = self.header.synth_offset as usize + i * 4;
+                self.string(read_u32(&self.mmap, offset)?)
+            })
+            .collect()
+    }
+
+    /// Forms of a lemma for an exact tag
+    pub fn synthesize(&self, lemma: &str, tag: &str) -> Vec<&str> {
+        let key = format!("{}{}{}", lemma, KEY_SEPARATOR, tag);
+        self.synthesis.get(&key).map(|value| self.forms_at(value)).unwrap_or_default()
+    }
+
+    /// Forms of a lemma for every tag accepted by `matches`
+    pub fn synthesize_matching(&self, lemma: &str, mut matches: impl FnMut(&str) -> bool) -> Vec<&str> {
+        let prefix = format!("{}{}", lemma, KEY_SEPARATOR);
+        let mut stream = self.synthesis.range().ge(&prefix).into_stream();
+        let mut results = Vec::new();
+        while let Some((key, value)) = stream.next() {
+            let Some(tag) = key.strip_prefix(prefix.as_bytes()) else {
+                break;
+            };
+            if std::str::from_utf8(tag).is_ok_and(&mut matches) {
+                results.extend(self.forms_at(value));
+            }
+        }
+        results
+    }
+
+    /// Number of distinct forms
+    pub fn form_count(&self) -> usize {
+        self.forms.len()
+    }
+
+    /// Number of (lemma, tag) synthesis keys
+    pub fn synthesis_count(&self) -> usize {
+        self.synthesis.len()
+    }
+
+    /// Size of the mapped file in bytes
+    pub fn file_size(&self) -> usize {
+        self.mmap.len()
+    }
+}
+
+/// Iterator over the readings of a form (see `CompiledMorphology::readings`)
+pub struct Readings<'a> {
+    morph: &'a CompiledMorphology,
+    entries: Range<usize>,
+}
+
+impl<'a> Iterator for Readings<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let morph = self.morph;
+        self.entries.by_ref().find_map(|i| morph.entry(i))
+    }
+}
+
+/// Builds a compiled morphology file from (form, lemma, tag) triples
+#[derive(Default)]
+pub struct MorphologyBuilder {
+    strings: Vec<String>,
+    string_ids: HashMap<String, u32>,
+    /// form (lowercase) → [(lemma_id, tag_id)], in input order
+    analysis: BTreeMap<String, Vec<(u32, u32)>>,
+    /// "lemma\ttag" → [form_id]
+    synthesis: BTreeMap<String, Vec<u32>>,
+}
+
+impl MorphologyBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn intern(&mut self, s: &str) -> u32 {
+        if let Some(&id) = self.string_ids.get(s) {
+            return id;
+        }
+        let id = self.strings.len() as u32;
+        self.strings.push(s.to_string());
+        self.string_ids.insert(s.to_string(), id);
+        id
+    }
+
+    /// Add one reading; duplicates are ignored
+    pub fn add(&mut self, form: &str, lemma: &str, tag: &str) {
+        let form = form.to_lowercase();
+        let form_id = self.intern(&form);
+        let lemma_id = self.intern(lemma);
+        let tag_id = self.intern(tag);
+
+        let readings = self.analysis.entry(form).or_default();
+        if !readings.contains(&(lemma_id, tag_id)) {
+            readings.push((lemma_id, tag_id));
+        }
+        let forms = self.synthesis.entry(format!("{}{}{}", lemma, KEY_SEPARATOR, tag)).or_default();
+        if !forms.contains(&form_id) {
+            forms.push(form_id);
+        }
+    }
+
+    /// Add every `form\tlemma\ttag` line of a TSV lexicon
+    pub fn add_tsv<R: BufRead>(&mut self, reader: R) -> io::Result<()> {
+        for line in reader.lines() {
+            let line = line?;
+            let mut parts = line.split('\t');
+            if let (Some(form), Some(lemma), Some(tag)) = (parts.next(), parts.next(), parts.next()) {
+                self.add(form, lemma, tag);
+            }
+        }
+        Ok(())
+    }
+
+    /// Number of distinct forms added so far
+    pub fn form_count(&self) -> usize {
+        self.analysis.len()
+    }
+
+    /// Write the compiled file
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        let to_io = |e: fst::Error| invalid_data(e.to_string());
+
+        let mut string_index = Vec::with_capacity(self.strings.len() * 8);
+        let mut string_data = Vec::new();
+        for s in &self.strings {
+            string_index.extend_from_slice(&(string_data.len() as u32).to_le_bytes());
+            string_index.extend_from_slice(&(s.len() as u32).to_le_bytes());
+            string_data.extend_from_slice(s.as_bytes());
+        }
+
+        let mut entries = Vec::new();
+        let mut forms_fst = MapBuilder::memory();
+        let mut entry_count = 0;
+        for (form, readings) in &self.analysis {
+            forms_fst.insert(form, pack(entry_count, readings.len())).map_err(to_io)?;
+            for (lemma_id, tag_id) in readings {
+                entries.extend_from_slice(&lemma_id.to_le_bytes());
+                entries.extend_from_slice(&tag_id.to_le_bytes());
+            }
+            entry_count += readings.len();
+        }
+
+        let mut synth = Vec::new();
+        let mut synth_fst = MapBuilder::memory();
+        let mut synth_count = 0;
+        for (key, forms) in &self.synthesis {
+            synth_fst.insert(key, pack(synth_count, forms.len())).map_err(to_io)?;
+            for form_id in forms {
+                synth.extend_from_slice(&form_id.to_le_bytes());
+            }
+            synth_count += forms.len();
+        }
+
+        let forms_fst = forms_fst.into_inner().map_err(to_io)?;
+        let synth_fst = synth_fst.into_inner().map_err(to_io)?;
+
+        let mut header = MorphHeader {
+            string_count: self.strings.len() as u64,
+            entry_count: entry_count as u64,
+            synth_count: synth_count as u64,
+            forms_fst_len: forms_fst.len() as u64,
+            synth_fst_len: synth_fst.len() as u64,
+            ..MorphHeader::default()
+        };
+        let sections: [&[u8]; 6] = [&string_index, &string_data, &entries, &synth, &forms_fst, &synth_fst];
+        let mut offset = HEADER_SIZE as u64;
+        let mut offsets = [0u64; 6];
+        for (slot, section) in offsets.iter_mut().zip(sections) {
+            *slot = offset;
+            offset += section.len() as u64;
+        }
+        header.string_index_offset = offsets[0];
+        header.strings_offset = offsets[1];
+        header.entries_offset = offsets[2];
+        header.synth_offset = offsets[3];
+        header.forms_fst_offset = offsets[4];
+        header.synth_fst_offset = offsets[5];
+
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(&header.to_bytes())?;
+        for section in sections {
+            writer.write_all(section)?;
+        }
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::NamedTempFile;
+
+    const LEXICON: &str = "mange\tmanger\tV ind pres 3 s\n\
+                           mange\tmanger\tV ind pres 1 s\n\
+                           mangent\tmanger\tV ind pres 3 p\n\
+                           grande\tgrand\tJ f s\n\
+                           grandes\tgrand\tJ f p\n\
+                           grand\tgrand\tJ m s\n\
+                           Paris\tParis\tN m s\n";
+
+    fn compile() -> (NamedTempFile, CompiledMorphology) {
+        let mut builder = MorphologyBuilder::new();
+        builder.add_tsv(LEXICON.as_bytes()).unwrap();
+        let file = NamedTempFile::new().unwrap();
+        builder.write(file.path()).unwrap();
+        let morph = CompiledMorphology::open(file.path()).unwrap();
+        (file, morph)
+    }
+
+    #[test]
+    fn test_analysis_round_trip() {
+        let (_file, morph) = compile();
+        assert_eq!(morph.form_count(), 6);
+        assert_eq!(
+            morph.analyze("mange"),
+            vec![("manger", "V ind pres 3 s"), ("manger", "V ind pres 1 s")]
+        );
+        assert_eq!(morph.analyze("paris"), vec![("Paris", "N m s")]);
+        assert!(morph.analyze("inconnu").is_empty());
+    }
+
+    #[test]
+    fn test_synthesis_round_trip() {
+        let (_file, morph) = compile();
+        assert_eq!(morph.synthesize("grand", "J f s"), vec!["grande"]);
+        assert_eq!(morph.synthesize("manger", "V ind pres 3 p"), vec!["mangent"]);
+        assert!(morph.synthesize("grand", "J m p").is_empty());
+
+        let mut feminine = morph.synthesize_matching("grand", |tag| tag.starts_with("J f"));
+        feminine.sort();
+        assert_eq!(feminine, vec!["grande", "grandes"]);
+        // "grand\t" must not match lemmas that only share the prefix
+        assert!(morph.synthesize_matching("gran", |_| true).is_empty());
+    }
+
+    #[test]
+    fn test_rejects_other_files() {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(&[0u8; HEADER_SIZE]).unwrap();
+        assert!(CompiledMorphology::open(file.path()).is_err());
+    }
+
+    #[test]
+    fn test_corrupt_header_does_not_panic() {
+        let (file, _) = compile();
+        let bytes = std::fs::read(file.path()).unwrap();
+        let patched = |field: usize, value: u64| {
+            let mut bytes = bytes.clone();
+            bytes[8 + field * 8..16 + field * 8].copy_from_slice(&value.to_le_bytes());
+            let file = NamedTempFile::new().unwrap();
+            std::fs::write(file.path(), bytes).unwrap();
+            file
+        };
+
+        // strings_offset past the end of the file
+        let corrupt = patched(2, u64::MAX - 1);
+        assert!(CompiledMorphology::open(corrupt.path()).is_err());
+
+        // entry_count smaller than the FST values: lookups come back empty
+        let corrupt = patched(3, 0);
+        let morph = CompiledMorphology::open(corrupt.path()).unwrap();
+        assert!(morph.analyze("mange").is_empty());
+
+        // synth_count smaller than the FST values
+        let corrupt = patched(5, 0);
+        let morph = CompiledMorphology::open(corrupt.path()).unwrap();
+        assert!(morph.synthesize("grand", "J f s").is_empty());
+    }
+}
@@ -0,0 +1,539 @@
+//! English morphology: lemmatizer and inflection synthesizer
+//!
+//! Provides analysis (form → lemma+POS) and synthesis (lemma+POS → form)
+//! for English words with Penn Treebank tags. Built-in tables cover the
+//! irregular verbs, plurals and comparatives; regular forms are handled by
+//! spelling rules (stop → stopped, city → cities, big → bigger).
+//!
+//! A full lexicon can be loaded from a LanguageTool `english.dict` export
+//! or any TSV in `form\tlemma\tPOS` format.
+
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+use std::sync::OnceLock;
+use regex::Regex;
+use crate::checker::EN_ADDED_WORDS;
+use super::french::MorphEntry;
+
+/// Irregular verbs: (base, past, past participle)
+/// Default path of the LanguageTool `english.dict` export (optional)
+pub const EN_MORPHOLOGY_PATH: &str = "data/morphology/en_english.tsv";
+
+const IRREGULAR_VERBS: &[(&str, &str, &str)] = &[
+    ("arise", "arose", "arisen"), ("awake", "awoke", "awoken"), ("bear", "bore", "borne"),
+    ("beat", "beat", "beaten"), ("become", "became", "become"), ("begin", "began", "begun"),
+    ("bend", "bent", "bent"), ("bet", "bet", "bet"), ("bind", "bound", "bound"),
+    ("bite", "bit", "bitten"), ("bleed", "bled", "bled"), ("blow", "blew", "blown"),
+    ("break", "broke", "broken"), ("breed", "bred", "bred"), ("bring", "brought", "brought"),
+    ("build", "built", "built"), ("burn", "burnt", "burnt"), ("burst", "burst", "burst"),
+    ("buy", "bought", "bought"), ("cast", "cast", "cast"), ("catch", "caught", "caught"),
+    ("choose", "chose", "chosen"), ("cling", "clung", "clung"), ("come", "came", "come"),
+    ("cost", "cost", "cost"), ("creep", "crept", "crept"), ("cut", "cut", "cut"),
+    ("deal", "dealt", "dealt"), ("dig", "dug", "dug"), ("do", "did", "done"),
+    ("draw", "drew", "drawn"), ("dream", "dreamt", "dreamt"), ("drink", "drank", "drunk"),
+    ("drive", "drove", "driven"), ("eat", "ate", "eaten"), ("fall", "fell", "fallen"),
+    ("feed", "fed", "fed"), ("feel", "felt", "felt"), ("fight", "fought", "fought"),
+    ("find", "found", "found"), ("flee", "fled", "fled"), ("fling", "flung", "flung"),
+    ("fly", "flew", "flown"), ("forbid", "forbade", "forbidden"), ("forget", "forgot", "forgotten"),
+    ("forgive", "forgave", "forgiven"), ("freeze", "froze", "frozen"), ("get", "got", "gotten"),
+    ("give", "gave", "given"), ("go", "went", "gone"), ("grind", "ground", "ground"),
+    ("grow", "grew", "grown"), ("hang", "hung", "hung"), ("have", "had", "had"),
+    ("hear", "heard", "heard"), ("hide", "hid", "hidden"), ("hit", "hit", "hit"),
+    ("hold", "held", "held"), ("hurt", "hurt", "hurt"), ("keep", "kept", "kept"),
+    ("kneel", "knelt", "knelt"), ("know", "knew", "known"), ("lay", "laid", "laid"),
+    ("lead", "led", "led"), ("lean", "leant", "leant"), ("leap", "leapt", "leapt"),
+    ("learn", "learnt", "learnt"), ("leave", "left", "left"), ("lend", "lent", "lent"),
+    ("let", "let", "let"), ("lie", "lay", "lain"), ("light", "lit", "lit"),
+    ("lose", "lost", "lost"), ("make", "made", "made"), ("mean", "meant", "meant"),
+    ("meet", "met", "met"), ("mistake", "mistook", "mistaken"), ("overcome", "overcame", "overcome"),
+    ("pay", "paid", "paid"), ("put", "put", "put"), ("quit", "quit", "quit"),
+    ("read", "read", "read"), ("ride", "rode", "ridden"), ("ring", "rang", "rung"),
+    ("rise", "rose", "risen"), ("run", "ran", "run"), ("say", "said", "said"),
+    ("see", "saw", "seen"), ("seek", "sought", "sought"), ("sell", "sold", "sold"),
+    ("send", "sent", "sent"), ("set", "set", "set"), ("sew", "sewed", "sewn"),
+    ("shake", "shook", "shaken"), ("shed", "shed", "shed"), ("shine", "shone", "shone"),
+    ("shoot", "shot", "shot"), ("show", "showed", "shown"), ("shrink", "shrank", "shrunk"),
+    ("shut", "shut", "shut"), ("sing", "sang", "sung"), ("sink", "sank", "sunk"),
+    ("sit", "sat", "sat"), ("sleep", "slept", "slept"), ("slide", "slid", "slid"),
+    ("speak", "spoke", "spoken"), ("speed", "sped", "sped"), ("spell", "spelt", "spelt"),
+    ("spend", "spent", "spent"), ("spill", "spilt", "spilt"), ("spin", "spun", "spun"),
+    ("spit", "spat", "spat"), ("split", "split", "split"), ("spread", "spread", "spread"),
+    ("spring", "sprang", "sprung"), ("stand", "stood", "stood"), ("steal", "stole", "stolen"),
+    ("stick", "stuck", "stuck"), ("sting", "stung", "stung"), ("stink", "stank", "stunk"),
+    ("strike", "struck", "struck"), ("swear", "swore", "sworn"), ("sweep", "swept", "swept"),
+    ("swim", "swam", "swum"), ("swing", "swung", "swung"), ("take", "took", "taken"),
+    ("teach", "taught", "taught"), ("tear", "tore", "torn"), ("tell", "told", "told"),
+    ("think", "thought", "thought"), ("throw", "threw", "thrown"), ("understand", "understood", "understood"),
+    ("undertake", "undertook", "undertaken"), ("upset", "upset", "upset"), ("wake", "woke", "woken"),
+    ("wear", "wore", "worn"), ("weave", "wove", "woven"), ("weep", "wept", "wept"),
+    ("win", "won", "won"), ("wind", "wound", "wound"), ("withdraw", "withdrew", "withdrawn"),
+    ("write", "wrote", "written"),
+];
+
+/// Irregular nouns: (singular, plural)
+const IRREGULAR_PLURALS: &[(&str, &str)] = &[
+    ("man", "men"), ("woman", "women"), ("child", "children"), ("person", "people"),
+    ("mouse", "mice"), ("goose", "geese"), ("foot", "feet"), ("tooth", "teeth"),
+    ("ox", "oxen"), ("life", "lives"), ("wife", "wives"), ("knife", "knives"),
+    ("leaf", "leaves"), ("half", "halves"), ("wolf", "wolves"), ("shelf", "shelves"),
+    ("thief", "thieves"), ("loaf", "loaves"), ("calf", "calves"), ("self", "selves"),
+    ("potato", "potatoes"), ("tomato", "tomatoes"), ("hero", "heroes"), ("echo", "echoes"),
+    ("analysis", "analyses"), ("crisis", "crises"), ("thesis", "theses"), ("basis", "bases"),
+    ("phenomenon", "phenomena"), ("criterion", "criteria"), ("datum", "data"), ("medium", "media"),
+    ("cactus", "cacti"), ("fungus", "fungi"), ("nucleus", "nuclei"), ("index", "indices"),
+    ("appendix", "appendices"), ("sheep", "sheep"), ("fish", "fish"), ("deer", "deer"),
+    ("series", "series"), ("species", "species"), ("aircraft", "aircraft"),
+];
+
+/// Irregular comparatives: (base, comparative, superlative)
+const IRREGULAR_COMPARATIVES: &[(&str, &str, &str)] = &[
+    ("good", "better", "best"), ("bad", "worse", "worst"), ("far", "farther", "farthest"),
+    ("far", "further", "furthest"), ("little", "less", "least"), ("many", "more", "most"),
+    ("much", "more", "most"),
+];
+
+/// Tags that rule-based synthesis can produce (for `synthesize_regex`)
+const INFLECTION_TAGS: &[&str] = &[
+    "NN", "NNS", "VB", "VBP", "VBZ", "VBD", "VBN", "VBG", "JJ", "JJR", "JJS",
+];
+
+/// English morphology dictionary for analysis and synthesis
+pub struct EnglishMorphology {
+    /// form (lowercase) → Vec<(lemma, POS)>
+    analysis: HashMap<String, Vec<MorphEntry>>,
+    /// (lemma, POS) → Vec<form>
+    synthesis: HashMap<(String, String), Vec<String>>,
+    /// All unique POS tags for regex matching
+    all_pos_tags: Vec<String>,
+}
+
+/// Static instance loaded once
+static ENGLISH_MORPHOLOGY: OnceLock<EnglishMorphology> = OnceLock::new();
+
+impl EnglishMorphology {
+    /// Load the English morphology (singleton)
+    ///
+    /// Built-in tables plus `data/morphology/en_english.tsv` when present.
+    pub fn load() -> &'static EnglishMorphology {
+        ENGLISH_MORPHOLOGY.get_or_init(|| {
+            let path = Path::new(EN_MORPHOLOGY_PATH);
+            if !path.exists() {
+                return Self::builtin();
+            }
+            Self::with_tsv(path).unwrap_or_else(|e| {
+                tracing::warn!("Failed to read English morphology {:?}: {}", path, e);
+                Self::builtin()
+            })
+        })
+    }
+
+    /// Initialize the singleton with the TSV at an explicit path
+    ///
+    /// Fails if the file cannot be read or if `load()` already ran.
+    pub fn init(path: &Path) -> io::Result<&'static EnglishMorphology> {
+        let morph = Self::with_tsv(path)?;
+        ENGLISH_MORPHOLOGY.set(morph).map_err(|_| {
+            io::Error::new(io::ErrorKind::AlreadyExists, "English morphology already loaded")
+        })?;
+        Ok(Self::load())
+    }
+
+    /// Built-in tables plus the entries of a "form\tlemma\tPOS" file
+    fn with_tsv(path: &Path) -> io::Result<EnglishMorphology> {
+        let text = std::fs::read_to_string(path)?;
+        let mut morph = Self::builtin();
+        morph.load_from_lines(text.lines());
+        tracing::info!("Loaded English morphology from {:?}: {} forms", path, morph.analysis.len());
+        Ok(morph)
+    }
+
+    /// Built-in irregular forms and LanguageTool added words
+    pub fn builtin() -> EnglishMorphology {
+        let mut morph = EnglishMorphology {
+            analysis: HashMap::new(),
+            synthesis: HashMap::new(),
+            all_pos_tags: Vec::new(),
+        };
+
+        // be / have / do / go have irregular present forms
+        for (form, tag) in [
+            ("be", "VB"), ("am", "VBP"), ("are", "VBP"), ("is", "VBZ"), ("was", "VBD"),
+            ("were", "VBD"), ("been", "VBN"), ("being", "VBG"),
+        ] {
+            morph.add(form, "be", tag);
+        }
+        for (base, third) in [("have", "has"), ("do", "does"), ("go", "goes")] {
+            morph.add(third, base, "VBZ");
+        }
+
+        for &(base, past, participle) in IRREGULAR_VERBS {
+            morph.add(base, base, "VB");
+            morph.add(base, base, "VBP");
+            if let Some(third) = inflect(base, "VBZ") {
+                if morph.synthesize_known(base, "VBZ").is_empty() {
+                    morph.add(&third, base, "VBZ");
+                }
+            }
+            morph.add(past, base, "VBD");
+            morph.add(participle, base, "VBN");
+            if let Some(gerund) = inflect(base, "VBG") {
+                morph.add(&gerund, base, "VBG");
+            }
+        }
+        for &(singular, plural) in IRREGULAR_PLURALS {
+            morph.add(singular, singular, "NN");
+            morph.add(plural, singular, "NNS");
+        }
+        for &(base, comparative, superlative) in IRREGULAR_COMPARATIVES {
+            morph.add(base, base, "JJ");
+            morph.add(comparative, base, "JJR");
+            morph.add(superlative, base, "JJS");
+        }
+
+        for entry in EN_ADDED_WORDS {
+            morph.add(entry.word, entry.base_form, entry.pos_tag);
+        }
+
+        tracing::debug!("Loaded built-in English morphology: {} forms", morph.analysis.len());
+        morph
+    }
+
+    /// Load entries from lines in "form\tlemma\tPOS" format
+    ///
+    /// This is the format of LanguageTool's `english.dict` export (and of
+    /// `added.txt`); comment lines start with `#`.
+    pub fn load_from_lines<I, S>(&mut self, lines: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for line in lines {
+            let line = line.as_ref();
+            if line.starts_with('#') {
+                continue;
+            }
+            let parts: Vec<&str> = line.split('\t').collect();
+            if parts.len() >= 3 && !parts[0].is_empty() {
+                self.add(parts[0], parts[1], parts[2]);
+            }
+        }
+    }
+
+    fn add(&mut self, form: &str, lemma: &str, pos: &str) {
+        let form = form.to_lowercase();
+        let entries = self.analysis.entry(form.clone()).or_default();
+        if entries.iter().any(|e| e.lemma == lemma && e.pos == pos) {
+            return;
+        }
+        entries.push(MorphEntry { lemma: lemma.to_string(), pos: pos.to_string() });
+
+        self.synthesis.entry((lemma.to_string(), pos.to_string()))
+            .or_default()
+            .push(form);
+
+        if !self.all_pos_tags.iter().any(|p| p == pos) {
+            self.all_pos_tags.push(pos.to_string());
+        }
+    }
+
+    /// Analyze a word form: returns all known (lemma, POS) readings
+    pub fn analyze(&self, form: &str) -> &[MorphEntry] {
+        self.analysis.get(&form.to_lowercase())
+            .map(|v| v.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Get the first lemma for a form (for simple cases)
+    pub fn get_lemma(&self, form: &str) -> Option<&str> {
+        self.analyze(form).first().map(|e| e.lemma.as_str())
+    }
+
+    /// Lemma of a form with a given POS tag
+    ///
+    /// Known forms come from the dictionary, others from the spelling rules
+    /// (walked/VBD → walk, cities/NNS → city, bigger/JJR → big).
+    pub fn lemmatize(&self, form: &str, pos: &str) -> String {
+        if let Some(entry) = self.analyze(form).iter().find(|e| e.pos == pos) {
+            return entry.lemma.clone();
+        }
+        let lower = form.to_lowercase();
+        lemmatize_regular(&lower, pos).unwrap_or(lower)
+    }
+
+    /// Synthesize: given lemma + exact POS tag, return matching forms
+    ///
+    /// Falls back to the regular inflection when the dictionary has no form.
+    pub fn synthesize(&self, lemma: &str, pos: &str) -> Vec<String> {
+        let known = self.synthesize_known(lemma, pos);
+        if !known.is_empty() {
+            return known.into_iter().map(str::to_string).collect();
+        }
+        inflect(lemma, pos).into_iter().collect()
+    }
+
+    /// Synthesize with regex: find forms where POS matches the pattern
+    pub fn synthesize_regex(&self, lemma: &str, pos_pattern: &str) -> Vec<String> {
+        // First try exact match (faster)
+        let exact = self.synthesize(lemma, pos_pattern);
+        if !exact.is_empty() {
+            return exact;
+        }
+
+        let re = match Regex::new(&format!("^(?:{})$", pos_pattern)) {
+            Ok(r) => r,
+            Err(_) => return Vec::new(),
+        };
+
+        let mut results: Vec<String> = Vec::new();
+        let tags = self.all_pos_tags.iter().map(String::as_str).chain(INFLECTION_TAGS.iter().copied());
+        for pos in tags.filter(|pos| re.is_match(pos)) {
+            for form in self.synthesize(lemma, pos) {
+                if !results.contains(&form) {
+                    results.push(form);
+                }
+            }
+        }
+        results
+    }
+
+    fn synthesize_known(&self, lemma: &str, pos: &str) -> Vec<&str> {
+        self.synthesis.get(&(lemma.to_string(), pos.to_string()))
+            .map(|forms| forms.iter().map(|s| s.as_str()).collect())
+            .unwrap_or_default()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// Spelling rules for regular forms
+// ═══════════════════════════════════════════════════════════════════════════════
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+/// Number of vowel groups (rough syllable count)
+fn syllables(word: &str) -> usize {
+    let mut count = 0;
+    let mut previous_vowel = false;
+    for c in word.chars() {
+        let vowel = is_vowel(c) || (c == 'y' && count > 0);
+        if vowel && !previous_vowel {
+            count += 1;
+        }
+        previous_vowel = vowel;
+    }
+    count
+}
+
+/// Short consonant-vowel-consonant ending whose consonant doubles (stop → stopped)
+fn doubles_final_consonant(word: &str) -> bool {
+    let chars: Vec<char> = word.chars().collect();
+    let [.., a, b, c] = chars[..] else {
+        return false;
+    };
+    syllables(word) == 1
+        && !is_vowel(a)
+        && is_vowel(b)
+        && !is_vowel(c)
+        && !matches!(c, 'w' | 'x' | 'y')
+}
+
+/// Consonant + y ending (city → cities)
+fn ends_with_consonant_y(word: &str) -> bool {
+    let mut chars = word.chars().rev();
+    chars.next() == Some('y') && chars.next().is_some_and(|c| !is_vowel(c))
+}
+
+fn ends_with_sibilant(word: &str) -> bool {
+    ["s", "x", "z", "ch", "sh"].iter().any(|end| word.ends_with(end))
+}
+
+/// Regular inflection of a lemma for a Penn tag
+fn inflect(lemma: &str, pos: &str) -> Option<String> {
+    let word = lemma.to_lowercase();
+    let last = word.chars().next_back()?;
+    let stem = &word[..word.len() - last.len_utf8()];
+
+    let form = match pos {
+        "NN" | "VB" | "VBP" | "JJ" => word.clone(),
+        "NNS" | "VBZ" => {
+            if ends_with_consonant_y(&word) {
+                format!("{stem}ies")
+            } else if ends_with_sibilant(&word) || (pos == "VBZ" && last == 'o') {
+                format!("{word}es")
+            } else {
+                format!("{word}s")
+            }
+        }
+        "VBD" | "VBN" => {
+            if last == 'e' {
+                format!("{word}d")
+            } else if ends_with_consonant_y(&word) {
+                format!("{stem}ied")
+            } else if doubles_final_consonant(&word) {
+                format!("{word}{last}ed")
+            } else {
+                format!("{word}ed")
+            }
+        }
+        "VBG" => {
+            if word.ends_with("ie") {
+                format!("{}ying", &word[..word.len() - 2])
+            } else if last == 'e' && !word.ends_with("ee") && !word.ends_with("ye") && !word.ends_with("oe") && word.len() > 2 {
+                format!("{stem}ing")
+            } else if doubles_final_consonant(&word) {
+                format!("{word}{last}ing")
+            } else {
+                format!("{word}ing")
+            }
+        }
+        "JJR" | "JJS" => {
+            // Longer adjectives use "more"/"most" instead
+            let short = syllables(&word) == 1 || (syllables(&word) == 2 && ends_with_consonant_y(&word));
+            if !short {
+                return None;
+            }
+            let suffix = if pos == "JJR" { "er" } else { "est" };
+            if last == 'e' {
+                format!("{word}{}", &suffix[1..])
+            } else if ends_with_consonant_y(&word) {
+                format!("{stem}i{suffix}")
+            } else if doubles_final_consonant(&word) {
+                format!("{word}{last}{suffix}")
+            } else {
+                format!("{word}{suffix}")
+            }
+        }
+        _ => return None,
+    };
+    Some(form)
+}
+
+/// Undo the spelling changes of a stripped suffix (-ed, -ing, -er, -est)
+fn restore_stem(stem: &str) -> String {
+    let chars: Vec<char> = stem.chars().collect();
+    match chars[..] {
+        // stopped → stop (but called → call, passed → pass)
+        [.., a, b] if a == b && !is_vowel(b) && !matches!(b, 'l' | 's' | 'z' | 'f') => {
+            stem[..stem.len() - b.len_utf8()].to_string()
+        }
+        // baked → bake, loved → love, danced → dance, organized → organize
+        [.., 'v'] | [.., 'c'] | [.., 'i', 'z'] | [.., 'y', 's'] => format!("{stem}e"),
+        _ if doubles_final_consonant(stem) && !stem.ends_with(['r', 'l', 'n', 'm']) => format!("{stem}e"),
+        _ => stem.to_string(),
+    }
+}
+
+/// Lemma of a regular inflected form
+fn lemmatize_regular(word: &str, pos: &str) -> Option<String> {
+    let lemma = match pos {
+        "NNS" | "VBZ" => {
+            if let Some(stem) = word.strip_suffix("ies").filter(|s| s.len() > 1) {
+                format!("{stem}y")
+            } else if let Some(stem) = word.strip_suffix("es").filter(|s| ends_with_sibilant(s) || (pos == "VBZ" && s.ends_with('o'))) {
+                stem.to_string()
+            } else if word.ends_with("ss") || word.ends_with("us") || word.ends_with("is") {
+                word.to_string()
+            } else {
+                word.strip_suffix('s')?.to_string()
+            }
+        }
+        "VBD" | "VBN" => {
+            if let Some(stem) = word.strip_suffix("ied").filter(|s| s.len() > 1) {
+                format!("{stem}y")
+            } else if let Some(stem) = word.strip_suffix("eed") {
+                format!("{stem}ee")
+            } else {
+                restore_stem(word.strip_suffix("ed")?)
+            }
+        }
+        "VBG" => {
+            let stem = word.strip_suffix("ing")?;
+            // dying → die, lying → lie (but trying → try)
+            match stem.strip_suffix('y') {
+                Some(base) if base.len() == 1 => format!("{base}ie"),
+                _ => restore_stem(stem),
+            }
+        }
+        "JJR" | "JJS" => {
+            let stem = word.strip_suffix(if pos == "JJR" { "er" } else { "est" })?;
+            match stem.strip_suffix('i') {
+                Some(base) => format!("{base}y"),
+                None => restore_stem(stem),
+            }
+        }
+        _ => return None,
+    };
+    (!lemma.is_empty()).then_some(lemma)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_irregular_analysis() {
+        let morph = EnglishMorphology::builtin();
+
+        assert!(morph.analyze("went").iter().any(|e| e.lemma == "go" && e.pos == "VBD"));
+        assert!(morph.analyze("children").iter().any(|e| e.lemma == "child" && e.pos == "NNS"));
+        assert!(morph.analyze("better").iter().any(|e| e.lemma == "good" && e.pos == "JJR"));
+        assert_eq!(morph.lemmatize("was", "VBD"), "be");
+        assert_eq!(morph.lemmatize("Seen", "VBN"), "see");
+    }
+
+    #[test]
+    fn test_regular_lemmatization() {
+        let morph = EnglishMorphology::builtin();
+
+        for (form, pos, lemma) in [
+            ("walked", "VBD", "walk"), ("stopped", "VBD", "stop"), ("baked", "VBN", "bake"),
+            ("called", "VBD", "call"), ("tried", "VBD", "try"), ("agreed", "VBD", "agree"),
+            ("running", "VBG", "run"), ("making", "VBG", "make"), ("dying", "VBG", "die"),
+            ("trying", "VBG", "try"),
+            ("cities", "NNS", "city"), ("boxes", "NNS", "box"), ("dogs", "NNS", "dog"),
+            ("goes", "VBZ", "go"), ("bus", "NNS", "bus"), ("bigger", "JJR", "big"),
+            ("happiest", "JJS", "happy"), ("nicer", "JJR", "nice"), ("dog", "NN", "dog"),
+        ] {
+            assert_eq!(morph.lemmatize(form, pos), lemma, "{form}/{pos}");
+        }
+    }
+
+    #[test]
+    fn test_synthesis() {
+        let morph = EnglishMorphology::builtin();
+
+        for (lemma, pos, form) in [
+            ("go", "VBD", "went"), ("write", "VBN", "written"), ("have", "VBZ", "has"),
+            ("walk", "VBD", "walked"), ("stop", "VBG", "stopping"), ("make", "VBG", "making"),
+            ("try", "VBZ", "tries"), ("watch", "VBZ", "watches"), ("city", "NNS", "cities"),
+            ("child", "NNS", "children"), ("big", "JJR", "bigger"), ("happy", "JJS", "happiest"),
+            ("good", "JJR", "better"),
+        ] {
+            assert_eq!(morph.synthesize(lemma, pos), vec![form.to_string()], "{lemma}/{pos}");
+        }
+        // Long adjectives have no single-word comparative
+        assert!(morph.synthesize("beautiful", "JJR").is_empty());
+    }
+
+    #[test]
+    fn test_synthesize_regex_and_tsv() {
+        let mut morph = EnglishMorphology::builtin();
+        morph.load_from_lines(["# comment", "octopodes\toctopus\tNNS", "octopus\toctopus\tNN"]);
+
+        assert_eq!(morph.synthesize("octopus", "NNS"), vec!["octopodes".to_string()]);
+        let forms = morph.synthesize_regex("see", "VB[DN]");
+        assert!(forms.contains(&"saw".to_string()) && forms.contains(&"seen".to_string()));
+    }
+
+    #[test]
+    fn test_non_ascii_words() {
+        let morph = EnglishMorphology::builtin();
+
+        assert_eq!(morph.synthesize("café", "NNS"), vec!["cafés".to_string()]);
+        assert_eq!(morph.synthesize("fiancé", "VBD"), vec!["fiancéed".to_string()]);
+        assert!(morph.synthesize_regex("cliché", "NN.*").contains(&"clichés".to_string()));
+        assert_eq!(morph.lemmatize("ŋŋed", "VBD"), "ŋ");
+    }
+}
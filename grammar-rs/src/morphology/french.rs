@@ -3,10 +3,15 @@
 //! Provides analysis (form → lemma+POS) and synthesis (lemma+POS → form)
 //! for French words.
 
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::io;
+use std::path::Path;
 use std::sync::OnceLock;
 use regex::Regex;
 
+use super::compiled::{CompiledMorphology, Readings};
+
 /// Entry in the morphology dictionary
 #[derive(Debug, Clone)]
 pub struct MorphEntry {
@@ -14,14 +19,29 @@ pub struct MorphEntry {
     pub pos: String,
 }
 
+/// Environment variable overriding the compiled morphology path
+pub const FR_MORPHOLOGY_ENV: &str = "GRAMMAR_RS_FR_MORPHOLOGY";
+/// Default path of the compiled morphology (built with `build-morphology`)
+pub const FR_MORPHOLOGY_PATH: &str = "data/morphology/fr_lefff.morph";
+/// Lefff TSV, parsed at startup when no compiled file is available
+const FR_LEFFF_TSV_PATH: &str = "data/morphology/fr_lefff.tsv";
+
+/// Stockage du lexique : tables en mémoire ou fichier compilé (mmap)
+enum Storage {
+    Tables {
+        /// form (lowercase) → Vec<(lemma, POS)>
+        analysis: HashMap<String, Vec<MorphEntry>>,
+        /// (lemma, POS) → Vec<form>
+        synthesis: HashMap<(String, String), Vec<String>>,
+        /// All unique POS tags for regex matching
+        all_pos_tags: Vec<String>,
+    },
+    Compiled(CompiledMorphology),
+}
+
 /// French morphology dictionary for analysis and synthesis
 pub struct FrenchMorphology {
-    /// form (lowercase) → Vec<(lemma, POS)>
-    analysis: HashMap<String, Vec<MorphEntry>>,
-    /// (lemma, POS) → Vec<form>
-    synthesis: HashMap<(String, String), Vec<String>>,
-    /// All unique POS tags for regex matching
-    all_pos_tags: Vec<String>,
+    storage: Storage,
 }
 
 /// Static instance loaded once
@@ -29,15 +49,46 @@ static FRENCH_MORPHOLOGY: OnceLock<FrenchMorphology> = OnceLock::new();
 
 impl FrenchMorphology {
     /// Load the French morphology dictionary (singleton)
+    ///
+    /// Order: compiled file (`GRAMMAR_RS_FR_MORPHOLOGY` or
+    /// `data/morphology/fr_lefff.morph`), then the Lefff TSV, then the
+    /// built-in minimal dictionary.
     pub fn load() -> &'static FrenchMorphology {
         FRENCH_MORPHOLOGY.get_or_init(|| {
             Self::load_from_embedded()
         })
     }
 
+    /// Initialize the singleton from a compiled file at an explicit path
+    ///
+    /// Fails if the file cannot be opened or if `load()` already ran.
+    pub fn init(path: &Path) -> io::Result<&'static FrenchMorphology> {
+        let morph = Self::open(path)?;
+        FRENCH_MORPHOLOGY.set(morph).map_err(|_| {
+            io::Error::new(io::ErrorKind::AlreadyExists, "French morphology already loaded")
+        })?;
+        Ok(Self::load())
+    }
+
+    /// Open a compiled morphology file (zero-copy, memory-mapped)
+    pub fn open(path: &Path) -> io::Result<FrenchMorphology> {
+        let compiled = CompiledMorphology::open(path)?;
+        tracing::info!("Opened compiled French morphology {:?}: {} forms, {} synthesis entries",
+                      path, compiled.form_count(), compiled.synthesis_count());
+        Ok(FrenchMorphology { storage: Storage::Compiled(compiled) })
+    }
+
+    /// True when backed by a compiled (memory-mapped) file
+    pub fn is_compiled(&self) -> bool {
+        matches!(self.storage, Storage::Compiled(_))
+    }
+
     /// Load from embedded data
     fn load_from_embedded() -> FrenchMorphology {
-        // Try to load from binary file, fallback to built-in minimal dictionary
+        if let Some(morph) = Self::try_load_compiled() {
+            return morph;
+        }
+
         if let Some(morph) = Self::try_load_from_file() {
             return morph;
         }
@@ -46,19 +97,36 @@ impl FrenchMorphology {
         Self::load_minimal()
     }
 
-    /// Try to load from data/morphology/fr_morphology.bin
+    /// Try to open the compiled file from the configured path
+    fn try_load_compiled() -> Option<FrenchMorphology> {
+        let path = std::env::var(FR_MORPHOLOGY_ENV).unwrap_or_else(|_| FR_MORPHOLOGY_PATH.to_string());
+        let path = Path::new(&path);
+        if !path.exists() {
+            tracing::debug!("Compiled French morphology not found at {:?}", path);
+            return None;
+        }
+
+        match Self::open(path) {
+            Ok(morph) => Some(morph),
+            Err(e) => {
+                tracing::warn!("Failed to open compiled French morphology {:?}: {}", path, e);
+                None
+            }
+        }
+    }
+
+    /// Try to load from data/morphology/fr_lefff.tsv
     fn try_load_from_file() -> Option<FrenchMorphology> {
-        use std::path::Path;
         use std::fs::File;
         use std::io::{BufRead, BufReader};
 
-        let path = Path::new("data/morphology/fr_lefff.tsv");
+        let path = Path::new(FR_LEFFF_TSV_PATH);
         if !path.exists() {
             tracing::debug!("French morphology file not found at {:?}", path);
             return None;
         }
 
-        tracing::info!("Loading French morphology from {:?}", path);
+        tracing::info!("Loading French morphology from {:?} (compile it with build-morphology for faster startup)", path);
 
         let file = File::open(path).ok()?;
         let reader = BufReader::new(file);
@@ -100,7 +168,7 @@ impl FrenchMorphology {
         tracing::info!("Loaded French morphology: {} forms, {} synthesis entries, {} POS tags",
                       entry_count, synth_count, all_pos_tags.len());
 
-        Some(FrenchMorphology { analysis, synthesis, all_pos_tags })
+        Some(FrenchMorphology { storage: Storage::Tables { analysis, synthesis, all_pos_tags } })
     }
 
     /// Minimal built-in dictionary for common cases
@@ -217,23 +285,42 @@ impl FrenchMorphology {
 
         tracing::debug!("Loaded minimal French morphology: {} forms", analysis.len());
 
-        FrenchMorphology { analysis, synthesis, all_pos_tags }
+        FrenchMorphology { storage: Storage::Tables { analysis, synthesis, all_pos_tags } }
+    }
+
+    /// All (lemma, POS) readings of a form, borrowed from the lexicon
+    ///
+    /// Allocation-free unless the form has uppercase letters.
+    pub fn readings(&self, form: &str) -> FrenchReadings<'_> {
+        let lower = lowercase(form);
+        match &self.storage {
+            Storage::Tables { analysis, .. } => FrenchReadings::Tables(
+                analysis.get(lower.as_ref()).map_or([].iter(), |entries| entries.iter()),
+            ),
+            Storage::Compiled(compiled) => FrenchReadings::Compiled(compiled.readings(&lower)),
+        }
     }
 
     /// Analyze a word form: returns all possible (lemma, POS) readings
-    pub fn analyze(&self, form: &str) -> &[MorphEntry] {
-        let lower = form.to_lowercase();
-        self.analysis.get(&lower)
-            .map(|v| v.as_slice())
-            .unwrap_or(&[])
+    ///
+    /// Owned copy of `readings`; prefer `readings` on hot paths.
+    pub fn analyze(&self, form: &str) -> Vec<MorphEntry> {
+        self.readings(form)
+            .map(|(lemma, pos)| MorphEntry { lemma: lemma.to_string(), pos: pos.to_string() })
+            .collect()
     }
 
     /// Synthesize: given lemma + exact POS tag, return matching forms
     pub fn synthesize(&self, lemma: &str, pos: &str) -> Vec<&str> {
-        let key = (lemma.to_string(), pos.to_string());
-        self.synthesis.get(&key)
-            .map(|forms| forms.iter().map(|s| s.as_str()).collect())
-            .unwrap_or_default()
+        match &self.storage {
+            Storage::Tables { synthesis, .. } => {
+                let key = (lemma.to_string(), pos.to_string());
+                synthesis.get(&key)
+                    .map(|forms| forms.iter().map(|s| s.as_str()).collect())
+                    .unwrap_or_default()
+            }
+            Storage::Compiled(compiled) => compiled.synthesize(lemma, pos),
+        }
     }
 
     /// Synthesize with regex: find forms where POS matches the pattern
@@ -250,26 +337,57 @@ impl FrenchMorphology {
             Err(_) => return Vec::new(),
         };
 
-        let mut results = Vec::new();
-        for pos in &self.all_pos_tags {
-            if re.is_match(pos) {
-                let key = (lemma.to_string(), pos.clone());
-                if let Some(forms) = self.synthesis.get(&key) {
-                    results.extend(forms.iter().map(|s| s.as_str()));
+        match &self.storage {
+            Storage::Tables { synthesis, all_pos_tags, .. } => {
+                let mut results = Vec::new();
+                for pos in all_pos_tags {
+                    if re.is_match(pos) {
+                        let key = (lemma.to_string(), pos.clone());
+                        if let Some(forms) = synthesis.get(&key) {
+                            results.extend(forms.iter().map(|s| s.as_str()));
+                        }
+                    }
                 }
+                results
             }
+            Storage::Compiled(compiled) => compiled.synthesize_matching(lemma, |pos| re.is_match(pos)),
         }
-        results
     }
 
     /// Get the first lemma for a form (for simple cases)
     pub fn get_lemma(&self, form: &str) -> Option<&str> {
-        self.analyze(form).first().map(|e| e.lemma.as_str())
+        self.readings(form).next().map(|(lemma, _)| lemma)
     }
 
     /// Get the first POS for a form (for simple cases)
     pub fn get_pos(&self, form: &str) -> Option<&str> {
-        self.analyze(form).first().map(|e| e.pos.as_str())
+        self.readings(form).next().map(|(_, pos)| pos)
+    }
+}
+
+/// Iterator over the readings of a form (see `FrenchMorphology::readings`)
+pub enum FrenchReadings<'a> {
+    Tables(std::slice::Iter<'a, MorphEntry>),
+    Compiled(Readings<'a>),
+}
+
+impl<'a> Iterator for FrenchReadings<'a> {
+    type Item = (&'a str, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            FrenchReadings::Tables(entries) => entries.next().map(|e| (e.lemma.as_str(), e.pos.as_str())),
+            FrenchReadings::Compiled(readings) => readings.next(),
+        }
+    }
+}
+
+/// Lowercase form, borrowed when already lowercase
+fn lowercase(form: &str) -> Cow<'_, str> {
+    if form.chars().any(char::is_uppercase) {
+        Cow::Owned(form.to_lowercase())
+    } else {
+        Cow::Borrowed(form)
     }
 }
 
@@ -322,4 +440,27 @@ mod tests {
         assert!(forms.contains(&"grande") || forms.contains(&"grandes"),
                 "Expected feminine forms of 'grand'");
     }
+
+    #[test]
+    fn test_compiled_matches_tables() {
+        use crate::morphology::MorphologyBuilder;
+
+        let lexicon = "grand\tgrand\tJ m s\ngrande\tgrand\tJ f s\ngrandes\tgrand\tJ f p\nla\tle\tD f s\n";
+        let mut builder = MorphologyBuilder::new();
+        builder.add_tsv(lexicon.as_bytes()).unwrap();
+        let file = tempfile::NamedTempFile::new().unwrap();
+        builder.write(file.path()).unwrap();
+
+        let morph = FrenchMorphology::open(file.path()).unwrap();
+        assert!(morph.is_compiled());
+        assert_eq!(morph.get_lemma("Grande"), Some("grand"));
+        assert_eq!(morph.get_pos("la"), Some("D f s"));
+        assert_eq!(morph.analyze("grandes")[0].pos, "J f p");
+        assert_eq!(morph.readings("GRANDES").collect::<Vec<_>>(), vec![("grand", "J f p")]);
+        assert_eq!(morph.synthesize("le", "D f s"), vec!["la"]);
+
+        let mut forms = morph.synthesize_regex("grand", "J f .*");
+        forms.sort();
+        assert_eq!(forms, vec!["grande", "grandes"]);
+    }
 }
@@ -1,12 +1,16 @@
-//! French morphology module using Lefff lexicon
+//! Morphology modules (French Lefff lexicon, English lemmatizer/synthesizer)
 //!
-//! Provides morphological analysis and synthesis for French words.
+//! Provides morphological analysis and synthesis for French and English words.
 //! Used for `postag_replace` suggestions in DynamicPatternChecker.
 
 mod french;
+mod english;
+mod compiled;
 mod pos_transform;
 
-pub use french::FrenchMorphology;
+pub use french::{FrenchMorphology, FrenchReadings, MorphEntry, FR_MORPHOLOGY_ENV, FR_MORPHOLOGY_PATH};
+pub use compiled::{CompiledMorphology, MorphologyBuilder, Readings};
+pub use english::{EnglishMorphology, EN_MORPHOLOGY_PATH};
 pub use pos_transform::transform_pos;
 
 #[cfg(test)]
@@ -0,0 +1,715 @@
+//! Pipelines préconfigurées - le jeu de checkers complet par langue
+//!
+//! Utilisées par l'API (`grammar-api`) et le CLI (`grammar-check`) pour
+//! vérifier un texte exactement avec les mêmes règles.
+//!
+//! `PresetConfig` (section `[pipelines]` de la config de `grammar-api`)
+//! choisit les checkers et filtres de chaque langue et les chemins des
+//! données ; la config par défaut donne le jeu complet.
+
+use crate::analyzer::{create_en_disambiguator, create_fr_disambiguator, PerceptronTagger, PosTagger};
+use crate::checker::{
+    RuleChecker, EnglishConfusionRule, FrenchConfusionRule,
+    AhoPatternRuleChecker, ReplaceRuleChecker,
+    StyleChecker, CoherencyChecker, DiacriticsChecker,
+    ContractionChecker, ContextChecker,
+    PosPatternChecker, UncountableNounChecker, CompoundWordChecker,
+    ProhibitChecker, SpellChecker, NgramConfusionChecker,
+    // Spelling suggestion ranking
+    KeyboardLayout, SuggestionRanker, WordFrequency, EN_COMMON_WORDS,
+    // en-US / en-GB variant spelling
+    EnglishVariant, VariantSpellingChecker, EN_US_GB_MAPPINGS,
+    // Dynamic pattern checker for complex rules (regex, postag_regexp, skip, etc.)
+    create_en_dynamic_checker, create_fr_dynamic_checker,
+    EN_PATTERN_RULES, FR_PATTERN_RULES,
+    EN_REPLACE_RULES, FR_REPLACE_RULES,
+    EN_ANTIPATTERNS, FR_ANTIPATTERNS,
+    EN_POS_PATTERN_RULES, FR_POS_PATTERN_RULES,
+    EN_ADDED_WORDS,
+    // Number words for POS tagging
+    EN_NUMBERS, FR_NUMBERS,
+    // Spelling skip lists and dictionaries
+    EN_IGNORE, EN_PROPER_NOUNS,
+    FR_IGNORE, FR_SPELLING, FR_COMMON_WORDS,
+    // Disambiguation skip patterns
+    EN_DISAMBIG_SKIP, FR_DISAMBIG_SKIP,
+};
+use crate::core::pipeline::Pipeline;
+use crate::core::traits::{Analyzer, Checker, Tokenizer};
+use crate::core::filter::FilterChain;
+use crate::core::PosTag;
+use crate::dictionary::FstDictionary;
+use crate::filter::{CodeBlockFilter, DateFilter, NumberFilter, QuotedTextFilter, UrlFilter};
+use crate::morphology::{EnglishMorphology, FrenchMorphology};
+use crate::tokenizer::SimpleTokenizer;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Checkers of the English pipelines, by configuration name
+pub const EN_CHECKER_NAMES: &[&str] = &[
+    "rules", "patterns", "pos_patterns", "replace", "style", "coherency", "diacritics",
+    "contractions", "context", "uncountable", "compound", "prohibit", "dynamic", "ngram",
+    "variant_spelling", "spell",
+];
+
+/// Checkers of the French pipelines, by configuration name
+pub const FR_CHECKER_NAMES: &[&str] = &[
+    "rules", "patterns", "pos_patterns", "replace", "style", "compound", "dynamic", "spell", "ngram",
+];
+
+/// Filters, by configuration name (all enabled by default)
+pub const FILTER_NAMES: &[&str] = &["url", "code", "quoted", "date", "number"];
+
+/// Configuration of the preset pipelines
+///
+/// ```toml
+/// [data]
+/// dictionaries = "/srv/grammar/dictionaries"
+/// ngrams = "/srv/grammar/ngrams"
+///
+/// [en]
+/// disabled_checkers = ["style"]
+/// ngram_min_factor = 10.0
+///
+/// [fr]
+/// checkers = ["rules", "patterns", "spell"]
+/// filters = ["url", "code"]
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PresetConfig {
+    pub data: DataPaths,
+    /// en-US and en-GB
+    pub en: LanguageConfig,
+    /// fr-FR and fr-CA
+    pub fr: LanguageConfig,
+}
+
+/// Data files used by the pipelines (`None`: default path, relative to the
+/// working directory)
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DataPaths {
+    /// `en_US.fst`, `en_GB.fst` and `fr_FR.fst` (default: `data/dictionaries`)
+    pub dictionaries: Option<PathBuf>,
+    /// `en_pos.bin` (default: `data/models`)
+    pub models: Option<PathBuf>,
+    /// `en_ngrams.bin` and `fr_ngrams.bin` (default: `data/ngrams` and its usual alternatives)
+    pub ngrams: Option<PathBuf>,
+    /// English morphology TSV (default: `EN_MORPHOLOGY_PATH` when present)
+    pub en_morphology: Option<PathBuf>,
+    /// Compiled Lefff morphology (default: `GRAMMAR_RS_FR_MORPHOLOGY`, then
+    /// `FR_MORPHOLOGY_PATH`)
+    pub fr_morphology: Option<PathBuf>,
+}
+
+impl DataPaths {
+    /// Directory of the FST dictionaries
+    pub fn dictionaries_dir(&self) -> &Path {
+        self.dictionaries.as_deref().unwrap_or(Path::new("data/dictionaries"))
+    }
+
+    /// Directory of the POS models
+    pub fn models_dir(&self) -> &Path {
+        self.models.as_deref().unwrap_or(Path::new("data/models"))
+    }
+
+    /// Load the configured morphologies (process-wide, before any pipeline
+    /// or `warm_up` uses them)
+    ///
+    /// Without a configured path, the morphology keeps its default lookup.
+    pub fn init_morphology(&self) -> Result<(), String> {
+        if let Some(path) = &self.en_morphology {
+            EnglishMorphology::init(path)
+                .map_err(|e| format!("English morphology {}: {}", path.display(), e))?;
+        }
+        if let Some(path) = &self.fr_morphology {
+            FrenchMorphology::init(path)
+                .map_err(|e| format!("French morphology {}: {}", path.display(), e))?;
+        }
+        Ok(())
+    }
+}
+
+/// Checkers and filters of one language
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LanguageConfig {
+    /// Checkers to build (default: all, see `EN_CHECKER_NAMES` / `FR_CHECKER_NAMES`)
+    pub checkers: Option<Vec<String>>,
+    /// Checkers left out, applied after `checkers`
+    pub disabled_checkers: Vec<String>,
+    /// Filters (default: all, see `FILTER_NAMES`)
+    pub filters: Option<Vec<String>>,
+    /// Minimum probability ratio of the N-gram confusion checker (default 3.0)
+    pub ngram_min_factor: Option<f64>,
+}
+
+impl LanguageConfig {
+    /// True if the checker `name` must be built
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.checkers.as_ref().is_none_or(|names| names.iter().any(|n| n == name))
+            && !self.disabled_checkers.iter().any(|n| n == name)
+    }
+
+    /// Filter chain of the language
+    pub fn filter_chain(&self) -> FilterChain {
+        let enabled = |name: &str| self.filters.as_ref().is_none_or(|names| names.iter().any(|n| n == name));
+        let mut chain = FilterChain::new();
+        if enabled("url") {
+            chain = chain.with_filter(UrlFilter::new());
+        }
+        if enabled("code") {
+            chain = chain.with_filter(CodeBlockFilter::new());
+        }
+        if enabled("quoted") {
+            chain = chain.with_filter(QuotedTextFilter::new());
+        }
+        if enabled("date") {
+            chain = chain.with_filter(DateFilter::new());
+        }
+        if enabled("number") {
+            chain = chain.with_filter(NumberFilter::new());
+        }
+        chain
+    }
+
+    /// N-gram confusion checker with the configured factor
+    fn ngram_checker(&self, checker: NgramConfusionChecker) -> NgramConfusionChecker {
+        match self.ngram_min_factor {
+            Some(factor) => checker.with_min_factor(factor),
+            None => checker,
+        }
+    }
+
+    fn validate(&self, section: &str, checker_names: &[&str]) -> Result<(), String> {
+        let checkers = self.checkers.iter().flatten().chain(&self.disabled_checkers);
+        if let Some(name) = checkers.into_iter().find(|n| !checker_names.contains(&n.as_str())) {
+            return Err(format!("[{}] unknown checker {:?} (expected one of {})", section, name, checker_names.join(", ")));
+        }
+        if let Some(name) = self.filters.iter().flatten().find(|n| !FILTER_NAMES.contains(&n.as_str())) {
+            return Err(format!("[{}] unknown filter {:?} (expected one of {})", section, name, FILTER_NAMES.join(", ")));
+        }
+        if let Some(factor) = self.ngram_min_factor.filter(|f| !(f.is_finite() && *f > 0.0)) {
+            return Err(format!("[{}] ngram_min_factor must be positive, got {}", section, factor));
+        }
+        Ok(())
+    }
+}
+
+impl PresetConfig {
+    /// Check the checker and filter names
+    pub fn validate(&self) -> Result<(), String> {
+        self.en.validate("en", EN_CHECKER_NAMES)?;
+        self.fr.validate("fr", FR_CHECKER_NAMES)
+    }
+
+    /// Make the relative data paths set in the file relative to `base`
+    /// (the config file directory); defaults stay relative to the working
+    /// directory
+    pub fn resolve_paths(&mut self, base: &Path) {
+        let data = &mut self.data;
+        for path in [&mut data.dictionaries, &mut data.models, &mut data.ngrams, &mut data.en_morphology, &mut data.fr_morphology]
+            .into_iter()
+            .flatten()
+        {
+            if path.is_relative() {
+                *path = base.join(&*path);
+            }
+        }
+    }
+}
+
+/// Normalize a language code to the variant codes used by the pipelines
+///
+/// "en" → "en-US", "fr" → "fr-FR", "en-gb" → "en-GB", ... Unknown codes are
+/// returned lowercased.
+pub fn normalize_language(lang: &str) -> String {
+    match lang.to_lowercase().as_str() {
+        "en" | "en-us" | "english" => "en-US".to_string(),
+        "en-gb" | "en-uk" => "en-GB".to_string(),
+        "fr" | "fr-fr" | "french" => "fr-FR".to_string(),
+        "fr-ca" => "fr-CA".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Variant pipeline that checks a normalized language code
+///
+/// "en-GB" and "fr-CA" have their own pipelines, other French codes use
+/// fr-FR and everything else en-US.
+pub fn pipeline_language(lang: &str) -> &'static str {
+    match lang {
+        "en-GB" => "en-GB",
+        "fr-CA" => "fr-CA",
+        _ if lang.starts_with("fr") => "fr-FR",
+        _ => "en-US",
+    }
+}
+
+/// The four variant pipelines (en-US, en-GB, fr-FR, fr-CA)
+pub struct LanguagePipelines {
+    /// en-US pipeline (also used for plain "en" and unknown languages)
+    pub en_us: Arc<Pipeline>,
+    /// en-GB pipeline (shares all checkers with en-US except spelling)
+    pub en_gb: Arc<Pipeline>,
+    /// fr-FR pipeline (also used for plain "fr")
+    pub fr_fr: Arc<Pipeline>,
+    /// fr-CA pipeline (Quebec punctuation spacing)
+    pub fr_ca: Arc<Pipeline>,
+}
+
+impl LanguagePipelines {
+    /// Build every pipeline
+    pub fn new() -> Self {
+        Self::with_config(&PresetConfig::default())
+    }
+
+    /// Build every pipeline with the given configuration
+    pub fn with_config(config: &PresetConfig) -> Self {
+        tracing::info!("Building English pipelines (en-US, en-GB)...");
+        let (en_us, en_gb) = en_pipelines_with_config(config);
+
+        tracing::info!("Building French pipelines (fr-FR, fr-CA)...");
+        let (fr_fr, fr_ca) = fr_pipelines_with_config(config);
+
+        Self {
+            en_us: Arc::new(en_us),
+            en_gb: Arc::new(en_gb),
+            fr_fr: Arc::new(fr_fr),
+            fr_ca: Arc::new(fr_ca),
+        }
+    }
+
+    /// Pipeline of a normalized language code (en-US, en-GB, fr-FR, fr-CA, ...)
+    pub fn get(&self, lang: &str) -> &Arc<Pipeline> {
+        match pipeline_language(lang) {
+            "en-GB" => &self.en_gb,
+            "fr-CA" => &self.fr_ca,
+            "fr-FR" => &self.fr_fr,
+            _ => &self.en_us,
+        }
+    }
+
+    /// Every pipeline with its language code
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &Arc<Pipeline>)> {
+        [("en-US", &self.en_us), ("en-GB", &self.en_gb), ("fr-FR", &self.fr_fr), ("fr-CA", &self.fr_ca)].into_iter()
+    }
+}
+
+impl Default for LanguagePipelines {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Create an English POS tagger with the added words dictionary
+fn create_en_pos_tagger(data: &DataPaths) -> PosTagger {
+    // Lemmas and irregular forms from the English morphology
+    let mut tagger = PosTagger::new().with_morphology(EnglishMorphology::load());
+
+    // Load the POS-tagged words from LanguageTool
+    for entry in EN_ADDED_WORDS {
+        if let Some(pos) = PosTag::from_str(entry.pos_tag) {
+            tagger.add_word(entry.word, entry.base_form, pos);
+        }
+    }
+
+    // Load number words (twenty-one, thirty-five, etc.) as CD (cardinal number)
+    tagger.load_from_lines(EN_NUMBERS.iter().copied());
+
+    // Statistical model (optional - trained with `train-pos-tagger`)
+    if let Some(model) = load_en_pos_model(data) {
+        tagger = tagger.with_model(model);
+    }
+
+    tracing::debug!("EN POS tagger loaded with {} dictionary entries + {}",
+                   tagger.dictionary_size(),
+                   if tagger.has_model() { "perceptron model" } else { "suffix heuristics" });
+    tagger
+}
+
+/// Load the English perceptron model (`data/models/en_pos.bin`) when present
+fn load_en_pos_model(data: &DataPaths) -> Option<PerceptronTagger> {
+    let model_path = &data.models_dir().join("en_pos.bin");
+    if !model_path.exists() {
+        return None;
+    }
+
+    match PerceptronTagger::from_file(model_path) {
+        Ok(model) => {
+            tracing::info!("EN POS model loaded from {:?} ({} features)", model_path, model.feature_count());
+            Some(model)
+        }
+        Err(e) => {
+            tracing::warn!("Failed to load EN POS model {:?}: {}", model_path, e);
+            None
+        }
+    }
+}
+
+/// Create a French POS tagger with number words
+fn create_fr_pos_tagger() -> PosTagger {
+    let mut tagger = PosTagger::new();
+
+    // Load French number words (vingt-et-un, trente-deux, etc.) as CD (cardinal number)
+    tagger.load_from_lines(FR_NUMBERS.iter().copied());
+
+    tracing::debug!("FR POS tagger loaded with {} dictionary entries + suffix heuristics",
+                   tagger.dictionary_size());
+    tagger
+}
+
+/// Load the FST dictionary for an English variant
+///
+/// Uses `data/dictionaries/en_GB.fst` for en-GB when present and falls
+/// back to the en-US dictionary otherwise.
+fn load_en_dictionary(variant: EnglishVariant, data: &DataPaths) -> Option<FstDictionary> {
+    let gb_path = data.dictionaries_dir().join("en_GB.fst");
+    let dict_path = &match variant {
+        EnglishVariant::British if gb_path.exists() => gb_path,
+        _ => data.dictionaries_dir().join("en_US.fst"),
+    };
+
+    if !dict_path.exists() {
+        tracing::warn!("EN dictionary not found at {:?}, spell checking disabled", dict_path);
+        return None;
+    }
+
+    match FstDictionary::from_fst(dict_path) {
+        Ok(dict) => Some(dict),
+        Err(e) => {
+            tracing::warn!("Failed to load EN dictionary {:?}: {}", dict_path, e);
+            None
+        }
+    }
+}
+
+/// Create an English spell checker with FST dictionary (370K words)
+///
+/// US/GB variant spellings are skipped: `VariantSpellingChecker` reports
+/// them with a variant-specific message instead of a generic SPELL match.
+fn create_en_spell_checker(variant: EnglishVariant, data: &DataPaths) -> Option<SpellChecker> {
+    let dict = load_en_dictionary(variant, data)?;
+    let word_count = dict.len();
+    let skip_count = EN_IGNORE.len() + EN_PROPER_NOUNS.len() + EN_DISAMBIG_SKIP.len()
+        + EN_US_GB_MAPPINGS.len() * 2;
+    let ranker = SuggestionRanker::new().with_frequency(WordFrequency::CommonWords(EN_COMMON_WORDS));
+    let checker = SpellChecker::with_fst_dictionary(dict)
+        .with_ranker(ranker)
+        .with_skip_words(EN_IGNORE.iter().copied())
+        .with_skip_words(EN_PROPER_NOUNS.iter().copied())
+        .with_skip_words(EN_DISAMBIG_SKIP.iter().copied())
+        .with_skip_words(EN_US_GB_MAPPINGS.iter().flat_map(|m| [m.us_word, m.gb_word]));
+    tracing::info!("{} spell checker enabled ({} dictionary words, {} skip words)",
+                  variant.code(), word_count, skip_count);
+    Some(checker)
+}
+
+/// Load the French FST dictionary (`data/dictionaries/fr_FR.fst`)
+///
+/// Built with `build-dict` from the Lefff inflected forms plus the built-in
+/// French lists (`--fr-builtins`).
+fn load_fr_dictionary(data: &DataPaths) -> Option<FstDictionary> {
+    let dict_path = &data.dictionaries_dir().join("fr_FR.fst");
+    if !dict_path.exists() {
+        tracing::debug!("FR dictionary not found at {:?}", dict_path);
+        return None;
+    }
+
+    match FstDictionary::from_fst(dict_path) {
+        Ok(dict) => Some(dict),
+        Err(e) => {
+            tracing::warn!("Failed to load FR dictionary {:?}: {}", dict_path, e);
+            None
+        }
+    }
+}
+
+/// Create a French spell checker
+///
+/// Uses the FST dictionary (Lefff, ~600K forms) when present, otherwise
+/// FR_COMMON_WORDS (9.7K) + FR_SPELLING (34K) in a HashSet.
+fn create_fr_spell_checker(data: &DataPaths) -> Option<SpellChecker> {
+    let (checker, total_words) = match load_fr_dictionary(data) {
+        Some(dict) => {
+            let word_count = dict.len();
+            (SpellChecker::with_fst_dictionary(dict), word_count)
+        }
+        None => {
+            let checker = SpellChecker::new()
+                .with_words(FR_COMMON_WORDS.iter().copied())
+                .with_words(FR_SPELLING.iter().copied());
+            (checker, FR_COMMON_WORDS.len() + FR_SPELLING.len())
+        }
+    };
+    let ranker = SuggestionRanker::new()
+        .with_layout(KeyboardLayout::Azerty)
+        .with_frequency(WordFrequency::CommonWords(FR_COMMON_WORDS));
+    let checker = checker
+        .with_ranker(ranker)
+        .with_skip_words(FR_IGNORE.iter().copied())
+        .with_skip_words(FR_DISAMBIG_SKIP.iter().copied());
+
+    let skip_count = FR_IGNORE.len() + FR_DISAMBIG_SKIP.len();
+    tracing::info!("FR spell checker enabled ({} dictionary words, {} skip words)",
+                  total_words, skip_count);
+    Some(checker)
+}
+
+/// Create the English checkers shared by every variant
+fn create_en_checkers(config: &PresetConfig) -> Vec<Arc<dyn Checker>> {
+    let en = &config.en;
+    let mut checkers: Vec<Arc<dyn Checker>> = Vec::new();
+    let mut add = |name: &str, create: &dyn Fn() -> Arc<dyn Checker>| {
+        if en.is_enabled(name) {
+            checkers.push(create());
+        }
+    };
+
+    // Basic grammar rules + confusion pairs
+    add("rules", &|| Arc::new(RuleChecker::new().with_english_rules().with_rule(EnglishConfusionRule)));
+    // Pattern-based rules (Aho-Corasick for speed) with antipattern filtering
+    add("patterns", &|| Arc::new(AhoPatternRuleChecker::with_antipatterns(EN_PATTERN_RULES, EN_ANTIPATTERNS)));
+    // POS pattern rules (require POS tagging) - 94 rules from LanguageTool
+    add("pos_patterns", &|| Arc::new(PosPatternChecker::with_rules(EN_POS_PATTERN_RULES)));
+    // Simple replacements
+    add("replace", &|| Arc::new(ReplaceRuleChecker::new(EN_REPLACE_RULES, "EN_REPLACE")));
+    // Style checking (wordiness, redundancy) - uses default EN_STYLE_RULES
+    add("style", &|| Arc::new(StyleChecker::new()));
+    // Coherency checking (US/UK spelling consistency) - uses default
+    add("coherency", &|| Arc::new(CoherencyChecker::new()));
+    // Diacritics (café, résumé, etc.) - uses default
+    add("diacritics", &|| Arc::new(DiacriticsChecker::new()));
+    // Contractions (don't, won't, etc.) - uses default
+    add("contractions", &|| Arc::new(ContractionChecker::new()));
+    // Context-sensitive words - uses default
+    add("context", &|| Arc::new(ContextChecker::new()));
+    // Uncountable noun pluralization errors (informations → information)
+    add("uncountable", &|| Arc::new(UncountableNounChecker::new()));
+    // Compound word errors (air plane → airplane, well being → well-being)
+    add("compound", &|| Arc::new(CompoundWordChecker::new()));
+    // Prohibited words (common misspellings that are always wrong)
+    add("prohibit", &|| Arc::new(ProhibitChecker::new()));
+    // Dynamic pattern checker (complex rules with regex, postag_regexp, skip, etc.)
+    // 2110 additional rules from LanguageTool that can't be compiled to static code
+    add("dynamic", &|| {
+        let checker = create_en_dynamic_checker();
+        tracing::info!("EN dynamic pattern checker enabled ({} rules)", checker.rule_count());
+        Arc::new(checker)
+    });
+
+    // N-gram confusion checker (optional - requires data/ngrams/en_ngrams.bin)
+    if en.is_enabled("ngram") {
+        let ngram_checker = match &config.data.ngrams {
+            Some(dir) => NgramConfusionChecker::try_load_en_in(dir),
+            None => NgramConfusionChecker::try_load_en(),
+        };
+        if let Some(ngram_checker) = ngram_checker {
+            tracing::info!("EN N-gram confusion checker enabled");
+            checkers.push(Arc::new(en.ngram_checker(ngram_checker)));
+        }
+    }
+
+    checkers
+}
+
+/// Create the en-US and en-GB pipelines
+///
+/// Both variants share the tokenizer, POS tagger and every checker except
+/// the variant spelling checker and the spell checker.
+pub fn en_pipelines() -> (Pipeline, Pipeline) {
+    en_pipelines_with_config(&PresetConfig::default())
+}
+
+/// Same as `en_pipelines`, with the checkers, filters and data of `config`
+pub fn en_pipelines_with_config(config: &PresetConfig) -> (Pipeline, Pipeline) {
+    // Use POS tagger instead of passthrough for better rule matching
+    let tokenizer: Arc<dyn Tokenizer> = Arc::new(SimpleTokenizer::new());
+    let analyzer: Arc<dyn Analyzer> = Arc::new(create_en_pos_tagger(&config.data));
+    let checkers = create_en_checkers(config);
+
+    let build = |variant: EnglishVariant| {
+        let mut pipeline = Pipeline::from_shared(Arc::clone(&tokenizer), Arc::clone(&analyzer))
+            // Contextual POS disambiguation (disambiguation.xml)
+            .with_disambiguator(create_en_disambiguator())
+            .with_checkers(checkers.clone());
+
+        // US/GB spelling variants (color vs colour)
+        if config.en.is_enabled("variant_spelling") {
+            pipeline = pipeline.with_checker(VariantSpellingChecker::new(variant));
+        }
+
+        // Spell checker (370K word FST dictionary + skip lists)
+        if config.en.is_enabled("spell") {
+            if let Some(spell_checker) = create_en_spell_checker(variant, &config.data) {
+                pipeline = pipeline.with_checker(spell_checker);
+            }
+        }
+
+        // Filters (URLs, code, quotes, etc.)
+        pipeline.with_filters(config.en.filter_chain())
+    };
+
+    (build(EnglishVariant::American), build(EnglishVariant::British))
+}
+
+/// Create the French checkers shared by fr-FR and fr-CA
+fn create_fr_checkers(config: &PresetConfig) -> Vec<Arc<dyn Checker>> {
+    let fr = &config.fr;
+    let mut checkers: Vec<Arc<dyn Checker>> = Vec::new();
+    let mut add = |name: &str, create: &dyn Fn() -> Arc<dyn Checker>| {
+        if fr.is_enabled(name) {
+            checkers.push(create());
+        }
+    };
+
+    // Pattern-based rules with antipattern filtering
+    add("patterns", &|| Arc::new(AhoPatternRuleChecker::with_antipatterns(FR_PATTERN_RULES, FR_ANTIPATTERNS)));
+    // POS pattern rules (25 rules from LanguageTool)
+    add("pos_patterns", &|| Arc::new(PosPatternChecker::with_rules(FR_POS_PATTERN_RULES)));
+    // Simple replacements
+    add("replace", &|| Arc::new(ReplaceRuleChecker::new(FR_REPLACE_RULES, "FR_REPLACE")));
+    // Style checking (wordiness, redundancy) - 51 FR rules
+    add("style", &|| Arc::new(StyleChecker::french()));
+    // Compound word errors (aller retour → aller-retour)
+    add("compound", &|| Arc::new(CompoundWordChecker::french()));
+    // Dynamic pattern checker (complex rules with regex, postag_regexp, skip, etc.)
+    // 826 additional rules from LanguageTool that can't be compiled to static code
+    add("dynamic", &|| {
+        let checker = create_fr_dynamic_checker();
+        tracing::info!("FR dynamic pattern checker enabled ({} rules)", checker.rule_count());
+        Arc::new(checker)
+    });
+
+    // Spell checker (34K word dictionary from FR_SPELLING + skip list)
+    if fr.is_enabled("spell") {
+        if let Some(spell_checker) = create_fr_spell_checker(&config.data) {
+            checkers.push(Arc::new(spell_checker));
+        }
+    }
+
+    // N-gram confusion checker (optional - requires data/ngrams/fr_ngrams.bin)
+    if fr.is_enabled("ngram") {
+        let ngram_checker = match &config.data.ngrams {
+            Some(dir) => NgramConfusionChecker::try_load_fr_in(dir),
+            None => NgramConfusionChecker::try_load_fr(),
+        };
+        if let Some(ngram_checker) = ngram_checker {
+            tracing::info!("FR N-gram confusion checker enabled");
+            checkers.push(Arc::new(fr.ngram_checker(ngram_checker)));
+        }
+    }
+
+    checkers
+}
+
+/// Create the fr-FR and fr-CA pipelines
+///
+/// The variants only differ in punctuation spacing: fr-CA follows Quebec
+/// typography (no space before `;`, `!` and `?`).
+pub fn fr_pipelines() -> (Pipeline, Pipeline) {
+    fr_pipelines_with_config(&PresetConfig::default())
+}
+
+/// Same as `fr_pipelines`, with the checkers, filters and data of `config`
+pub fn fr_pipelines_with_config(config: &PresetConfig) -> (Pipeline, Pipeline) {
+    // Use POS tagger for better rule matching (includes French number words)
+    let tokenizer: Arc<dyn Tokenizer> = Arc::new(SimpleTokenizer::new());
+    let analyzer: Arc<dyn Analyzer> = Arc::new(create_fr_pos_tagger());
+    let checkers = create_fr_checkers(config);
+
+    let build = |rules: RuleChecker| {
+        let mut pipeline = Pipeline::from_shared(Arc::clone(&tokenizer), Arc::clone(&analyzer))
+            .with_disambiguator(create_fr_disambiguator())
+            .with_checkers(checkers.clone());
+
+        // Basic French grammar rules + confusion pairs
+        if config.fr.is_enabled("rules") {
+            pipeline = pipeline.with_checker(rules.with_rule(FrenchConfusionRule));
+        }
+
+        // Filters
+        pipeline.with_filters(config.fr.filter_chain())
+    };
+
+    (
+        build(RuleChecker::new().with_french_rules()),
+        build(RuleChecker::new().with_canadian_french_rules()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_language() {
+        assert_eq!(normalize_language("en"), "en-US");
+        assert_eq!(normalize_language("EN-gb"), "en-GB");
+        assert_eq!(normalize_language("fr"), "fr-FR");
+        assert_eq!(normalize_language("fr-CA"), "fr-CA");
+        assert_eq!(normalize_language("de"), "de");
+    }
+
+    #[test]
+    fn test_pipeline_language() {
+        assert_eq!(pipeline_language("fr-BE"), "fr-FR");
+        assert_eq!(pipeline_language("fr-CA"), "fr-CA");
+        assert_eq!(pipeline_language("en-GB"), "en-GB");
+        assert_eq!(pipeline_language("de"), "en-US");
+    }
+
+    #[test]
+    fn test_config_selection() {
+        let config: PresetConfig = toml::from_str(
+            r#"
+            [en]
+            checkers = ["rules", "style", "spell"]
+            disabled_checkers = ["style"]
+            filters = ["url"]
+            ngram_min_factor = 10.0
+            "#,
+        )
+        .unwrap();
+        assert!(config.validate().is_ok());
+        assert!(config.en.is_enabled("rules"));
+        assert!(!config.en.is_enabled("style"));
+        assert!(!config.en.is_enabled("dynamic"));
+        assert!(config.fr.is_enabled("dynamic"));
+        assert_eq!(config.en.filter_chain().len(), 1);
+        assert_eq!(config.fr.filter_chain().len(), FILTER_NAMES.len());
+    }
+
+    #[test]
+    fn test_config_validation() {
+        let parse = |text: &str| toml::from_str::<PresetConfig>(text).unwrap().validate();
+        assert!(parse("[fr]\ncheckers = [\"coherency\"]").is_err());
+        assert!(parse("[en]\nfilters = [\"html\"]").is_err());
+        assert!(parse("[en]\nngram_min_factor = 0.0").is_err());
+        assert!(toml::from_str::<PresetConfig>("[de]\nfilters = []").is_err());
+    }
+
+    #[test]
+    fn test_resolve_paths() {
+        let mut config: PresetConfig =
+            toml::from_str("[data]\nmodels = \"/opt/models\"\nfr_morphology = \"lefff.morph\"").unwrap();
+        config.resolve_paths(Path::new("/etc/grammar"));
+        assert_eq!(config.data.models_dir(), Path::new("/opt/models"));
+        assert_eq!(config.data.fr_morphology.as_deref(), Some(Path::new("/etc/grammar/lefff.morph")));
+        // Paths not set in the file keep their working-directory default
+        assert_eq!(config.data.dictionaries_dir(), Path::new("data/dictionaries"));
+        assert_eq!(config.data.ngrams, None);
+    }
+
+    #[test]
+    fn test_pipelines_with_config() {
+        let config: PresetConfig = toml::from_str(
+            "[fr]\ncheckers = [\"rules\"]\nfilters = []",
+        )
+        .unwrap();
+        let (fr_fr, _) = fr_pipelines_with_config(&config);
+        assert_eq!(fr_fr.checker_count(), 1);
+    }
+}
@@ -1,5 +1,7 @@
 mod simple;
 mod contraction;
+mod sentence;
 
 pub use simple::SimpleTokenizer;
 pub use contraction::ContractionTokenizer;
+pub use sentence::SimpleSentenceSplitter;
@@ -0,0 +1,236 @@
+//! Découpage en phrases
+//!
+//! Fin de phrase = `.`, `!`, `?` ou `…` suivi d'un espace (guillemets et
+//! parenthèses fermants compris), sauf :
+//! - abréviations (« e.g. », « Dr. », « M. ») et initiales (« J. K. »)
+//! - nombres décimaux et URLs (pas d'espace après le point)
+//! - `!`, `?` et points de suspension suivis d'une minuscule
+//!   (« « Viens ! » dit-il », « Wait... what? »)
+//!
+//! Une ligne vide termine toujours la phrase.
+
+use crate::core::traits::SentenceSplitter;
+use std::collections::HashSet;
+use std::ops::Range;
+
+/// Abréviations anglaises et françaises (minuscules, sans le point final)
+const ABBREVIATIONS: &[&str] = &[
+    // Anglais
+    "mr", "mrs", "ms", "dr", "prof", "sr", "jr", "st", "vs", "e.g", "i.e", "cf", "approx",
+    "fig", "figs", "vol", "pp", "ch", "eds", "dept", "inc", "ltd", "co", "corp",
+    "jan", "feb", "apr", "jun", "jul", "aug", "sept", "oct", "nov", "dec",
+    "a.m", "p.m", "u.s", "u.k", "ph.d",
+    // Français
+    "mme", "mmes", "mlle", "mlles", "mgr", "cie", "env", "p.ex", "c.-à-d", "c-à-d",
+    "av", "bd", "chap", "éd", "ibid", "réf", "tél",
+];
+
+/// Ponctuation de fin de phrase
+fn is_terminator(c: char) -> bool {
+    matches!(c, '.' | '!' | '?' | '…' | '‽')
+}
+
+/// Guillemets et parenthèses fermants qui restent dans la phrase
+fn is_closer(c: char) -> bool {
+    matches!(c, ')' | ']' | '"' | '\'' | '’' | '”' | '»')
+}
+
+/// Découpeur de phrases à base de règles et de listes d'abréviations
+pub struct SimpleSentenceSplitter {
+    abbreviations: HashSet<String>,
+}
+
+impl SimpleSentenceSplitter {
+    pub fn new() -> Self {
+        Self {
+            abbreviations: ABBREVIATIONS.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+
+    /// Ajoute des abréviations (sans le point final, ex: "approx")
+    pub fn with_abbreviations<I, S>(mut self, abbreviations: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.abbreviations.extend(
+            abbreviations
+                .into_iter()
+                .map(|a| a.as_ref().trim_end_matches('.').to_lowercase()),
+        );
+        self
+    }
+
+    /// Le mot qui précède le point en `dot` est-il une abréviation ?
+    fn is_abbreviation(&self, chars: &[(usize, char)], dot: usize) -> bool {
+        let start = chars[..dot]
+            .iter()
+            .rposition(|(_, c)| c.is_whitespace() || matches!(c, '(' | '[' | '"' | '«' | '“'))
+            .map_or(0, |i| i + 1);
+        if start == dot {
+            return false;
+        }
+        let word: String = chars[start..dot].iter().map(|(_, c)| *c).collect();
+
+        // Initiale : « J. K. Rowling »
+        let mut letters = word.chars();
+        if let (Some(first), None) = (letters.next(), letters.next()) {
+            if first.is_uppercase() {
+                return true;
+            }
+        }
+        self.abbreviations.contains(&word.to_lowercase())
+    }
+}
+
+impl Default for SimpleSentenceSplitter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SentenceSplitter for SimpleSentenceSplitter {
+    fn split(&self, text: &str) -> Vec<Range<usize>> {
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+        let byte_at = |i: usize| chars.get(i).map_or(text.len(), |(pos, _)| *pos);
+
+        let mut sentences = Vec::new();
+        // Début de la phrase en cours (premier caractère non blanc)
+        let mut start: Option<usize> = None;
+        // Fin du dernier caractère non blanc
+        let mut last = 0;
+        let mut i = 0;
+
+        let mut close = |start: &mut Option<usize>, end: usize| {
+            if let Some(s) = start.take() {
+                sentences.push(s..end);
+            }
+        };
+
+        while i < chars.len() {
+            let (pos, c) = chars[i];
+
+            if c == '\n' {
+                // Ligne vide : fin de paragraphe
+                let next = chars[i + 1..].iter().find(|(_, c)| !matches!(c, ' ' | '\t' | '\r'));
+                if next.is_some_and(|(_, c)| *c == '\n') {
+                    close(&mut start, last);
+                }
+            }
+
+            if c.is_whitespace() {
+                i += 1;
+                continue;
+            }
+            if start.is_none() {
+                start = Some(pos);
+            }
+
+            if !is_terminator(c) {
+                last = pos + c.len_utf8();
+                i += 1;
+                continue;
+            }
+
+            // Suite de terminateurs (« ?! », « ... »)
+            let run_end = i + chars[i..].iter().take_while(|(_, c)| is_terminator(*c)).count();
+
+            // Fermants, avec l'espace français avant « » »
+            let mut end = run_end;
+            loop {
+                if end < chars.len() && is_closer(chars[end].1) {
+                    end += 1;
+                } else if end + 1 < chars.len() && matches!(chars[end].1, ' ' | '\u{a0}' | '\u{202f}') && chars[end + 1].1 == '»' {
+                    end += 2;
+                } else {
+                    break;
+                }
+            }
+            last = byte_at(end);
+
+            // « 3.14 », « example.com », « e.g. » au milieu d'un mot
+            let followed_by_space = end == chars.len() || chars[end].1.is_whitespace();
+            let next = chars[end..].iter().map(|(_, c)| *c).find(|c| !c.is_whitespace());
+            let single_dot = c == '.' && run_end == i + 1;
+
+            let boundary = followed_by_space
+                && match next {
+                    None => true,
+                    Some(next) if next.is_lowercase() => single_dot && !self.is_abbreviation(&chars, i),
+                    Some(_) => !(single_dot && self.is_abbreviation(&chars, i)),
+                };
+
+            if boundary {
+                close(&mut start, last);
+            }
+            i = end;
+        }
+
+        close(&mut start, last);
+        sentences
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(text: &str) -> Vec<&str> {
+        SimpleSentenceSplitter::new()
+            .split(text)
+            .into_iter()
+            .map(|span| &text[span])
+            .collect()
+    }
+
+    #[test]
+    fn test_basic_split() {
+        assert_eq!(split("Hello world. How are you? Fine!"), vec!["Hello world.", "How are you?", "Fine!"]);
+        assert_eq!(split("  Trailing spaces.  "), vec!["Trailing spaces."]);
+        assert_eq!(split("No terminator"), vec!["No terminator"]);
+        assert!(split("   ").is_empty());
+    }
+
+    #[test]
+    fn test_abbreviations_and_numbers() {
+        assert_eq!(split("Use fruit, e.g. apples. Dr. Smith paid 3.50 dollars."), vec![
+            "Use fruit, e.g. apples.",
+            "Dr. Smith paid 3.50 dollars.",
+        ]);
+        assert_eq!(split("J. K. Rowling wrote it. See example.com now."), vec![
+            "J. K. Rowling wrote it.",
+            "See example.com now.",
+        ]);
+        assert_eq!(split("M. Dupont est là. Il attend."), vec!["M. Dupont est là.", "Il attend."]);
+    }
+
+    #[test]
+    fn test_lowercase_after_period_still_splits() {
+        assert_eq!(split("Hello. world"), vec!["Hello.", "world"]);
+    }
+
+    #[test]
+    fn test_quotes_and_ellipses() {
+        assert_eq!(split("\"Stop!\" he said. Then he left."), vec!["\"Stop!\" he said.", "Then he left."]);
+        assert_eq!(split("He said \"go.\" We went."), vec!["He said \"go.\"", "We went."]);
+        assert_eq!(split("Wait... what? I waited... Then it came."), vec![
+            "Wait... what?",
+            "I waited...",
+            "Then it came.",
+        ]);
+    }
+
+    #[test]
+    fn test_french_quotes() {
+        assert_eq!(split("« Viens ! » dit-il. Elle a répondu : « Non. » Puis elle est partie."), vec![
+            "« Viens ! » dit-il.",
+            "Elle a répondu : « Non. »",
+            "Puis elle est partie.",
+        ]);
+    }
+
+    #[test]
+    fn test_paragraph_break() {
+        assert_eq!(split("# Title\n\nFirst line\nsame sentence"), vec!["# Title", "First line\nsame sentence"]);
+    }
+}
@@ -21,6 +21,7 @@ use grammar_rs::checker::{
     EN_IGNORE, EN_PROPER_NOUNS, FR_IGNORE, FR_COMMON_WORDS, FR_SPELLING,
 };
 use grammar_rs::dictionary::FstDictionary;
+use grammar_rs::markup::{AnnotatedText, MarkupFormat};
 use std::path::Path;
 
 // ============================================================================
@@ -228,6 +229,31 @@ fn api_en_code_block_filtered() {
         "Inline code should not trigger spell errors");
 }
 
+#[test]
+fn api_en_annotated_data_offsets() {
+    let pipeline = create_test_en_pipeline();
+    // LanguageTool `data` parameter: the markup never reaches the checkers
+    let data = r#"{"annotation":[{"text":"I want "},{"markup":"<b>"},{"text":"a"},{"markup":"</b>"},{"text":" apple."}]}"#;
+    let annotated = AnnotatedText::from_lt_json(data).unwrap();
+    let result = pipeline.check_annotated(&annotated);
+
+    let a_an = result.matches.iter().find(|m| m.rule_id.contains("A_AN"))
+        .expect("Should detect 'a apple' through the markup");
+    assert_eq!(&annotated.original_text()[a_an.span.clone()], "a");
+    assert_eq!(a_an.span.start, 10);
+}
+
+#[test]
+fn api_en_markdown_no_spurious_matches() {
+    let pipeline = create_test_en_pipeline();
+    let text = "This is **really** important, see [the guide](https://x.y/a__b).";
+    let annotated = MarkupFormat::Markdown.annotate(text);
+    let result = pipeline.check_annotated(&annotated);
+
+    assert!(result.matches.is_empty(),
+        "Markdown syntax should not trigger matches: {:?}", result.matches);
+}
+
 // ============================================================================
 // French Pipeline Tests
 // ============================================================================
@@ -355,6 +381,39 @@ fn api_response_offset_length_correct() {
     }
 }
 
+// ============================================================================
+// Rule Registry Tests (/v2/rules)
+// ============================================================================
+
+#[test]
+fn api_rule_registry_lists_checker_rules() {
+    let pipeline = create_test_en_pipeline();
+    let registry = pipeline.rule_registry();
+
+    let double_space = registry.get("DOUBLE_SPACE").expect("DOUBLE_SPACE should be registered");
+    assert_eq!(double_space.category, RuleCategory::Typography);
+    assert!(double_space.default_on);
+
+    let wordiness = registry.iter().find(|r| r.id.starts_with("WORDINESS_"))
+        .expect("Style checker rules should be registered");
+    assert_eq!(wordiness.level, CheckLevel::Picky);
+    assert!(registry.iter().any(|r| !r.examples.is_empty()),
+        "Pattern rules should carry their LanguageTool examples");
+}
+
+#[test]
+fn api_rule_registry_covers_emitted_matches() {
+    let pipeline = create_test_en_pipeline();
+    let text = "This is is a test.  In order to win, I have a apple.";
+    let result = pipeline.check_text(text);
+
+    assert!(!result.matches.is_empty());
+    for m in &result.matches {
+        assert!(pipeline.rule_registry().get(&m.rule_id).is_some(),
+            "Rule {} is missing from the registry", m.rule_id);
+    }
+}
+
 // ============================================================================
 // Language Detection Tests
 // ============================================================================
@@ -0,0 +1,64 @@
+//! Accuracy report for the averaged-perceptron POS tagger
+//!
+//! Trains on `tests/data/pos_train.txt` and reports the accuracy on the
+//! held-out `tests/data/pos_heldout.conll` (unseen nouns, verbs and
+//! adjectives included), next to the dictionary + suffix heuristics
+//! baseline built from the same training data.
+//!
+//! Run with: cargo test --test pos_tagger -- --nocapture
+
+use grammar_rs::analyzer::{parse_tagged_corpus, PerceptronTagger, PosTagger, TaggedSentence};
+use grammar_rs::core::PosTag;
+
+const TRAIN: &str = include_str!("data/pos_train.txt");
+const HELDOUT: &str = include_str!("data/pos_heldout.conll");
+
+/// Dictionary lookup + suffix heuristics over the training words
+fn baseline_accuracy(train: &[TaggedSentence], heldout: &[TaggedSentence]) -> f64 {
+    let mut tagger = PosTagger::new();
+    for (word, tag) in train.iter().flatten() {
+        if let Some(pos) = PosTag::from_str(tag) {
+            tagger.add_word(word, word, pos);
+        }
+    }
+
+    let (mut correct, mut total) = (0, 0);
+    for (word, tag) in heldout.iter().flatten() {
+        let predicted = tagger.tag_word(word).map(|pos| pos.as_str());
+        correct += usize::from(predicted == Some(tag.as_str()));
+        total += 1;
+    }
+    correct as f64 / total as f64
+}
+
+#[test]
+fn test_heldout_accuracy() {
+    let train = parse_tagged_corpus(TRAIN);
+    let heldout = parse_tagged_corpus(HELDOUT);
+    assert!(!train.is_empty() && !heldout.is_empty());
+
+    let tagger = PerceptronTagger::train(&train, 5);
+    let accuracy = tagger.accuracy(&heldout);
+    let baseline = baseline_accuracy(&train, &heldout);
+
+    let words: usize = heldout.iter().map(Vec::len).sum();
+    println!("Held-out set: {} sentences, {} words", heldout.len(), words);
+    println!("  perceptron:          {:.2}%", accuracy * 100.0);
+    println!("  dictionary + suffix: {:.2}%", baseline * 100.0);
+
+    assert!(accuracy >= 0.95, "perceptron accuracy too low: {:.2}%", accuracy * 100.0);
+    assert!(accuracy > baseline, "perceptron should beat the baseline");
+}
+
+#[test]
+fn test_saved_model_tags_the_same() {
+    let train = parse_tagged_corpus(TRAIN);
+    let heldout = parse_tagged_corpus(HELDOUT);
+    let tagger = PerceptronTagger::train(&train, 5);
+
+    let file = tempfile::NamedTempFile::new().unwrap();
+    tagger.to_file(file.path()).unwrap();
+    let loaded = PerceptronTagger::from_file(file.path()).unwrap();
+
+    assert_eq!(loaded.accuracy(&heldout), tagger.accuracy(&heldout));
+}